@@ -364,6 +364,23 @@ fn batched_window<G: AffineCurve>(
 }
 
 pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+    // Determine the bucket size `c` (chosen empirically).
+    let c = match scalars.len() < 32 {
+        true => 1,
+        false => crate::msm::ln_without_floats(scalars.len()) + 2,
+    };
+    msm_with_window(bases, scalars, c)
+}
+
+/// Performs the MSM with the given bucket size `c`, which must be nonzero.
+/// Note: For fewer than 15 bases, the window is unused, as the MSM is computed by double-and-add.
+pub fn msm_with_window<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    c: usize,
+) -> G::Projective {
+    assert!(c > 0, "The MSM window size must be nonzero");
+
     if bases.len() < 15 {
         let num_bits = G::ScalarField::size_in_bits();
         let bigint_size = <G::ScalarField as PrimeField>::BigInteger::NUM_LIMBS * 64;
@@ -386,12 +403,6 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         debug_assert!(bits.iter_mut().all(|b| b.next().is_none()));
         sum
     } else {
-        // Determine the bucket size `c` (chosen empirically).
-        let c = match scalars.len() < 32 {
-            true => 1,
-            false => crate::msm::ln_without_floats(scalars.len()) + 2,
-        };
-
         let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
         // Each window is of size `c`.
@@ -49,6 +49,23 @@ impl VariableBase {
         }
     }
 
+    /// Performs the variable base MSM with the given window (bucket) size, which must be nonzero.
+    /// This allows callers to benchmark and pin a window size for their expected number of bases.
+    pub fn msm_with_window<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        window: usize,
+    ) -> G::Projective {
+        // For BLS12-377, we perform variable base MSM using a batched addition technique.
+        if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+            batched::msm_with_window(bases, scalars, window)
+        }
+        // For all other curves, we perform variable base MSM using Pippenger's algorithm.
+        else {
+            standard::msm_with_window(bases, scalars, window)
+        }
+    }
+
     #[cfg(test)]
     fn msm_naive<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         use itertools::Itertools;
@@ -107,6 +124,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_with_window() {
+        use snarkvm_curves::ProjectiveCurve;
+        for msm_size in [1, 10, 50, 500] {
+            let mut rng = TestRng::default();
+            let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+
+            let expected = VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+            for window in 1..=12 {
+                let candidate = VariableBase::msm_with_window(bases.as_slice(), scalars.as_slice(), window).to_affine();
+                assert_eq!(expected, candidate, "MSM size: {msm_size}, window: {window}");
+
+                let candidate = standard::msm_with_window(bases.as_slice(), scalars.as_slice(), window).to_affine();
+                assert_eq!(expected, candidate, "MSM size: {msm_size}, window: {window}");
+            }
+        }
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
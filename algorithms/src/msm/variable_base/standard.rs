@@ -83,6 +83,16 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         true => 1,
         false => crate::msm::ln_without_floats(scalars.len()) + 2,
     };
+    msm_with_window(bases, scalars, c)
+}
+
+/// Performs the MSM with the given bucket size `c`, which must be nonzero.
+pub fn msm_with_window<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    c: usize,
+) -> G::Projective {
+    assert!(c > 0, "The MSM window size must be nonzero");
 
     let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
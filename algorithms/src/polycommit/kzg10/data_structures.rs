@@ -32,7 +32,10 @@ use snarkvm_utilities::{
 };
 
 use anyhow::Result;
-use core::ops::{Add, AddAssign};
+use core::{
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign},
+};
 use parking_lot::RwLock;
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
@@ -220,6 +223,17 @@ impl<E: PairingEngine> ToBytes for VerifierKey<E> {
     }
 }
 
+impl<E: PairingEngine> Hash for VerifierKey<E> {
+    /// Hashes the generators of the verifying key.
+    /// Note: The prepared generators are omitted, as they are derived from `h` and `beta_h`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.g.hash(state);
+        self.gamma_g.hash(state);
+        self.h.hash(state);
+        self.beta_h.hash(state);
+    }
+}
+
 impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifierKey<E> {
     fn to_field_elements(&self) -> Result<Vec<E::Fq>, ConstraintFieldError> {
         let mut res = Vec::new();
@@ -43,6 +43,14 @@ impl<A: Aleo> Inject for ComputeKey<A> {
         let pk_sig = Group::new(mode, compute_key.pk_sig());
         // Inject `pr_sig`.
         let pr_sig = Group::new(mode, compute_key.pr_sig());
+        // Output the compute key.
+        Self::from((pk_sig, pr_sig))
+    }
+}
+
+impl<A: Aleo> From<(Group<A>, Group<A>)> for ComputeKey<A> {
+    /// Derives the account compute key from a tuple `(pk_sig, pr_sig)`.
+    fn from((pk_sig, pr_sig): (Group<A>, Group<A>)) -> Self {
         // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
         let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
         // Output the compute key.
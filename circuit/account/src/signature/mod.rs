@@ -46,6 +46,13 @@ impl<A: Aleo> Inject for Signature<A> {
     }
 }
 
+impl<A: Aleo> From<(Scalar<A>, Scalar<A>, ComputeKey<A>)> for Signature<A> {
+    /// Derives the account signature from a tuple `(challenge, response, compute_key)`.
+    fn from((challenge, response, compute_key): (Scalar<A>, Scalar<A>, ComputeKey<A>)) -> Self {
+        Self { challenge, response, compute_key }
+    }
+}
+
 impl<A: Aleo> Signature<A> {
     /// Returns the challenge.
     pub const fn challenge(&self) -> &Scalar<A> {
@@ -60,5 +60,7 @@ pub trait FromGroup {
     type Scalar: ScalarTrait;
 
     /// Casts a circuit from an affine group element.
-    fn from_group(group: Self::Group) -> Self;
+    fn from_group(group: Self::Group) -> Self
+    where
+        Self: Sized;
 }
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(console)]
+impl<A: Aleo> Record<A, Plaintext<A>> {
+    /// Returns the bytes of the ejected record, which are the bytes of the console record.
+    /// The data entries are prefixed with their number, and each entry is prefixed with its length in bytes.
+    pub fn eject_to_bytes(&self) -> Vec<u8> {
+        use console::ToBytes;
+
+        match self.eject_value().to_bytes_le() {
+            Ok(bytes) => bytes,
+            Err(error) => A::halt(format!("Record::<Plaintext>::eject_to_bytes: {error}")),
+        }
+    }
+
+    /// Initializes a plaintext record from the bytes of an ejected record.
+    pub fn inject_from_bytes(bytes: &[u8]) -> Result<Self> {
+        use console::FromBytes;
+
+        // Deserialize the record, ensuring all of the bytes are consumed.
+        let mut reader = bytes;
+        let record = console::Record::<A::Network, console::Plaintext<A::Network>>::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the record", reader.len());
+
+        Ok(Self::new(Mode::Private, record))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = console::Record::<CurrentNetwork, console::Plaintext<CurrentNetwork>>::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 5u64.public,
+    a: true.private,
+    b: 123u8.public,
+    c: 1field.private,
+    _nonce: 0group.public
+}",
+        )?;
+        let record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, expected.clone());
+
+        // Ensure the bytes round-trip into an equal record.
+        let bytes = record.eject_to_bytes();
+        let candidate = Record::<Circuit, Plaintext<Circuit>>::inject_from_bytes(&bytes)?;
+        assert_eq!(expected, candidate.eject_value());
+        assert!(record.is_equal(&candidate).eject_value());
+        assert_eq!(record.nonce().eject_value(), candidate.nonce().eject_value());
+        assert_eq!(3, candidate.data().len());
+
+        // Ensure truncated and extended bytes are rejected.
+        assert!(Record::<Circuit, Plaintext<Circuit>>::inject_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let extended = [bytes.as_slice(), &[0u8]].concat();
+        assert!(Record::<Circuit, Plaintext<Circuit>>::inject_from_bytes(&extended).is_err());
+
+        Circuit::reset();
+        Ok(())
+    }
+}
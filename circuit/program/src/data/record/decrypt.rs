@@ -18,11 +18,17 @@ use super::*;
 
 impl<A: Aleo> Record<A, Ciphertext<A>> {
     /// Decrypts `self` into a plaintext record using the given view key & nonce.
+    ///
+    /// Note: The circuit is not satisfied if the record owner is not the address of the given view key.
     pub fn decrypt(&self, view_key: &ViewKey<A>) -> Record<A, Plaintext<A>> {
         // Compute the record view key.
         let record_view_key = (&**view_key * &self.nonce).to_x_coordinate();
         // Decrypt the record.
-        self.decrypt_symmetric(record_view_key)
+        let record = self.decrypt_symmetric(record_view_key);
+        // Ensure the record owner is the address of the view key.
+        A::assert((*record.owner).is_equal(&view_key.to_address()));
+        // Return the decrypted record.
+        record
     }
 
     /// Decrypts `self` into a plaintext record using the given record view key.
@@ -134,6 +140,8 @@ mod tests {
         let ciphertext = record.encrypt(&randomizer);
         // Decrypt the record.
         assert_eq!(record.eject(), ciphertext.decrypt(view_key).eject());
+        assert!(A::is_satisfied());
+        A::reset();
         Ok(())
     }
 
@@ -189,4 +197,51 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_decrypt_with_wrong_view_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Generate the view key and address of the owner, and an unrelated view key.
+            let private_key = snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let view_key = snarkvm_console_account::ViewKey::try_from(private_key)?;
+            let address = snarkvm_console_account::Address::try_from(private_key)?;
+            let other_private_key =
+                snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let other_view_key = snarkvm_console_account::ViewKey::try_from(other_private_key)?;
+
+            // Sample the gates, the public entry, and the randomizer.
+            // Note: A private entry is not used, as it does not decrypt to a valid plaintext under the wrong view key.
+            let gates = console::U64::new(u64::rand(&mut rng) >> 12);
+            let entry = Uniform::rand(&mut rng);
+            let randomizer = Uniform::rand(&mut rng);
+
+            for (view_key, is_owner) in [(view_key, true), (other_view_key, false)] {
+                // Prepare the record.
+                let randomizer = Scalar::new(Mode::Private, randomizer);
+                let record = Record {
+                    owner: Owner::Public(Address::<Circuit>::new(Mode::Public, address)),
+                    gates: Balance::Public(U64::new(Mode::Public, gates)),
+                    data: IndexMap::from_iter([(
+                        Identifier::from_str("a")?,
+                        Entry::Public(Plaintext::from(Literal::Field(Field::new(Mode::Public, entry)))),
+                    )]),
+                    nonce: Circuit::g_scalar_multiply(&randomizer),
+                };
+
+                // Encrypt the record, and decrypt it with the view key.
+                let ciphertext = record.encrypt(&randomizer);
+                let candidate = ciphertext.decrypt(&ViewKey::<Circuit>::new(Mode::Private, view_key));
+
+                // Ensure the circuit is only satisfied for the view key of the owner.
+                assert_eq!(is_owner, Circuit::is_satisfied());
+                if is_owner {
+                    assert_eq!(record.eject(), candidate.eject());
+                }
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
 }
@@ -71,8 +71,8 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
             let randomizers = &randomizers[index..index + num_randomizers as usize];
             // Encrypt the entry.
             let entry = match entry {
-                // Constant entries do not need to be encrypted.
-                Entry::Constant(plaintext) => Entry::Constant(plaintext.clone()),
+                // Constant entries are rejected, as only private and public entries may be encrypted.
+                Entry::Constant(..) => A::halt(format!("Cannot encrypt the constant entry '{id}' in a record")),
                 // Public entries do not need to be encrypted.
                 Entry::Public(plaintext) => Entry::Public(plaintext.clone()),
                 // Private entries are encrypted with the given randomizers.
@@ -90,3 +90,90 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
         Record { owner, gates, data: encrypted_data, nonce: self.nonce.clone() }
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_encrypt_with_different_randomizers() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a record with private and public entries.
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 5u64.private,
+    a: true.private,
+    b: 123456789field.public,
+    _nonce: 0group.public
+}",
+        )?;
+        let record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+        let (a, b) = (console::Identifier::from_str("a")?, console::Identifier::from_str("b")?);
+
+        for _ in 0..ITERATIONS {
+            // Encrypts the record under the given randomizer, with the nonce of the randomizer.
+            let encrypt = |randomizer: &Scalar<Circuit>| {
+                let record = Record { nonce: Circuit::g_scalar_multiply(randomizer), ..record.clone() };
+                record.encrypt(randomizer)
+            };
+
+            // Encrypt the record under two randomizers.
+            let ciphertext_a = encrypt(&Scalar::new(Mode::Private, Uniform::rand(&mut rng)));
+            let ciphertext_b = encrypt(&Scalar::new(Mode::Private, Uniform::rand(&mut rng)));
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the ciphertexts have identical lengths.
+            assert_eq!(ciphertext_a.to_bits_le().len(), ciphertext_b.to_bits_le().len());
+
+            // Ensure the private entries differ, while the public entries match.
+            let (ciphertext_a, ciphertext_b) = (ciphertext_a.eject_value(), ciphertext_b.eject_value());
+            assert!(ciphertext_a.owner() != ciphertext_b.owner());
+            assert!(ciphertext_a.gates() != ciphertext_b.gates());
+            assert!(ciphertext_a.data().get(&a) != ciphertext_b.data().get(&a));
+            assert!(ciphertext_a.data().get(&b) == ciphertext_b.data().get(&b));
+
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_rejects_constant_entries() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a record with a constant entry.
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 5u64.private,
+    a: true.private,
+    c: 7u8.constant,
+    _nonce: 0group.public
+}",
+        )?;
+        let record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+
+        // Ensure the record fails to encrypt.
+        let randomizer = Scalar::new(Mode::Private, Uniform::rand(&mut rng));
+        let record = Record { nonce: Circuit::g_scalar_multiply(&randomizer), ..record };
+        let result = std::panic::catch_unwind(|| record.encrypt(&randomizer));
+        assert!(result.is_err());
+
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -23,16 +23,19 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::{Balance, Owner};
 
+mod bytes;
 mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
+mod nonnegative_balance;
 mod num_randomizers;
 mod serial_number;
 mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod validate_schema;
 
 use crate::{Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
 use snarkvm_circuit_account::{PrivateKey, ViewKey};
@@ -90,6 +93,8 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
         data: IndexMap<Identifier<A>, Entry<A, Plaintext<A>>>,
         nonce: Group<A>,
     ) -> Result<Record<A, Plaintext<A>>> {
+        // Ensure the nonce is not a constant, as it must be derived from a secret randomizer.
+        ensure!(!nonce.is_constant(), "The nonce of a record must not be a constant");
         // Ensure the members has no duplicate names.
         ensure!(!has_duplicates(data.iter().map(|(name, ..)| name)), "A duplicate entry name was found in a record");
         // Ensure the number of structs is within `A::Network::MAX_DATA_ENTRIES`.
@@ -109,6 +114,8 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
         data: IndexMap<Identifier<A>, Entry<A, Ciphertext<A>>>,
         nonce: Group<A>,
     ) -> Result<Record<A, Ciphertext<A>>> {
+        // Ensure the nonce is not a constant, as it must be derived from a secret randomizer.
+        ensure!(!nonce.is_constant(), "The nonce of a record must not be a constant");
         // Ensure the members has no duplicate names.
         ensure!(!has_duplicates(data.iter().map(|(name, ..)| name)), "A duplicate entry name was found in a record");
         // Ensure the number of structs is within `A::Network::MAX_DATA_ENTRIES`.
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Record<A, Plaintext<A>> {
+    /// Returns `true` if the balance of the record is non-negative, when read as a signed 64-bit integer.
+    pub fn is_nonnegative_balance(&self) -> Boolean<A> {
+        // The balance is non-negative if its sign bit is not set.
+        !self.gates.msb()
+    }
+
+    /// Enforces that the balance of the record is non-negative, when read as a signed 64-bit integer.
+    pub fn enforce_nonnegative_balance(&self) {
+        // Ensure the sign bit of the balance is zero.
+        A::assert(self.is_nonnegative_balance());
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_types::environment::assert_scope_fails;
+
+    fn sample_record(gates: &str) -> Record<Circuit, Plaintext<Circuit>> {
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(&format!(
+            r"{{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: {gates}.private,
+    a: true.private,
+    _nonce: 0group.public
+}}"
+        ))
+        .unwrap();
+        Record::new(Mode::Private, record)
+    }
+
+    #[test]
+    fn test_nonnegative_balance() {
+        for gates in ["0u64", "5u64", "9223372036854775807u64"] {
+            let record = sample_record(gates);
+
+            Circuit::scope(gates, || {
+                let candidate = record.is_nonnegative_balance();
+                assert!(candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+
+            Circuit::scope(gates, || {
+                record.enforce_nonnegative_balance();
+                assert_scope!(0, 0, 0, 1);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_negative_balance() {
+        // Note: `2^63` and `u64::MAX` are `-2^63` and `-1` when read as signed 64-bit integers.
+        for gates in ["9223372036854775808u64", "18446744073709551615u64"] {
+            let record = sample_record(gates);
+
+            Circuit::scope(gates, || {
+                let candidate = record.is_nonnegative_balance();
+                assert!(!candidate.eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+
+            Circuit::scope(gates, || {
+                record.enforce_nonnegative_balance();
+                assert_scope_fails!(0, 0, 0, 1);
+            });
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
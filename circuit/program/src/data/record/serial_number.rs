@@ -35,3 +35,67 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
         A::commit_bhp512(&(A::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentRecord = Record<Circuit, Plaintext<Circuit>>;
+    type ConsoleRecord =
+        console::Record<<Circuit as Environment>::Network, console::Plaintext<<Circuit as Environment>::Network>>;
+    type ConsolePrivateKey = snarkvm_console_account::PrivateKey<<Circuit as Environment>::Network>;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_serial_number() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two private keys and a commitment.
+            let private_key = ConsolePrivateKey::new(&mut rng)?;
+            let other_private_key = ConsolePrivateKey::new(&mut rng)?;
+            let commitment = Uniform::rand(&mut rng);
+
+            // Compute the expected serial numbers natively.
+            let expected = ConsoleRecord::serial_number(private_key, commitment)?;
+            let other_expected = ConsoleRecord::serial_number(other_private_key, commitment)?;
+            assert_ne!(expected, other_expected);
+
+            // Ensure the circuit matches the native serial number, and is deterministic.
+            let circuit_commitment = Field::<Circuit>::new(Mode::Private, commitment);
+            for _ in 0..2 {
+                let circuit_private_key = PrivateKey::<Circuit>::new(Mode::Private, private_key);
+                let candidate = CurrentRecord::serial_number(circuit_private_key, circuit_commitment.clone());
+                assert_eq!(expected, candidate.eject_value());
+            }
+
+            // Ensure a different private key yields the native serial number for that key.
+            let candidate = CurrentRecord::serial_number(
+                PrivateKey::<Circuit>::new(Mode::Private, other_private_key),
+                circuit_commitment.clone(),
+            );
+            assert_eq!(other_expected, candidate.eject_value());
+
+            // Ensure flipping one bit of the commitment changes the serial number.
+            let mut bits_le = circuit_commitment.to_bits_le();
+            bits_le[0] = !bits_le[0].clone();
+            let flipped_commitment = Field::<Circuit>::from_bits_le(&bits_le);
+            let circuit_private_key = PrivateKey::<Circuit>::new(Mode::Private, private_key);
+            let candidate = CurrentRecord::serial_number(circuit_private_key, flipped_commitment.clone());
+            assert_ne!(expected, candidate.eject_value());
+            assert_eq!(
+                ConsoleRecord::serial_number(private_key, flipped_commitment.eject_value())?,
+                candidate.eject_value()
+            );
+
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
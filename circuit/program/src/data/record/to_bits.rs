@@ -99,3 +99,60 @@ impl<A: Aleo> ToBits for Record<A, Ciphertext<A>> {
         bits_be
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Literal};
+    use snarkvm_circuit_types::{Address, U64};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_to_bits_with_distinct_nonces() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the owner, gates, and data of the records.
+            let address = console::Address::new(Uniform::rand(&mut rng));
+            let owner = Owner::Public(Address::<Circuit>::new(Mode::Public, address));
+            let gates = Balance::Public(U64::new(Mode::Public, console::U64::new(u64::rand(&mut rng) >> 12)));
+            let data = IndexMap::from_iter([(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::new(Mode::Private, Uniform::rand(&mut rng))))),
+            )]);
+
+            // Construct two records that only differ in their nonces.
+            let nonce_a = Group::new(Mode::Private, Uniform::rand(&mut rng));
+            let nonce_b = Group::new(Mode::Private, Uniform::rand(&mut rng));
+            let record_a = Record::<Circuit, Plaintext<Circuit>>::from_plaintext(owner.clone(), gates.clone(), data.clone(), nonce_a)?;
+            let record_b = Record::<Circuit, Plaintext<Circuit>>::from_plaintext(owner, gates, data, nonce_b)?;
+
+            // Ensure the bits of the records have the same length, but differ.
+            let (bits_a, bits_b) = (record_a.to_bits_le().eject_value(), record_b.to_bits_le().eject_value());
+            assert_eq!(bits_a.len(), bits_b.len());
+            assert_ne!(bits_a, bits_b);
+
+            // Ensure the records are not equal.
+            assert!(!record_a.is_equal(&record_b).eject_value());
+
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_plaintext_rejects_constant_nonce() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let address = console::Address::new(Uniform::rand(&mut rng));
+        let owner = Owner::Public(Address::<Circuit>::new(Mode::Public, address));
+        let gates = Balance::Public(U64::new(Mode::Public, console::U64::new(u64::rand(&mut rng) >> 12)));
+        let nonce = Group::new(Mode::Constant, Uniform::rand(&mut rng));
+        assert!(Record::<Circuit, Plaintext<Circuit>>::from_plaintext(owner, gates, IndexMap::new(), nonce).is_err());
+        Ok(())
+    }
+}
@@ -26,6 +26,15 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
         // Compute the BHP hash of the program record.
         A::hash_bhp1024(&input)
     }
+
+    /// Returns a hiding commitment to the record, under the given randomizer.
+    ///
+    /// The record is serialized as `(owner || gates || data_length || data || nonce)`,
+    /// where each data entry is serialized as `(identifier || entry)`, in order.
+    pub fn to_hiding_commitment(&self, randomizer: &Scalar<A>) -> Field<A> {
+        // Compute the BHP commitment of the program record.
+        A::commit_bhp1024(&self.to_bits_le(), randomizer)
+    }
 }
 
 impl<A: Aleo> Record<A, Ciphertext<A>> {
@@ -33,4 +42,120 @@ impl<A: Aleo> Record<A, Ciphertext<A>> {
     pub fn to_commitment(&self, _program_id: &ProgramID<A>, _record_name: &Identifier<A>) -> Field<A> {
         A::halt("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
+
+    /// Returns a hiding commitment to the record, under the given randomizer.
+    pub fn to_hiding_commitment(&self, _randomizer: &Scalar<A>) -> Field<A> {
+        A::halt("Illegal operation: Record::to_hiding_commitment() cannot be invoked on the `Ciphertext` variant.")
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn sample_record(gates: u64) -> Record<Circuit, Plaintext<Circuit>> {
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(&format!(
+            r"{{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: {gates}u64.private,
+    a: true.private,
+    b: 123456789field.public,
+    _nonce: 0group.public
+}}"
+        ))
+        .unwrap();
+        Record::new(Mode::Private, record)
+    }
+
+    #[test]
+    fn test_to_hiding_commitment_matches_console() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a record with data entries, and a record without data entries.
+        let records = [
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 5u64.private,
+    a: true.private,
+    b: 123456789field.public,
+    _nonce: 0group.public
+}",
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 5u64.private,
+    _nonce: 0group.public
+}",
+        ];
+
+        for record in records {
+            let record = console::Record::<
+                <Circuit as Environment>::Network,
+                console::Plaintext<<Circuit as Environment>::Network>,
+            >::from_str(record)?;
+
+            for _ in 0..ITERATIONS {
+                let randomizer = Uniform::rand(&mut rng);
+
+                // Compute the expected commitment natively.
+                let expected = record.to_hiding_commitment(&randomizer)?;
+
+                // Ensure the circuit commitment matches.
+                let candidate = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record.clone())
+                    .to_hiding_commitment(&Scalar::new(Mode::Private, randomizer));
+                assert_eq!(expected, candidate.eject_value());
+
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_hiding_commitment_is_deterministic() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the record and randomizer.
+            let record = sample_record(u64::rand(&mut rng) >> 12);
+            let randomizer = Scalar::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+            // Ensure the same record and randomizer produce the same commitment.
+            let candidate_a = record.to_hiding_commitment(&randomizer);
+            let candidate_b = record.to_hiding_commitment(&randomizer);
+            assert_eq!(candidate_a.eject_value(), candidate_b.eject_value());
+
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_hiding_commitment_differs_on_value_change() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two records that only differ in their gates.
+            let gates = u64::rand(&mut rng) >> 12;
+            let record_a = sample_record(gates);
+            let record_b = sample_record(gates + 1);
+            let randomizer = Scalar::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+            // Ensure the commitments differ.
+            let candidate_a = record_a.to_hiding_commitment(&randomizer);
+            let candidate_b = record_b.to_hiding_commitment(&randomizer);
+            assert_ne!(candidate_a.eject_value(), candidate_b.eject_value());
+
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(console)]
+impl<A: Aleo> Record<A, Plaintext<A>> {
+    /// Ensures the data entries of the record are literals of the given types, in order.
+    pub fn validate_schema(&self, schema: &[console::LiteralType]) -> Result<()> {
+        // Ensure the number of data entries matches the schema.
+        ensure!(
+            self.data.len() == schema.len(),
+            "Record data has {} entries, but the schema expects {}",
+            self.data.len(),
+            schema.len()
+        );
+        // Ensure each data entry is a literal of the expected type.
+        for ((identifier, entry), expected) in self.data.iter().zip_eq(schema) {
+            let plaintext = match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext,
+            };
+            match plaintext {
+                Plaintext::Literal(literal, ..) => {
+                    let literal_type = literal.to_type();
+                    ensure!(
+                        literal_type == *expected,
+                        "Record entry '{identifier}' is a '{literal_type}', but the schema expects a '{expected}'"
+                    )
+                }
+                Plaintext::Struct(..) => {
+                    bail!("Record entry '{identifier}' is a struct, but the schema expects a '{expected}'")
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::LiteralType;
+
+    fn sample_record(mode: Mode) -> Record<Circuit, Plaintext<Circuit>> {
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 0u64.private,
+    a: 5u64.private,
+    b: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.public,
+    c: 123456789field.constant,
+    _nonce: 0group.public
+}",
+        )
+        .unwrap();
+        Record::new(mode, record)
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let record = sample_record(mode);
+
+            // Ensure a matching schema is accepted.
+            assert!(record.validate_schema(&[LiteralType::U64, LiteralType::Address, LiteralType::Field]).is_ok());
+            // Ensure a type mismatch is rejected.
+            assert!(record.validate_schema(&[LiteralType::U64, LiteralType::Address, LiteralType::Group]).is_err());
+            assert!(record.validate_schema(&[LiteralType::U32, LiteralType::Address, LiteralType::Field]).is_err());
+            // Ensure a length mismatch is rejected.
+            assert!(record.validate_schema(&[LiteralType::U64, LiteralType::Address]).is_err());
+            assert!(
+                record
+                    .validate_schema(&[LiteralType::U64, LiteralType::Address, LiteralType::Field, LiteralType::Field])
+                    .is_err()
+            );
+            assert!(record.validate_schema(&[]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_schema_rejects_structs() {
+        let record = console::Record::<
+            <Circuit as Environment>::Network,
+            console::Plaintext<<Circuit as Environment>::Network>,
+        >::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    gates: 0u64.private,
+    a: { b: 5u64.private },
+    _nonce: 0group.public
+}",
+        )
+        .unwrap();
+        let record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+        assert!(record.validate_schema(&[LiteralType::U64]).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Add<Group<E>> for Address<E> {
+    type Output = Group<E>;
+
+    fn add(self, other: Group<E>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment> Add<&Group<E>> for Address<E> {
+    type Output = Group<E>;
+
+    fn add(self, other: &Group<E>) -> Self::Output {
+        self.0 + other
+    }
+}
+
+impl<E: Environment> Add<Address<E>> for Group<E> {
+    type Output = Group<E>;
+
+    fn add(self, other: Address<E>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment> Add<&Address<E>> for Group<E> {
+    type Output = Group<E>;
+
+    fn add(self, other: &Address<E>) -> Self::Output {
+        self + &other.0
+    }
+}
+
+impl<E: Environment> Metrics<dyn Add<Group<E>, Output = Group<E>>> for Address<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        <Group<E> as Metrics<dyn Add<Group<E>, Output = Group<E>>>>::count(case)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Add<Group<E>, Output = Group<E>>> for Address<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Group<E> as OutputMode<dyn Add<Group<E>, Output = Group<E>>>>::output_mode(case)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_add(
+        name: &str,
+        expected: &console::Group<<Circuit as Environment>::Network>,
+        a: &Address<Circuit>,
+        b: &Group<Circuit>,
+    ) {
+        Circuit::scope(name, || {
+            let candidate = a.clone() + b;
+            assert_eq!(*expected, candidate.eject_value(), "({} + {})", a.eject_value(), b.eject_value());
+            assert_count!(Add(Address, Group) => Group, &(a.eject_mode(), b.eject_mode()));
+            assert_output_mode!(Add(Address, Group) => Group, &(a.eject_mode(), b.eject_mode()), candidate);
+        });
+        Circuit::scope(name, || {
+            let candidate = b.clone() + a;
+            assert_eq!(*expected, candidate.eject_value(), "({} + {})", b.eject_value(), a.eject_value());
+            assert_count!(Add(Group, Group) => Group, &(b.eject_mode(), a.eject_mode()));
+            assert_output_mode!(Add(Group, Group) => Group, &(b.eject_mode(), a.eject_mode()), candidate);
+        });
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = console::Address::new(Uniform::rand(&mut rng));
+            let second = Uniform::rand(&mut rng);
+
+            let a = Address::<Circuit>::new(mode_a, first);
+            let b = Group::<Circuit>::new(mode_b, second);
+
+            let expected = first + second;
+
+            let name = format!("Add: a + b {i}");
+            check_add(&name, &expected, &a, &b);
+        }
+    }
+
+    #[test]
+    fn test_constant_plus_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_plus_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_constant_plus_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_plus_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_plus_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_plus_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_plus_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_private_plus_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_plus_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -40,6 +40,24 @@ impl<E: Environment> FromGroup for Address<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn FromGroup<Group = Group<E>, Scalar = Scalar<E>>> for Address<E> {
+    type Case = ();
+
+    #[inline]
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromGroup<Group = Group<E>, Scalar = Scalar<E>>> for Address<E> {
+    type Case = Mode;
+
+    #[inline]
+    fn output_mode(case: &Self::Case) -> Mode {
+        *case
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -50,11 +68,19 @@ mod tests {
         expected: console::Address<<Circuit as Environment>::Network>,
         candidate: &Group<Circuit>,
     ) {
+        let mode = candidate.eject_mode();
         Circuit::scope(name, || {
             // Perform the operation.
             let candidate = Address::from_group(candidate.clone());
             assert_eq!(expected, candidate.eject_value());
             assert_scope!(0, 0, 0, 0);
+            assert_count!(Address<Circuit>, FromGroup<Group = Group<Circuit>, Scalar = Scalar<Circuit>>, &());
+            assert_output_mode!(
+                Address<Circuit>,
+                FromGroup<Group = Group<Circuit>, Scalar = Scalar<Circuit>>,
+                &mode,
+                candidate
+            );
         });
     }
 
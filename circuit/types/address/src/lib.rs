@@ -19,6 +19,7 @@
 
 mod helpers;
 
+mod add;
 mod compare;
 mod equal;
 mod ternary;
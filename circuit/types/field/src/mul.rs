@@ -61,7 +61,11 @@ impl<E: Environment> MulAssign<Field<E>> for Field<E> {
 impl<E: Environment> MulAssign<&Field<E>> for Field<E> {
     fn mul_assign(&mut self, other: &Field<E>) {
         match (self.is_constant(), other.is_constant()) {
-            (true, true) | (false, true) => *self = (&self.linear_combination * *other.eject_value()).into(),
+            (true, true) => *self = (&self.linear_combination * *other.eject_value()).into(),
+            // If either operand is a constant zero, the product is a constant zero.
+            (false, true) if other.eject_value().is_zero() => *self = Field::zero(),
+            (true, false) if self.eject_value().is_zero() => *self = Field::zero(),
+            (false, true) => *self = (&self.linear_combination * *other.eject_value()).into(),
             (true, false) => *self = (&other.linear_combination * *self.eject_value()).into(),
             (false, false) => {
                 let product = witness!(|self, other| self * other);
@@ -90,26 +94,22 @@ impl<E: Environment> OutputMode<dyn Mul<Field<E>, Output = Field<E>>> for Field<
     type Case = (CircuitType<Field<E>>, CircuitType<Field<E>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0.mode(), case.1.mode()) {
-            (Mode::Constant, Mode::Constant) => Mode::Constant,
-            (Mode::Constant, Mode::Public) => match &case.0 {
-                CircuitType::Constant(constant) => match constant.eject_value() {
-                    // TODO: Should this be a constant?
-                    //value if value.is_zero() => Mode::Constant,
-                    value if value.is_one() => Mode::Public,
-                    _ => Mode::Private,
-                },
-                _ => E::halt("The constant is required to determine the output mode of Public * Constant"),
-            },
-            (Mode::Public, Mode::Constant) => match &case.1 {
+        // Returns the output mode of multiplying a variable in the given mode by the given constant.
+        fn output_mode_with_constant<E: Environment>(constant: &CircuitType<Field<E>>, mode: Mode) -> Mode {
+            match constant {
                 CircuitType::Constant(constant) => match constant.eject_value() {
-                    // TODO: Should this be a constant?
-                    //value if value.is_zero() => Mode::Constant,
-                    value if value.is_one() => Mode::Public,
+                    value if value.is_zero() => Mode::Constant,
+                    value if value.is_one() => mode,
                     _ => Mode::Private,
                 },
-                _ => E::halt("The constant is required to determine the output mode of Public * Constant"),
-            },
+                _ => E::halt("The constant is required to determine the output mode of a multiplication by a constant"),
+            }
+        }
+
+        match (case.0.mode(), case.1.mode()) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (Mode::Constant, mode) => output_mode_with_constant(&case.0, mode),
+            (mode, Mode::Constant) => output_mode_with_constant(&case.1, mode),
             (_, _) => Mode::Private,
         }
     }
@@ -348,4 +348,35 @@ mod tests {
         let candidate = Field::<Circuit>::new(Mode::Private, two) * Field::new(Mode::Private, two);
         assert_eq!(four, candidate.eject_value());
     }
+
+    #[test]
+    fn test_mul_by_constant_one_and_zero() {
+        let mut rng = TestRng::default();
+
+        let one = Field::<Circuit>::one();
+        let zero = Field::<Circuit>::zero();
+
+        for mode in [Mode::Public, Mode::Private] {
+            let value = Uniform::rand(&mut rng);
+            let a = Field::<Circuit>::new(mode, value);
+
+            // Ensure multiplying by a constant one preserves the value and mode, without constraints.
+            Circuit::scope(format!("{mode} * 1"), || {
+                for candidate in [&a * &one, &one * &a] {
+                    assert_eq!(value, candidate.eject_value());
+                    assert_eq!(mode, candidate.eject_mode());
+                }
+                assert_scope!(0, 0, 0, 0);
+            });
+
+            // Ensure multiplying by a constant zero yields a constant zero, without constraints.
+            Circuit::scope(format!("{mode} * 0"), || {
+                for candidate in [&a * &zero, &zero * &a] {
+                    assert!(candidate.eject_value().is_zero());
+                    assert!(candidate.is_constant());
+                }
+                assert_scope!(0, 0, 0, 0);
+            });
+        }
+    }
 }
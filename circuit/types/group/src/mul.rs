@@ -180,6 +180,48 @@ impl<E: Environment> MulAssign<&[Boolean<E>]> for Group<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn Mul<Scalar<E>, Output = Group<E>>> for Group<E> {
+    type Case = (CircuitType<Group<E>>, CircuitType<Scalar<E>>);
+
+    fn count(case: &Self::Case) -> Count {
+        match (&case.0, &case.1) {
+            (CircuitType::Constant(_), CircuitType::Constant(scalar)) => {
+                let num_doublings = num_nonzero_bits::<E>(&scalar.eject_value()).saturating_sub(1);
+                Count::less_than(7 * num_doublings + 251, 0, 0, 0)
+            }
+            (CircuitType::Constant(_), _) => Count::less_than(750, 0, 2751, 2752),
+            (_, CircuitType::Constant(scalar)) => {
+                // Each bit after the most-significant nonzero bit costs a double and an addition.
+                let num_doublings = num_nonzero_bits::<E>(&scalar.eject_value()).saturating_sub(1);
+                Count::less_than(3 * num_doublings + 251, 0, 11 * num_doublings, 11 * num_doublings)
+            }
+            (_, _) => Count::less_than(750, 0, 3503, 3504),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Mul<Scalar<E>, Output = Group<E>>> for Group<E> {
+    type Case = (CircuitType<Group<E>>, CircuitType<Scalar<E>>);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (&case.0, &case.1) {
+            (CircuitType::Constant(_), CircuitType::Constant(_)) => Mode::Constant,
+            // If the scalar is a constant zero or one, the output is a constant zero or the group element.
+            (group, CircuitType::Constant(scalar)) => match num_nonzero_bits::<E>(&scalar.eject_value()) {
+                0 => Mode::Constant,
+                1 => group.mode(),
+                _ => Mode::Private,
+            },
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+/// Returns the number of bits up to and including the most-significant nonzero bit of the given scalar.
+fn num_nonzero_bits<E: Environment>(scalar: &console::Scalar<E::Network>) -> u64 {
+    scalar.to_bits_le().iter().rposition(|bit| *bit).map_or(0, |index| index as u64 + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +243,8 @@ mod tests {
             let candidate = a * b;
             assert_eq!(*expected, candidate.eject_value(), "({} * {})", a.eject_value(), b.eject_value());
             assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+            assert_count!(Mul(Group, Scalar) => Group, &(CircuitType::from(a), CircuitType::from(b)));
+            assert_output_mode!(Mul(Group, Scalar) => Group, &(CircuitType::from(a), CircuitType::from(b)), candidate);
         });
         Circuit::reset();
     }
@@ -282,6 +282,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_minus_zero() {
+        let mut rng = TestRng::default();
+
+        // Sample a random element, and the zero element.
+        let first = Uniform::rand(&mut rng);
+        let zero = console::Group::<<Circuit as Environment>::Network>::zero();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = Group::<Circuit>::new(mode_a, first);
+                let b = Group::<Circuit>::new(mode_b, zero);
+
+                Circuit::scope(format!("Sub: a - 0 ({mode_a}, {mode_b})"), || {
+                    let candidate = &a - &b;
+                    assert_eq!(first, candidate.eject_value());
+
+                    // As `a - b` is computed as `a + (-b)`, subtracting a constant zero returns `a` as is,
+                    // which preserves the mode of `a` (see `add`). Otherwise, the output is private.
+                    let expected_mode = match (mode_a, mode_b) {
+                        (_, Mode::Constant) => mode_a,
+                        (_, _) => Mode::Private,
+                    };
+                    assert_eq!(expected_mode, candidate.eject_mode(), "({mode_a} - {mode_b})");
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
     #[test]
     fn test_sub_matches() {
         let mut rng = TestRng::default();
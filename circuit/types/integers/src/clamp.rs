@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Clamp for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `value` clamped into the range `[lower, upper]`.
+    fn clamp(value: &Self, lower: &Self, upper: &Self) -> Self::Output {
+        // Ensure the bounds are ordered.
+        if lower.is_constant() && upper.is_constant() {
+            if lower.eject_value() > upper.eject_value() {
+                E::halt(format!("Failed to clamp into the inverted range [{lower}, {upper}]"))
+            }
+        } else {
+            E::assert(lower.is_less_than_or_equal(upper));
+        }
+        // Raise the value to the lower bound, then cap it at the upper bound.
+        value.maximum(lower).minimum(upper)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Clamp<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_value, mode_lower, mode_upper) = *case;
+
+        // Check the bounds, unless both are constant.
+        let bounds_count = match (mode_lower, mode_upper) {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (_, _) => {
+                count!(Integer<E, I>, Compare<Integer<E, I>, Output = Boolean<E>>, &(mode_lower, mode_upper))
+                    + Count::is(0, 0, 0, 1)
+            }
+        };
+
+        // Raise the value to the lower bound.
+        let maximum_case = (mode_value, mode_lower);
+        let maximum_count = count!(Integer<E, I>, MinMax<Integer<E, I>, Output = Integer<E, I>>, &maximum_case);
+        let maximum_mode = output_mode!(Integer<E, I>, MinMax<Integer<E, I>, Output = Integer<E, I>>, &maximum_case);
+
+        // Cap the value at the upper bound.
+        let minimum_case = (maximum_mode, mode_upper);
+        let minimum_count = count!(Integer<E, I>, MinMax<Integer<E, I>, Output = Integer<E, I>>, &minimum_case);
+
+        bounds_count + maximum_count + minimum_count
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Clamp<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant, Mode::Constant) => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use core::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_clamp<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        lower: console::Integer<<Circuit as Environment>::Network, I>,
+        upper: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_value: Mode,
+        mode_lower: Mode,
+        mode_upper: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_value, value);
+        let b = Integer::<Circuit, I>::new(mode_lower, lower);
+        let c = Integer::<Circuit, I>::new(mode_upper, upper);
+        let case = (mode_value, mode_lower, mode_upper);
+
+        match lower <= upper {
+            true => {
+                let expected = Clamp::clamp(&value, &lower, &upper);
+                Circuit::scope(name, || {
+                    let candidate = Integer::clamp(&a, &b, &c);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_count!(Integer<Circuit, I>, Clamp<Output = Integer<Circuit, I>>, &case);
+                    assert_output_mode!(Integer<Circuit, I>, Clamp<Output = Integer<Circuit, I>>, &case, candidate);
+                });
+            }
+            false => match mode_lower.is_constant() && mode_upper.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(|| Integer::clamp(&a, &b, &c));
+                    assert!(result.is_err(), "Clamping into inverted constant bounds did not halt");
+                }
+                false => Circuit::scope(name, || {
+                    let _candidate = Integer::clamp(&a, &b, &c);
+                    assert_count_fails!(Integer<Circuit, I>, Clamp<Output = Integer<Circuit, I>>, &case);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_value: Mode, mode_lower: Mode, mode_upper: Mode) {
+        let mut rng = TestRng::default();
+
+        // Check values below, inside, and above the range `[1, 3]`.
+        let zero = console::Integer::new(I::zero());
+        let one = console::Integer::new(I::one());
+        let two = console::Integer::new(I::one().wrapping_add(&I::one()));
+        let three = console::Integer::new(I::one().wrapping_add(&I::one()).wrapping_add(&I::one()));
+        let max = console::Integer::new(I::MAX);
+        for (value, description) in [(zero, "below"), (two, "inside"), (max, "above")] {
+            let name = format!("Clamp: ({mode_value}, {mode_lower}, {mode_upper}) - {description} the range");
+            check_clamp::<I>(&name, value, one, three, mode_value, mode_lower, mode_upper);
+        }
+
+        // Check the inverted range `[3, 1]`.
+        let name = format!("Clamp: ({mode_value}, {mode_lower}, {mode_upper}) - inverted range");
+        check_clamp::<I>(&name, two, three, one, mode_value, mode_lower, mode_upper);
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            let lower = Uniform::rand(&mut rng);
+            let upper = Uniform::rand(&mut rng);
+
+            let name = format!("Clamp: ({mode_value}, {mode_lower}, {mode_upper}) - {i}th iteration");
+            check_clamp::<I>(&name, value, lower, upper, mode_value, mode_lower, mode_upper);
+        }
+    }
+
+    test_integer_ternary!(run_test, i8, clamp, between, and);
+    test_integer_ternary!(run_test, i16, clamp, between, and);
+    test_integer_ternary!(run_test, i32, clamp, between, and);
+    test_integer_ternary!(run_test, i64, clamp, between, and);
+    test_integer_ternary!(run_test, i128, clamp, between, and);
+
+    test_integer_ternary!(run_test, u8, clamp, between, and);
+    test_integer_ternary!(run_test, u16, clamp, between, and);
+    test_integer_ternary!(run_test, u32, clamp, between, and);
+    test_integer_ternary!(run_test, u64, clamp, between, and);
+    test_integer_ternary!(run_test, u128, clamp, between, and);
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Double for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the double of `self`, halting on overflow.
+    fn double(&self) -> Self::Output {
+        self.add_checked(self)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Double<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        <Self as Metrics<dyn AddChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(&(*case, *case))
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Double<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn AddChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(&(*case, *case))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_double<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        match value.checked_add(&value) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.double();
+                assert_eq!(expected, *candidate.eject_value());
+                assert_count!(Double(Integer<I>) => Integer<I>, &mode);
+                assert_output_mode!(Double(Integer<I>) => Integer<I>, &mode, candidate);
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.double()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.double();
+                    assert_count_fails!(Double(Integer<I>) => Integer<I>, &mode);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        // Check the 0 and 1 cases.
+        check_double::<I>(&format!("Double: {mode} zero"), console::Integer::zero(), mode);
+        check_double::<I>(&format!("Double: {mode} one"), console::Integer::one(), mode);
+        // Check the overflowing boundary cases.
+        check_double::<I>(&format!("Double: {mode} max"), console::Integer::MAX, mode);
+        check_double::<I>(&format!("Double: {mode} min"), console::Integer::MIN, mode);
+        // Check random values.
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_double::<I>(&format!("Double: {mode} {i}"), value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, double);
+    test_integer_unary!(run_test, i16, double);
+    test_integer_unary!(run_test, i32, double);
+    test_integer_unary!(run_test, i64, double);
+    test_integer_unary!(run_test, i128, double);
+
+    test_integer_unary!(run_test, u8, double);
+    test_integer_unary!(run_test, u16, double);
+    test_integer_unary!(run_test, u32, double);
+    test_integer_unary!(run_test, u64, double);
+    test_integer_unary!(run_test, u128, double);
+}
@@ -25,10 +25,13 @@ pub mod abs_wrapped;
 pub mod add_checked;
 pub mod add_wrapped;
 pub mod and;
+pub mod clamp;
 pub mod compare;
 pub mod div_checked;
 pub mod div_wrapped;
+pub mod double;
 pub mod equal;
+pub mod min_max;
 pub mod modulo;
 pub mod mul_checked;
 pub mod mul_wrapped;
@@ -43,6 +46,7 @@ pub mod shl_checked;
 pub mod shl_wrapped;
 pub mod shr_checked;
 pub mod shr_wrapped;
+pub mod square;
 pub mod sub_checked;
 pub mod sub_wrapped;
 pub mod ternary;
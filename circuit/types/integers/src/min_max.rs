@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> MinMax<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the smaller of `self` and `other`.
+    fn minimum(&self, other: &Self) -> Self::Output {
+        Self::ternary(&self.is_less_than(other), self, other)
+    }
+
+    /// Returns the larger of `self` and `other`.
+    fn maximum(&self, other: &Self) -> Self::Output {
+        Self::ternary(&self.is_less_than(other), other, self)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn MinMax<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Compare the operands, then select between them on the outcome of the comparison.
+        let compare_count = count!(Integer<E, I>, Compare<Integer<E, I>, Output = Boolean<E>>, case);
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => compare_count,
+            (mode_a, mode_b) => {
+                // The outcome of the comparison is private if either operand is not a constant.
+                let ternary_case = (Mode::Private, mode_a, mode_b);
+                let ternary_count =
+                    count!(Integer<E, I>, Ternary<Boolean = Boolean<E>, Output = Integer<E, I>>, &ternary_case);
+                compare_count + ternary_count
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn MinMax<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_min_max<I: IntegerType>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+
+        // Check `minimum`.
+        let expected = if first <= second { first } else { second };
+        Circuit::scope(name, || {
+            let candidate = a.minimum(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(MinMax(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(MinMax(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+
+        // Check `maximum`.
+        let expected = if first >= second { first } else { second };
+        Circuit::scope(name, || {
+            let candidate = a.maximum(&b);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(MinMax(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+            assert_output_mode!(MinMax(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("MinMax: ({mode_a}, {mode_b}) - {i}th iteration");
+            check_min_max::<I>(&name, first, second, mode_a, mode_b);
+        }
+
+        // Check the boundaries, and equal operands.
+        for (first, second) in [(I::MIN, I::MAX), (I::MAX, I::MIN), (I::MAX, I::MAX)] {
+            let first = console::Integer::new(first);
+            let second = console::Integer::new(second);
+
+            let name = format!("MinMax: ({mode_a}, {mode_b}) - ({first}, {second})");
+            check_min_max::<I>(&name, first, second, mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i8, min_max_with);
+    test_integer_binary!(run_test, i16, min_max_with);
+    test_integer_binary!(run_test, i32, min_max_with);
+    test_integer_binary!(run_test, i64, min_max_with);
+    test_integer_binary!(run_test, i128, min_max_with);
+
+    test_integer_binary!(run_test, u8, min_max_with);
+    test_integer_binary!(run_test, u16, min_max_with);
+    test_integer_binary!(run_test, u32, min_max_with);
+    test_integer_binary!(run_test, u64, min_max_with);
+    test_integer_binary!(run_test, u128, min_max_with);
+}
@@ -64,18 +64,18 @@ impl<E: Environment, I: IntegerType> MulAssign<&Integer<E, I>> for Integer<E, I>
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn Mul<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
-        <Self as Metrics<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+        <Self as Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
     }
 }
 
 impl<E: Environment, I: IntegerType> OutputMode<dyn Mul<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        <Self as OutputMode<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+        <Self as OutputMode<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
     }
 }
 
@@ -91,6 +91,15 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
                 Some(value) => Integer::new(Mode::Constant, console::Integer::new(value)),
                 None => E::halt("Integer overflow on multiplication of two constants"),
             }
+        } else if Self::is_constant_zero(self) || Self::is_constant_zero(other) {
+            // If either operand is a constant zero, the product is a constant zero.
+            Self::zero()
+        } else if Self::is_constant_one(self) {
+            // If `self` is a constant one, the product is `other`.
+            other.clone()
+        } else if Self::is_constant_one(other) {
+            // If `other` is a constant one, the product is `self`.
+            self.clone()
         } else if I::is_signed() {
             // Multiply the absolute value of `self` and `other` in the base field.
             // Note that it is safe to use abs_wrapped since we want Integer::MIN to be interpreted as an unsigned number.
@@ -133,6 +142,16 @@ impl<E: Environment, I: IntegerType> MulChecked<Self> for Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Returns `true` if the given integer is a constant zero.
+    fn is_constant_zero(integer: &Integer<E, I>) -> bool {
+        integer.is_constant() && integer.eject_value().is_zero()
+    }
+
+    /// Returns `true` if the given integer is a constant one.
+    fn is_constant_one(integer: &Integer<E, I>) -> bool {
+        integer.is_constant() && integer.eject_value().is_one()
+    }
+
     /// Multiply the integer bits of `this` and `that` in the base field.
     #[inline]
     pub(super) fn mul_with_carry(this: &Integer<E, I>, that: &Integer<E, I>) -> (Integer<E, I>, Vec<Boolean<E>>) {
@@ -185,14 +204,21 @@ impl<E: Environment, I: IntegerType> Integer<E, I> {
 }
 
 impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn count(case: &Self::Case) -> Count {
+        // A constant zero operand yields a new constant zero, and a constant one operand yields the other operand.
+        match constant_operand(case) {
+            Some(value) if value.is_zero() => return Count::is(I::BITS, 0, 0, 0),
+            Some(value) if value.is_one() => return Count::is(0, 0, 0, 0),
+            _ => (),
+        }
+
         // Case 1 - 2 integers fit in 1 field element (u8, u16, u32, u64, i8, i16, i32, i64).
         if 2 * I::BITS < (E::BaseField::size_in_bits() - 1) as u64 {
             match I::is_signed() {
                 // Signed case
-                true => match (case.0, case.1) {
+                true => match (case.0.mode(), case.1.mode()) {
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => {
                         Count::is(4 * I::BITS, 0, (8 * I::BITS) + 5, (8 * I::BITS) + 9)
@@ -200,7 +226,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
                     (_, _) => Count::is(3 * I::BITS, 0, (10 * I::BITS) + 8, (10 * I::BITS) + 13),
                 },
                 // Unsigned case
-                false => match (case.0, case.1) {
+                false => match (case.0.mode(), case.1.mode()) {
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, (3 * I::BITS) - 1, (3 * I::BITS) + 1),
                     (_, _) => Count::is(0, 0, 3 * I::BITS, (3 * I::BITS) + 2),
@@ -211,7 +237,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
         else if (I::BITS + I::BITS / 2) < (E::BaseField::size_in_bits() - 1) as u64 {
             match I::is_signed() {
                 // Signed case
-                true => match (case.0, case.1) {
+                true => match (case.0.mode(), case.1.mode()) {
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => {
                         Count::is(4 * I::BITS, 0, (9 * I::BITS) + 7, (9 * I::BITS) + 12)
@@ -219,7 +245,7 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
                     (_, _) => Count::is(3 * I::BITS, 0, (11 * I::BITS) + 13, (11 * I::BITS) + 19),
                 },
                 // Unsigned case
-                false => match (case.0, case.1) {
+                false => match (case.0.mode(), case.1.mode()) {
                     (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
                     (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, (4 * I::BITS) + 1, (4 * I::BITS) + 4),
                     (_, _) => Count::is(0, 0, (4 * I::BITS) + 5, (4 * I::BITS) + 8),
@@ -234,16 +260,35 @@ impl<E: Environment, I: IntegerType> Metrics<dyn MulChecked<Integer<E, I>, Outpu
 impl<E: Environment, I: IntegerType> OutputMode<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>
     for Integer<E, I>
 {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
             (Mode::Constant, Mode::Constant) => Mode::Constant,
-            (_, _) => Mode::Private,
+            (mode_a, mode_b) => match constant_operand(case) {
+                Some(value) if value.is_zero() => Mode::Constant,
+                // The mode of the non-constant operand.
+                Some(value) if value.is_one() => match mode_a.is_constant() {
+                    true => mode_b,
+                    false => mode_a,
+                },
+                _ => Mode::Private,
+            },
         }
     }
 }
 
+/// Returns the value of the constant operand, if exactly one of the operands is a constant.
+fn constant_operand<E: Environment, I: IntegerType>(
+    case: &(CircuitType<Integer<E, I>>, CircuitType<Integer<E, I>>),
+) -> Option<console::Integer<E::Network, I>> {
+    match case {
+        (CircuitType::Constant(_), CircuitType::Constant(_)) => None,
+        (CircuitType::Constant(constant), _) | (_, CircuitType::Constant(constant)) => Some(constant.eject_value()),
+        (_, _) => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,14 +314,16 @@ mod tests {
                 let candidate = a.mul_checked(&b);
                 assert_eq!(expected, *candidate.eject_value());
                 assert_eq!(console::Integer::new(expected), candidate.eject_value());
-                assert_count!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
-                assert_output_mode!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+                let case = (CircuitType::from(&a), CircuitType::from(&b));
+                assert_count!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &case);
+                assert_output_mode!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &case, candidate);
             }),
             None => match (mode_a, mode_b) {
                 (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::mul_checked),
                 _ => Circuit::scope(name, || {
                     let _candidate = a.mul_checked(&b);
-                    assert_count_fails!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                    let case = (CircuitType::from(&a), CircuitType::from(&b));
+                    assert_count_fails!(MulChecked(Integer<I>, Integer<I>) => Integer<I>, &case);
                 }),
             },
         }
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Square for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the square of `self`, halting on overflow.
+    fn square(&self) -> Self::Output {
+        self.mul_checked(self)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Square<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(I::BITS, 0, 0, 0),
+            Mode::Public => {
+                let case = (CircuitType::Public, CircuitType::Public);
+                <Self as Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(&case)
+            }
+            Mode::Private => {
+                let case = (CircuitType::Private, CircuitType::Private);
+                <Self as Metrics<dyn MulChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(&case)
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Square<Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use core::panic::UnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_square<I: IntegerType + UnwindSafe>(
+        name: &str,
+        value: console::Integer<<Circuit as Environment>::Network, I>,
+        mode: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode, value);
+        match value.checked_mul(&value) {
+            Some(expected) => Circuit::scope(name, || {
+                let candidate = a.square();
+                assert_eq!(expected, *candidate.eject_value());
+                assert_count!(Square(Integer<I>) => Integer<I>, &mode);
+                assert_output_mode!(Square(Integer<I>) => Integer<I>, &mode, candidate);
+            }),
+            None => match mode {
+                Mode::Constant => check_unary_operation_halts(a, |a: Integer<Circuit, I>| a.square()),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.square();
+                    assert_count_fails!(Square(Integer<I>) => Integer<I>, &mode);
+                }),
+            },
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + UnwindSafe>(mode: Mode) {
+        // Check the 0 and 1 cases.
+        check_square::<I>(&format!("Square: {mode} zero"), console::Integer::zero(), mode);
+        check_square::<I>(&format!("Square: {mode} one"), console::Integer::one(), mode);
+        // Check the overflowing maximum case.
+        check_square::<I>(&format!("Square: {mode} max"), console::Integer::MAX, mode);
+        // Check random values.
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let value = Uniform::rand(&mut rng);
+            check_square::<I>(&format!("Square: {mode} {i}"), value, mode);
+        }
+    }
+
+    test_integer_unary!(run_test, i8, square);
+    test_integer_unary!(run_test, i16, square);
+    test_integer_unary!(run_test, i32, square);
+    test_integer_unary!(run_test, i64, square);
+    test_integer_unary!(run_test, i128, square);
+
+    test_integer_unary!(run_test, u8, square);
+    test_integer_unary!(run_test, u16, square);
+    test_integer_unary!(run_test, u32, square);
+    test_integer_unary!(run_test, u64, square);
+    test_integer_unary!(run_test, u128, square);
+}
@@ -77,6 +77,7 @@ pub use nom::{
     bytes::{complete::tag, streaming::take},
     character::complete::{alpha1, alphanumeric1, char, one_of},
     combinator::{complete, fail, map, map_res, opt, recognize},
+    error::context,
     multi::{many0, many1, separated_list0, separated_list1},
     sequence::{pair, terminated},
 };
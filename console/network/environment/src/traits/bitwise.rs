@@ -25,6 +25,16 @@ pub trait Equal<Rhs: ?Sized = Self> {
     fn is_not_equal(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Trait for clamping a value into a range.
+pub trait Clamp {
+    type Output;
+
+    /// Returns `value` clamped into the range `[lower, upper]`.
+    fn clamp(value: &Self, lower: &Self, upper: &Self) -> Self::Output
+    where
+        Self: Sized;
+}
+
 /// Trait for comparator operations.
 pub trait Compare<Rhs: ?Sized = Self> {
     type Output;
@@ -42,6 +52,17 @@ pub trait Compare<Rhs: ?Sized = Self> {
     fn is_greater_than_or_equal(&self, other: &Rhs) -> Self::Output;
 }
 
+/// Trait for selecting the smaller or larger of two values.
+pub trait MinMax<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the smaller of `self` and `other`.
+    fn minimum(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns the larger of `self` and `other`.
+    fn maximum(&self, other: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for performing `NOT (a AND b)`.
 pub trait Nand<Rhs: ?Sized = Self> {
     type Output;
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use nom::{error::VerboseError, IResult};
+use nom::{
+    error::{VerboseError, VerboseErrorKind},
+    IResult,
+};
 
 /// The `nom`-compatible parser return type.
 pub type ParserResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
@@ -26,3 +29,23 @@ pub trait Parser: core::fmt::Display + core::str::FromStr {
     where
         Self: Sized;
 }
+
+/// Returns the remaining input at the innermost failure of the given parser error,
+/// along with the innermost context label attached to the failure (if any).
+///
+/// Returns `None` if the parser error indicates the input is incomplete.
+pub fn parser_error_location<'a>(error: &nom::Err<VerboseError<&'a str>>) -> Option<(&'a str, Option<&'static str>)> {
+    match error {
+        nom::Err::Error(error) | nom::Err::Failure(error) => {
+            // Retrieve the remaining input at the innermost failure.
+            let (remaining, _) = error.errors.first()?;
+            // Retrieve the innermost context label.
+            let context = error.errors.iter().find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(*context),
+                _ => None,
+            });
+            Some((remaining, context))
+        }
+        nom::Err::Incomplete(_) => None,
+    }
+}
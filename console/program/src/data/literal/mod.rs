@@ -17,6 +17,7 @@
 mod bytes;
 mod equal;
 mod from_bits;
+mod neg;
 mod parse;
 mod sample;
 mod serialize;
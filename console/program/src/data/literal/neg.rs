@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the negation of the literal, matching the semantics of the `neg` instruction.
+    ///
+    /// # Errors
+    /// This method will error if the negation overflows, or if the literal type does not support negation.
+    pub fn neg_checked(&self) -> Result<Self> {
+        match self {
+            Self::Field(field) => Ok(Self::Field(-*field)),
+            Self::Group(group) => Ok(Self::Group(-*group)),
+            Self::I8(integer) => match (**integer).checked_neg() {
+                Some(value) => Ok(Self::I8(I8::new(value))),
+                None => bail!("Integer negation overflowed on '{self}'"),
+            },
+            Self::I16(integer) => match (**integer).checked_neg() {
+                Some(value) => Ok(Self::I16(I16::new(value))),
+                None => bail!("Integer negation overflowed on '{self}'"),
+            },
+            Self::I32(integer) => match (**integer).checked_neg() {
+                Some(value) => Ok(Self::I32(I32::new(value))),
+                None => bail!("Integer negation overflowed on '{self}'"),
+            },
+            Self::I64(integer) => match (**integer).checked_neg() {
+                Some(value) => Ok(Self::I64(I64::new(value))),
+                None => bail!("Integer negation overflowed on '{self}'"),
+            },
+            Self::I128(integer) => match (**integer).checked_neg() {
+                Some(value) => Ok(Self::I128(I128::new(value))),
+                None => bail!("Integer negation overflowed on '{self}'"),
+            },
+            _ => bail!("Cannot negate a literal of type '{}'", self.to_type()),
+        }
+    }
+
+    /// Returns the negation of the literal, wrapping around at the boundary of the type.
+    ///
+    /// # Errors
+    /// This method will error if the literal type does not support negation.
+    pub fn neg_wrapped(&self) -> Result<Self> {
+        match self {
+            Self::I8(integer) => Ok(Self::I8(I8::new((**integer).wrapping_neg()))),
+            Self::I16(integer) => Ok(Self::I16(I16::new((**integer).wrapping_neg()))),
+            Self::I32(integer) => Ok(Self::I32(I32::new((**integer).wrapping_neg()))),
+            Self::I64(integer) => Ok(Self::I64(I64::new((**integer).wrapping_neg()))),
+            Self::I128(integer) => Ok(Self::I128(I128::new((**integer).wrapping_neg()))),
+            _ => self.neg_checked(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_neg() -> Result<()> {
+        for (input, expected) in [("5i8", "-5i8"), ("-5i8", "5i8"), ("0i8", "0i8"), ("127i8", "-127i8")] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            let expected = Literal::<CurrentNetwork>::from_str(expected)?;
+            assert_eq!(expected, literal.neg_checked()?);
+            assert_eq!(expected, literal.neg_wrapped()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_neg_min_value() -> Result<()> {
+        let literal = Literal::<CurrentNetwork>::from_str("-128i8")?;
+        assert!(literal.neg_checked().is_err());
+        assert_eq!(literal, literal.neg_wrapped()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_neg_unsupported() -> Result<()> {
+        for input in ["true", "5u8", "1scalar"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            assert!(literal.neg_checked().is_err());
+            assert!(literal.neg_wrapped().is_err());
+        }
+        Ok(())
+    }
+}
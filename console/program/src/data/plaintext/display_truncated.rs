@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext as a single-line string, showing at most `max_len` members of each struct,
+    /// and eliding the remaining members with `...(N more)`.
+    pub fn display_truncated(&self, max_len: usize) -> String {
+        match self {
+            // Prints the literal, i.e. 10field
+            Self::Literal(literal, ..) => literal.to_string(),
+            // Prints the struct, i.e. { first: 10i64, second: 198u64, ...(3 more) }
+            Self::Struct(struct_, ..) => {
+                let mut members = struct_
+                    .iter()
+                    .take(max_len)
+                    .map(|(name, plaintext)| format!("{name}: {}", plaintext.display_truncated(max_len)))
+                    .collect::<Vec<_>>();
+                if struct_.len() > max_len {
+                    members.push(format!("...({} more)", struct_.len() - max_len));
+                }
+                format!("{{ {} }}", members.join(", "))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_display_truncated() -> Result<()> {
+        // Ensure a small struct is shown in full.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1field, b: { c: 2u8, d: true } }")?;
+        assert_eq!("{ a: 1field, b: { c: 2u8, d: true } }", plaintext.display_truncated(2));
+
+        // Ensure a large struct is truncated at the limit.
+        let members = (0..10).map(|i| format!("m{i}: {i}u64")).join(", ");
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(&format!("{{ {members} }}"))?;
+        assert_eq!("{ m0: 0u64, m1: 1u64, m2: 2u64, ...(7 more) }", plaintext.display_truncated(3));
+
+        // Ensure nested structs are truncated at the limit.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: { b: 1u8, c: 2u8, d: 3u8 }, e: 4u8, f: 5u8 }")?;
+        assert_eq!("{ a: { b: 1u8, ...(2 more) }, ...(2 more) }", plaintext.display_truncated(1));
+
+        // Ensure a literal is shown in full.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("5field")?;
+        assert_eq!("5field", plaintext.display_truncated(0));
+        Ok(())
+    }
+}
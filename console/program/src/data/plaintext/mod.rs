@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod display_truncated;
 mod encrypt;
 mod equal;
 mod find;
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the record as a single-line string, showing at most `max_len` entries,
+    /// and eliding the remaining entries with `...(N more)`.
+    /// Each entry is truncated as a plaintext, followed by its visibility.
+    pub fn display_truncated(&self, max_len: usize) -> String {
+        // Prints the owner and gates, i.e. owner: aleo1...private, gates: 5u64.private
+        let mut members = vec![format!("owner: {}", self.owner), format!("gates: {}", self.gates)];
+        // Prints the entries, i.e. first: 10i64.public, second: { a: 1u8, ...(3 more) }.private
+        members.extend(self.data.iter().take(max_len).map(|(identifier, entry)| {
+            let (plaintext, visibility) = match entry {
+                Entry::Constant(constant) => (constant, "constant"),
+                Entry::Public(public) => (public, "public"),
+                Entry::Private(private) => (private, "private"),
+            };
+            format!("{identifier}: {}.{visibility}", plaintext.display_truncated(max_len))
+        }));
+        if self.data.len() > max_len {
+            members.push(format!("...({} more)", self.data.len() - max_len));
+        }
+        // Prints the nonce, i.e. _nonce: 0group.public
+        members.push(format!("_nonce: {}.public", self.nonce));
+        format!("{{ {} }}", members.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const OWNER: &str = "aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add";
+
+    #[test]
+    fn test_display_truncated() -> Result<()> {
+        // Ensure a small record is shown in full.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {OWNER}.private, gates: 5u64.private, a: 1u8.public, _nonce: 0group.public }}"
+        ))?;
+        assert_eq!(record.to_string().split_whitespace().join(" "), record.display_truncated(2));
+
+        // Ensure the entries and their structs are truncated at the limit.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {OWNER}.public, gates: 5u64.public, a: {{ b: 1u8.private, c: 2u8.private }}, d: 3u8.constant, \
+            e: 4u8.public, _nonce: 0group.public }}"
+        ))?;
+        let expected = format!(
+            "{{ owner: {OWNER}.public, gates: 5u64.public, a: {{ b: 1u8, ...(1 more) }}.private, ...(2 more), \
+            _nonce: 0group.public }}"
+        );
+        assert_eq!(expected, record.display_truncated(1));
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ pub use helpers::{Balance, Owner};
 
 mod bytes;
 mod decrypt;
+mod display_truncated;
 mod encrypt;
 mod equal;
 mod find;
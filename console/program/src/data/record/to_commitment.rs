@@ -26,6 +26,15 @@ impl<N: Network> Record<N, Plaintext<N>> {
         // Compute the BHP hash of the program record.
         N::hash_bhp1024(&input)
     }
+
+    /// Returns a hiding commitment to the record, under the given randomizer.
+    ///
+    /// The record is serialized as `(owner || gates || data_length || data || nonce)`,
+    /// where each data entry is serialized as `(identifier || entry)`, in order.
+    pub fn to_hiding_commitment(&self, randomizer: &Scalar<N>) -> Result<Field<N>> {
+        // Compute the BHP commitment of the program record.
+        N::commit_bhp1024(&self.to_bits_le(), randomizer)
+    }
 }
 
 impl<N: Network> Record<N, Ciphertext<N>> {
@@ -33,4 +42,9 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     pub fn to_commitment(&self, _program_id: &ProgramID<N>, _record_name: &Identifier<N>) -> Result<Field<N>> {
         bail!("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
+
+    /// Returns a hiding commitment to the record, under the given randomizer.
+    pub fn to_hiding_commitment(&self, _randomizer: &Scalar<N>) -> Result<Field<N>> {
+        bail!("Illegal operation: Record::to_hiding_commitment() cannot be invoked on the `Ciphertext` variant.")
+    }
 }
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as a string, showing at most `max_len` members of each struct and entries of each record,
+    /// and eliding the remaining members with `...(N more)`.
+    pub fn display_truncated(&self, max_len: usize) -> String {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.display_truncated(max_len),
+            Self::Record(record) => record.display_truncated(max_len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_display_truncated() -> Result<()> {
+        // Ensure a large struct is truncated at the limit.
+        let members = (0..32).map(|i| format!("m{i}: {i}field")).join(", ");
+        let value = Value::<CurrentNetwork>::from_str(&format!("{{ {members} }}"))?;
+        assert_eq!("{ m0: 0field, m1: 1field, ...(30 more) }", value.display_truncated(2));
+
+        // Ensure a small struct is shown in full.
+        let value = Value::<CurrentNetwork>::from_str("{ m0: 0field, m1: 1field }")?;
+        assert_eq!("{ m0: 0field, m1: 1field }", value.display_truncated(2));
+
+        // Ensure a large record is truncated at the limit.
+        let owner = "aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add";
+        let entries = (0..32).map(|i| format!("m{i}: {i}field.private")).join(", ");
+        let value = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {owner}.private, gates: 5u64.private, {entries}, _nonce: 0group.public }}"
+        ))?;
+        let expected = format!(
+            "{{ owner: {owner}.private, gates: 5u64.private, m0: 0field.private, m1: 1field.private, ...(30 more), \
+            _nonce: 0group.public }}"
+        );
+        assert_eq!(expected, value.display_truncated(2));
+        Ok(())
+    }
+}
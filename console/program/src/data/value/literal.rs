@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the literal, if the value is a literal.
+    pub const fn as_literal(&self) -> Option<&Literal<N>> {
+        match self {
+            Self::Plaintext(Plaintext::Literal(literal, ..)) => Some(literal),
+            Self::Plaintext(Plaintext::Struct(..)) | Self::Record(..) => None,
+        }
+    }
+
+    /// Returns the literal, if the value is a literal.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a literal, with the given context as the subject of the error.
+    pub fn into_literal(self, context: &str) -> Result<Literal<N>> {
+        match self {
+            Self::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
+            Self::Plaintext(Plaintext::Struct(..)) | Self::Record(..) => bail!("{context} must be a literal"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_literal() -> Result<()> {
+        // Ensure a literal value returns its literal.
+        let value = Value::<CurrentNetwork>::from_str("5u64")?;
+        let expected = Literal::<CurrentNetwork>::from_str("5u64")?;
+        assert_eq!(Some(&expected), value.as_literal());
+        assert_eq!(expected, value.into_literal("Operand")?);
+
+        // Ensure a struct value is not a literal, and the error includes the context.
+        let value = Value::<CurrentNetwork>::from_str("{ x: 5u64 }")?;
+        assert_eq!(None, value.as_literal());
+        let error = value.into_literal("The first operand of 'add'").unwrap_err();
+        assert_eq!("The first operand of 'add' must be a literal", error.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Add<Group<E>> for Address<E> {
+    type Output = Group<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: Group<E>) -> Self::Output {
+        self.address + other
+    }
+}
+
+impl<E: Environment> Add<&Group<E>> for Address<E> {
+    type Output = Group<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: &Group<E>) -> Self::Output {
+        self.address + other
+    }
+}
+
+impl<E: Environment> Add<Address<E>> for Group<E> {
+    type Output = Group<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: Address<E>) -> Self::Output {
+        self + other.address
+    }
+}
+
+impl<E: Environment> Add<&Address<E>> for Group<E> {
+    type Output = Group<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: &Address<E>) -> Self::Output {
+        self + other.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_add() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random address and group element.
+            let address = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+            let group: Group<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Ensure the sum matches the sum of the underlying group elements, in either order.
+            let expected = *address + group;
+            assert_eq!(expected, address + group);
+            assert_eq!(expected, group + address);
+            assert_eq!(expected, address + &group);
+            assert_eq!(expected, group + &address);
+        }
+        Ok(())
+    }
+}
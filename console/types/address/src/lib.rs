@@ -17,6 +17,7 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 #![warn(clippy::cast_possible_truncation)]
 
+mod arithmetic;
 mod bitwise;
 mod bytes;
 mod from_bits;
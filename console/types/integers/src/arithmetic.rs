@@ -411,6 +411,19 @@ impl<E: Environment, I: IntegerType, M: Magnitude> PowWrapped<Integer<E, M>> for
     }
 }
 
+impl<E: Environment, I: IntegerType> Double for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `double` of `self`.
+    #[inline]
+    fn double(&self) -> Self::Output {
+        match self.integer.checked_add(&self.integer) {
+            Some(integer) => Integer::new(integer),
+            None => E::halt(format!("Integer double failed on: {}", self.integer)),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Square for Integer<E, I> {
     type Output = Integer<E, I>;
 
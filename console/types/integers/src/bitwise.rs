@@ -54,6 +54,38 @@ impl<E: Environment, I: IntegerType> Compare<Self> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> MinMax<Self> for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns the smaller of `self` and `other`.
+    fn minimum(&self, other: &Self) -> Self::Output {
+        match self.integer <= other.integer {
+            true => *self,
+            false => *other,
+        }
+    }
+
+    /// Returns the larger of `self` and `other`.
+    fn maximum(&self, other: &Self) -> Self::Output {
+        match self.integer >= other.integer {
+            true => *self,
+            false => *other,
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Clamp for Integer<E, I> {
+    type Output = Self;
+
+    /// Returns `value` clamped into the range `[lower, upper]`.
+    fn clamp(value: &Self, lower: &Self, upper: &Self) -> Self::Output {
+        match lower.integer <= upper.integer {
+            true => value.maximum(lower).minimum(upper),
+            false => E::halt(format!("Failed to clamp {value} into the inverted range [{lower}, {upper}]")),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Not for Integer<E, I> {
     type Output = Self;
 
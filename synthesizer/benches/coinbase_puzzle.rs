@@ -20,7 +20,7 @@
 extern crate criterion;
 
 use console::{account::*, network::Testnet3};
-use snarkvm_synthesizer::{CoinbasePuzzle, EpochChallenge, PuzzleConfig};
+use snarkvm_synthesizer::{CoinbasePuzzle, EpochChallenge, ProverPolynomialCache, PuzzleConfig};
 
 use criterion::Criterion;
 use rand::{self, thread_rng, CryptoRng, RngCore};
@@ -81,6 +81,35 @@ fn coinbase_puzzle_prove(c: &mut Criterion) {
     }
 }
 
+#[cfg(feature = "setup")]
+fn coinbase_puzzle_prover_polynomial(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let universal_srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for degree in [(1 << 13) - 1] {
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzleInst::trim(&universal_srs, config).unwrap();
+        let (epoch_challenge, address, nonce) = sample_inputs(degree, rng);
+        let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+
+        c.bench_function(&format!("ProverSolution::ProverPolynomial 2^{}", ((degree + 1) as f64).log2()), |b| {
+            b.iter(|| solution.to_prover_polynomial(&epoch_challenge).unwrap())
+        });
+
+        // Populate the cache, so that every iteration is served from the cache.
+        let cache = ProverPolynomialCache::new();
+        let _ = solution.to_prover_polynomial_cached(&cache, &epoch_challenge).unwrap();
+
+        c.bench_function(
+            &format!("ProverSolution::ProverPolynomial (cached) 2^{}", ((degree + 1) as f64).log2()),
+            |b| b.iter(|| solution.to_prover_polynomial_cached(&cache, &epoch_challenge).unwrap()),
+        );
+    }
+}
+
 #[cfg(feature = "setup")]
 fn coinbase_puzzle_accumulate(c: &mut Criterion) {
     let rng = &mut thread_rng();
@@ -140,10 +169,56 @@ fn coinbase_puzzle_verify(c: &mut Criterion) {
     }
 }
 
+/// Benchmarks verification across MSM window sizes, to find the fastest window for a given batch size.
+/// Note: The MSM is a small share of verification at these batch sizes, so expect modest differences.
+#[cfg(feature = "setup")]
+fn coinbase_puzzle_verify_with_msm_config(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let universal_srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for degree in [(1 << 13) - 1] {
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzleInst::trim(&universal_srs, config).unwrap();
+        let epoch_challenge = sample_epoch_challenge(degree, rng);
+
+        for batch_size in [100, 500] {
+            let solutions = (0..batch_size)
+                .map(|_| {
+                    let (address, nonce) = sample_address_and_nonce(rng);
+                    puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+                })
+                .collect::<Vec<_>>();
+            let solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+
+            for msm_window in [4, 8, 12] {
+                c.bench_function(
+                    &format!(
+                        "CoinbasePuzzle::Verify {batch_size} of 2^{} (MSM window {msm_window})",
+                        ((degree + 1) as f64).log2()
+                    ),
+                    |b| {
+                        b.iter(|| {
+                            assert!(
+                                puzzle
+                                    .verify_with_msm_config(msm_window, &solution, &epoch_challenge, 0u64, 0u64)
+                                    .unwrap()
+                            )
+                        })
+                    },
+                );
+            }
+        }
+    }
+}
+
 criterion_group! {
     name = coinbase_puzzle;
     config = Criterion::default().sample_size(10);
-    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_accumulate, coinbase_puzzle_verify,
+    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_prover_polynomial,
+        coinbase_puzzle_accumulate, coinbase_puzzle_verify, coinbase_puzzle_verify_with_msm_config,
 }
 
 criterion_main!(coinbase_puzzle);
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use console::{
+    network::Testnet3,
+    prelude::*,
+    program::{Identifier, Value},
+    types::{Address, Field},
+};
+use snarkvm_synthesizer::{Authorization, CallStack, ExecutionTrace, Process, Program, Stack};
+
+use criterion::Criterion;
+
+type CurrentNetwork = Testnet3;
+type CurrentAleo = circuit::AleoV0;
+
+/// Benchmarks closure evaluation with tracing disabled and enabled.
+/// With tracing disabled, the evaluation only adds a branch per instruction.
+fn evaluate_closure(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a closure.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program testing.aleo;
+
+closure compute:
+    input r0 as u32;
+    input r1 as u32;
+    add r0 r1 into r2;
+    mul r2 2u32 into r3;
+    assert.eq r3 10u32;
+    output r3 as u32;
+
+function run:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+    )
+    .unwrap();
+    let closure = program.get_closure(&Identifier::from_str("compute").unwrap()).unwrap();
+
+    // Initialize the stack.
+    let stack = Stack::new(&Process::load().unwrap(), &program).unwrap();
+
+    // Sample the inputs, the caller, and the transition view key.
+    let inputs = [Value::from_str("2u32").unwrap(), Value::from_str("3u32").unwrap()];
+    let caller = Address::new(Uniform::rand(rng));
+    let tvk = Field::rand(rng);
+    let call_stack = || CallStack::evaluate(Authorization::new(&[])).unwrap();
+
+    c.bench_function("Stack::evaluate_closure", |b| {
+        b.iter(|| stack.evaluate_closure::<CurrentAleo>(&closure, &inputs, call_stack(), caller, tvk).unwrap())
+    });
+    c.bench_function("Stack::evaluate_closure_with_trace", |b| {
+        b.iter(|| {
+            let mut trace = ExecutionTrace::new();
+            stack
+                .evaluate_closure_with_trace::<CurrentAleo>(&closure, &inputs, call_stack(), caller, tvk, &mut trace)
+                .unwrap()
+        })
+    });
+}
+
+criterion_group! {
+    name = process;
+    config = Criterion::default().sample_size(10);
+    targets = evaluate_closure
+}
+
+criterion_main!(process);
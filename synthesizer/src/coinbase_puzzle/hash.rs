@@ -79,3 +79,58 @@ pub fn hash_commitments<E: PairingEngine>(
     // Hash the commitment bytes into coefficients.
     Ok(hash_to_coefficients(&bytes, num_commitments + 1))
 }
+
+/// Derives the challenge points for the given commitments, exactly as in `accumulate` and `verify`.
+///
+/// The commitments are serialized uncompressed (96 bytes each), concatenated in the given order,
+/// and hashed with BLAKE2s-256. The `i`-th challenge point is the BLAKE2b-512 hash of this digest
+/// followed by `i` as a little-endian `u32`, reduced modulo the scalar field.
+///
+/// One challenge point is derived for each commitment, in order, followed by one more,
+/// so the last challenge point is always the accumulator challenge point.
+pub fn derive_challenges<E: PairingEngine>(commitments: &[KZGCommitment<E>]) -> Result<Vec<E::Fr>> {
+    // Compute the challenge points.
+    let challenges = hash_commitments(commitments.iter().copied())?;
+    ensure!(challenges.len() == commitments.len() + 1, "Invalid number of challenge points");
+    Ok(challenges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::{Rng, TestRng};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+
+    #[test]
+    fn test_derive_challenges() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_commitments in 1..10 {
+            let commitments = (0..num_commitments).map(|_| KZGCommitment::<Bls12_377>(rng.gen())).collect::<Vec<_>>();
+            let challenges = derive_challenges(&commitments)?;
+            assert_eq!(num_commitments + 1, challenges.len());
+
+            // Ensure the challenge points match the documented derivation.
+            let mut bytes = Vec::new();
+            for commitment in &commitments {
+                commitment.serialize_uncompressed(&mut bytes)?;
+            }
+            assert_eq!(96 * num_commitments, bytes.len());
+            let digest = blake2::Blake2s256::digest(&bytes);
+            for (counter, challenge) in challenges.iter().enumerate() {
+                let input = [digest.as_slice(), &(counter as u32).to_le_bytes()].concat();
+                assert_eq!(Fr::from_bytes_le_mod_order(&blake2::Blake2b512::digest(input)), *challenge);
+            }
+
+            // Ensure the challenge points depend on the order of the commitments.
+            if num_commitments > 1 {
+                let reversed = commitments.iter().rev().copied().collect::<Vec<_>>();
+                assert_ne!(challenges, derive_challenges(&reversed)?);
+            }
+        }
+
+        // Ensure there must be at least one commitment.
+        assert!(derive_challenges::<Bls12_377>(&[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An accumulator of prover solutions, for building a coinbase solution incrementally.
+/// Invalid prover solutions are rejected as they are pushed, rather than when the coinbase solution is built.
+#[derive(Clone)]
+pub struct Accumulator<N: Network> {
+    /// The coinbase puzzle.
+    puzzle: CoinbasePuzzle<N>,
+    /// The prover solutions, in the order they were pushed.
+    prover_solutions: Vec<ProverSolution<N>>,
+}
+
+impl<N: Network> Accumulator<N> {
+    /// Initializes a new, empty accumulator for the given coinbase puzzle.
+    pub fn new(puzzle: CoinbasePuzzle<N>) -> Self {
+        Self { puzzle, prover_solutions: Vec::new() }
+    }
+
+    /// Returns the prover solutions in the accumulator.
+    pub fn prover_solutions(&self) -> &[ProverSolution<N>] {
+        &self.prover_solutions
+    }
+
+    /// Returns the number of prover solutions in the accumulator.
+    pub fn len(&self) -> usize {
+        self.prover_solutions.len()
+    }
+
+    /// Returns `true` if there are no prover solutions in the accumulator.
+    pub fn is_empty(&self) -> bool {
+        self.prover_solutions.is_empty()
+    }
+
+    /// Adds the given prover solution to the accumulator.
+    pub fn push(&mut self, prover_solution: ProverSolution<N>) -> Result<()> {
+        // Ensure the number of prover solutions does not exceed `MAX_PROVER_SOLUTIONS`.
+        ensure!(
+            self.prover_solutions.len() < N::MAX_PROVER_SOLUTIONS,
+            "Cannot accumulate beyond {} prover solutions",
+            N::MAX_PROVER_SOLUTIONS
+        );
+        // Ensure the prover solution is non-hiding.
+        ensure!(!prover_solution.proof().is_hiding(), "Cannot accumulate a prover solution with a hiding proof");
+        // Ensure the prover solution is not a duplicate.
+        ensure!(!self.prover_solutions.contains(&prover_solution), "Cannot accumulate a duplicate prover solution");
+        self.prover_solutions.push(prover_solution);
+        Ok(())
+    }
+
+    /// Returns the coinbase solution for the given epoch challenge and the accumulated prover solutions.
+    pub fn finalize(self, epoch_challenge: &EpochChallenge<N>) -> Result<CoinbaseSolution<N>> {
+        self.puzzle.accumulate_unchecked(epoch_challenge, &self.prover_solutions)
+    }
+}
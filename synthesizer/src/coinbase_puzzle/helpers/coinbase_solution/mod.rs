@@ -20,6 +20,8 @@ mod string;
 
 use super::*;
 
+use indexmap::IndexSet;
+
 /// The coinbase puzzle solution constructed by accumulating the individual prover solutions.
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct CoinbaseSolution<N: Network> {
@@ -60,19 +62,122 @@ impl<N: Network> CoinbaseSolution<N> {
         self.partial_solutions.is_empty()
     }
 
+    /// Returns a share record for each partial solution.
+    pub fn to_share_records(&self) -> Result<Vec<ShareRecord>> {
+        self.partial_solutions.iter().map(ShareRecord::from_partial_solution).collect()
+    }
+
     /// Returns the cumulative sum of the prover solutions.
+    ///
+    /// The sum is computed exactly as a `u128`, and returns an error (rather than wrapping) on overflow.
+    /// Note: As each target is at most `u64::MAX`, overflow requires more than `2^64` partial solutions.
     pub fn to_cumulative_proof_target(&self) -> Result<u128> {
+        let targets = self.partial_solutions.iter().map(|solution| solution.to_target()).collect::<Result<Vec<_>>>()?;
+        Self::checked_cumulative_target(targets)
+    }
+
+    /// Returns `true` if each partial solution meets the proof target, and the cumulative target
+    /// meets the coinbase target, and `false` otherwise.
+    ///
+    /// This check skips the prover polynomials and the pairing check, so it is a cheap first-pass filter;
+    /// use `CoinbasePuzzle::verify` to fully verify the coinbase solution.
+    /// An error is returned only if the coinbase solution or the epoch challenge is malformed.
+    pub fn check_targets(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        // Ensure the epoch challenge is well-formed.
+        ensure!(epoch_challenge.is_well_formed(), "The epoch challenge is malformed");
+        // Ensure the coinbase solution is not empty.
+        ensure!(!self.is_empty(), "The coinbase solution does not contain any partial solutions");
+        // Ensure the number of partial solutions does not exceed `MAX_PROVER_SOLUTIONS`.
+        ensure!(
+            self.len() <= N::MAX_PROVER_SOLUTIONS,
+            "The coinbase solution exceeds the allowed number of partial solutions. ({} > {})",
+            self.len(),
+            N::MAX_PROVER_SOLUTIONS
+        );
+        // Ensure the puzzle commitments are unique.
+        ensure!(
+            !has_duplicates(self.puzzle_commitments()),
+            "The coinbase solution contains duplicate puzzle commitments"
+        );
+
+        // Ensure each of the partial solutions meets the proof target.
+        for partial_solution in &self.partial_solutions {
+            if !target_meets(&partial_solution.commitment(), proof_target)? {
+                return Ok(false);
+            }
+        }
+        // Ensure the cumulative target meets the coinbase target.
+        Ok(self.to_cumulative_proof_target()? >= coinbase_target as u128)
+    }
+
+    /// Returns the proof target contributed by the given prover address, or `None` if the address is not present.
+    /// If the address has more than one partial solution, the sum of their targets is returned.
+    /// An error is returned if the epoch challenge is malformed.
+    pub fn proof_target_of(&self, address: &Address<N>, epoch_challenge: &EpochChallenge<N>) -> Result<Option<u64>> {
+        // Ensure the epoch challenge is well-formed.
+        ensure!(epoch_challenge.is_well_formed(), "The epoch challenge is malformed");
+        // Sum the targets of the partial solutions of the address.
+        self.partial_solutions.iter().filter(|solution| solution.address() == *address).try_fold(
+            None,
+            |cumulative: Option<u64>, solution| match cumulative.unwrap_or(0).checked_add(solution.to_target()?) {
+                Some(target) => Ok(Some(target)),
+                None => bail!("Proof target of '{address}' overflowed"),
+            },
+        )
+    }
+
+    /// Returns the exact cumulative sum of the given targets, or an error if the sum exceeds `u128::MAX`.
+    fn checked_cumulative_target(targets: impl IntoIterator<Item = u64>) -> Result<u128> {
         // Compute the cumulative target as a u128.
-        self.partial_solutions.iter().try_fold(0u128, |cumulative, solution| {
-            cumulative.checked_add(solution.to_target()? as u128).ok_or_else(|| anyhow!("Cumulative target overflowed"))
+        targets.into_iter().try_fold(0u128, |cumulative, target| {
+            cumulative.checked_add(target as u128).ok_or_else(|| anyhow!("Cumulative target overflowed"))
         })
     }
 
+    /// Returns a coinbase solution that accumulates the union of the partial solutions in `self` and `other`.
+    ///
+    /// As accumulator proofs cannot be combined directly, this method re-accumulates the full
+    /// set of partial solutions from both coinbase solutions, and requires a prover puzzle.
+    ///
+    /// # Note
+    /// This method does *not* check that the partial solutions are valid.
+    pub fn merge(&self, other: &Self, epoch_challenge: &EpochChallenge<N>, puzzle: &CoinbasePuzzle<N>) -> Result<Self> {
+        // Retrieve the coinbase proving key.
+        let pk = match puzzle {
+            CoinbasePuzzle::Prover(coinbase_proving_key) => coinbase_proving_key,
+            CoinbasePuzzle::Verifier(_) => bail!("Cannot merge coinbase solutions with a verifier"),
+        };
+
+        // Compute the union of the partial solutions, in order.
+        let partial_solutions = self
+            .partial_solutions
+            .iter()
+            .chain(other.partial_solutions.iter())
+            .copied()
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Ensure the number of partial solutions does not exceed `MAX_PROVER_SOLUTIONS`.
+        ensure!(
+            partial_solutions.len() <= N::MAX_PROVER_SOLUTIONS,
+            "Cannot merge beyond {} partial solutions, found {}",
+            N::MAX_PROVER_SOLUTIONS,
+            partial_solutions.len()
+        );
+
+        CoinbasePuzzle::accumulate_partial_solutions(pk, epoch_challenge, partial_solutions, None)
+    }
+
     /// Returns the accumulator challenge point.
     pub fn to_accumulator_point(&self) -> Result<Field<N>> {
-        let mut challenge_points =
-            hash_commitments(self.partial_solutions.iter().map(|solution| *solution.commitment()))?;
-        ensure!(challenge_points.len() == self.partial_solutions.len() + 1, "Invalid number of challenge points");
+        let commitments = self.partial_solutions.iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let mut challenge_points = derive_challenges(&commitments)?;
 
         // Pop the last challenge point as the accumulator challenge point.
         match challenge_points.pop() {
@@ -81,3 +186,99 @@ impl<N: Network> CoinbaseSolution<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_cumulative_target_is_exact() -> Result<()> {
+        // Ensure many maximal targets accumulate exactly, without overflowing.
+        let num_targets = 1 << 20;
+        let expected = u64::MAX as u128 * num_targets as u128;
+        let candidate = CoinbaseSolution::<CurrentNetwork>::checked_cumulative_target(vec![u64::MAX; num_targets])?;
+        assert_eq!(expected, candidate);
+
+        // Ensure the cumulative target of a coinbase solution matches the sum of its targets.
+        let mut rng = TestRng::default();
+        let partial_solutions = (0..100).map(|_| rng.gen()).collect::<Vec<_>>();
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let solution = CoinbaseSolution::<CurrentNetwork>::new(partial_solutions, proof);
+        let expected = solution.partial_solutions().iter().map(|s| s.to_target().unwrap() as u128).sum::<u128>();
+        assert_eq!(expected, solution.to_cumulative_proof_target()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_proof_target_of() -> Result<()> {
+        let mut rng = TestRng::default();
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), Default::default(), (1 << 5) - 1)?;
+
+        // Sample a coinbase solution from three provers.
+        let provers: [Address<CurrentNetwork>; 3] = [(); 3].map(|_| Address::new(rng.gen()));
+        let partial_solutions = provers
+            .iter()
+            .map(|address| PartialSolution::new(*address, rng.gen(), KZGCommitment(rng.gen())))
+            .collect::<Vec<_>>();
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let solution = CoinbaseSolution::<CurrentNetwork>::new(partial_solutions.clone(), proof);
+
+        // Ensure the proof target of each prover matches its partial solution.
+        for (address, partial_solution) in provers.iter().zip(&partial_solutions) {
+            assert_eq!(Some(partial_solution.to_target()?), solution.proof_target_of(address, &epoch_challenge)?);
+        }
+
+        // Ensure an absent prover has no proof target.
+        assert_eq!(None, solution.proof_target_of(&Address::new(rng.gen()), &epoch_challenge)?);
+
+        // Ensure a prover with more than one partial solution reports the sum of its targets.
+        let extra = PartialSolution::new(provers[0], rng.gen(), KZGCommitment(rng.gen()));
+        let solution = CoinbaseSolution::new([partial_solutions.clone(), vec![extra]].concat(), proof);
+        let expected = partial_solutions[0].to_target()?.checked_add(extra.to_target()?);
+        if let Some(expected) = expected {
+            assert_eq!(Some(expected), solution.proof_target_of(&provers[0], &epoch_challenge)?);
+        } else {
+            assert!(solution.proof_target_of(&provers[0], &epoch_challenge).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_targets() -> Result<()> {
+        let mut rng = TestRng::default();
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), Default::default(), (1 << 5) - 1)?;
+
+        // Sample a coinbase solution.
+        let partial_solutions = (0..10).map(|_| rng.gen()).collect::<Vec<PartialSolution<CurrentNetwork>>>();
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let solution = CoinbaseSolution::new(partial_solutions.clone(), proof);
+
+        // Compute the minimum and cumulative targets of the partial solutions.
+        let targets = partial_solutions.iter().map(|s| s.to_target()).collect::<Result<Vec<_>>>()?;
+        let minimum_target = *targets.iter().min().unwrap();
+        let cumulative_target = u64::try_from(solution.to_cumulative_proof_target()?).unwrap_or(u64::MAX);
+
+        // Ensure the targets are met when both are at their thresholds.
+        assert!(solution.check_targets(&epoch_challenge, cumulative_target, minimum_target)?);
+        assert!(solution.check_targets(&epoch_challenge, 0, 0)?);
+
+        // Ensure a proof target above the minimum target is not met, even if the coinbase target is met.
+        if let Some(proof_target) = minimum_target.checked_add(1) {
+            assert!(!solution.check_targets(&epoch_challenge, 0, proof_target)?);
+        }
+        // Ensure a coinbase target above the cumulative target is not met, even if the proof target is met.
+        if let Some(coinbase_target) = cumulative_target.checked_add(1) {
+            assert!(!solution.check_targets(&epoch_challenge, coinbase_target, minimum_target)?);
+        }
+
+        // Ensure malformed coinbase solutions are rejected.
+        let empty = CoinbaseSolution::<CurrentNetwork>::new(vec![], proof);
+        assert!(empty.check_targets(&epoch_challenge, 0, 0).is_err());
+        let duplicates = CoinbaseSolution::new(vec![partial_solutions[0]; 2], proof);
+        assert!(duplicates.check_targets(&epoch_challenge, 0, 0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+impl<N: Network> EpochChallenge<N> {
+    /// The degree of the epoch challenges generated for property tests.
+    /// This degree is kept small, so prover solutions for the generated challenges are cheap to prove.
+    pub const ARBITRARY_DEGREE: u32 = (1 << 5) - 1;
+}
+
+impl<N: Network> Arbitrary for EpochChallenge<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Returns a strategy for epoch challenges of degree `ARBITRARY_DEGREE`,
+    /// with an arbitrary epoch number and epoch block hash.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u32>(), any::<u64>())
+            .prop_map(|(epoch_number, seed)| {
+                let epoch_block_hash = N::BlockHash::rand(&mut StdRng::seed_from_u64(seed));
+                Self::new(epoch_number, epoch_block_hash, Self::ARBITRARY_DEGREE)
+                    .expect("Failed to construct an arbitrary epoch challenge")
+            })
+            .boxed()
+    }
+}
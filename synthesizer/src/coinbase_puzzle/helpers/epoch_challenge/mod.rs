@@ -14,13 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(any(test, feature = "proptest"))]
+mod arbitrary;
 mod bytes;
+mod random;
 
-use snarkvm_algorithms::fft::Evaluations as EvaluationsOnDomain;
+use snarkvm_algorithms::{
+    fft::{Evaluations as EvaluationsOnDomain, Polynomial},
+    polycommit::kzg10::{Powers, UniversalParams as SRS},
+};
 
 use super::*;
 use crate::coinbase_puzzle::hash_to_polynomial;
 
+use std::borrow::Cow;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EpochChallenge<N: Network> {
     /// The epoch number.
@@ -77,9 +85,82 @@ impl<N: Network> EpochChallenge<N> {
         u32::try_from(self.epoch_polynomial.degree()).unwrap()
     }
 
+    /// Returns `true` if the epoch challenge is well-formed.
+    ///
+    /// An epoch challenge is well-formed if the degree of its epoch polynomial has a valid product domain,
+    /// and its cached evaluations are over that product domain, with one evaluation per element.
+    /// This holds for every epoch challenge from `new`, which is why `degree` may not fail.
+    pub fn is_well_formed(&self) -> bool {
+        // Ensure the degree of the epoch polynomial fits in a u32.
+        let degree = match u32::try_from(self.epoch_polynomial.degree()) {
+            Ok(degree) => degree,
+            Err(_) => return false,
+        };
+        // Ensure the degree has a valid product domain.
+        let product_domain = match CoinbasePuzzle::<N>::product_domain(degree) {
+            Ok(product_domain) => product_domain,
+            Err(_) => return false,
+        };
+        // Ensure the evaluations are over the product domain, with one evaluation per element.
+        self.epoch_polynomial_evaluations.domain() == product_domain
+            && self.epoch_polynomial_evaluations.evaluations().len() == product_domain.size()
+    }
+
     /// Returns the number of coefficients of the epoch polynomial.
     pub fn num_coefficients(&self) -> Result<u32> {
         let degree = self.degree();
         degree.checked_add(1).ok_or_else(|| anyhow!("Epoch polynomial degree ({degree} + 1) overflows"))
     }
+
+    /// Returns a KZG commitment to the epoch polynomial, under the given SRS.
+    /// The commitment is a succinct substitute for the epoch polynomial, which a prover
+    /// may check its locally-derived epoch polynomial against with a KZG opening.
+    pub fn polynomial_commitment(&self, srs: &SRS<N::PairingCurve>) -> Result<KZGCommitment<N::PairingCurve>> {
+        // Retrieve the powers of beta G for each coefficient of the epoch polynomial.
+        let num_coefficients = usize::try_from(self.num_coefficients()?)?;
+        let powers = Powers {
+            powers_of_beta_g: Cow::Owned(srs.powers_of_beta_g(0, num_coefficients)?),
+            powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
+        };
+        // Commit to the epoch polynomial, without hiding.
+        let polynomial = Polynomial::from(&self.epoch_polynomial);
+        let (commitment, _rand) = KZG10::commit(&powers, &polynomial, None, &Default::default(), None)?;
+        Ok(commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_is_well_formed() {
+        let mut rng = TestRng::default();
+
+        let degree = (1 << 8) - 1;
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), degree).unwrap();
+        assert!(epoch_challenge.is_well_formed());
+
+        // Ensure a challenge with a missing evaluation is not well-formed.
+        let mut candidate = epoch_challenge.clone();
+        candidate.epoch_polynomial_evaluations.evaluations.pop();
+        assert!(!candidate.is_well_formed());
+
+        // Ensure a challenge with evaluations over a different domain is not well-formed.
+        let other = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), 2 * degree + 1).unwrap();
+        assert!(other.is_well_formed());
+        let mut candidate = epoch_challenge.clone();
+        candidate.epoch_polynomial_evaluations = other.epoch_polynomial_evaluations().clone();
+        assert!(!candidate.is_well_formed());
+
+        // Ensure a challenge with an epoch polynomial of a different degree is not well-formed.
+        let mut candidate = epoch_challenge;
+        candidate.epoch_polynomial = other.epoch_polynomial().clone();
+        assert!(!candidate.is_well_formed());
+    }
 }
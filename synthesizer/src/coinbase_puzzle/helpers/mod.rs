@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod accumulator;
+pub use accumulator::*;
+
 mod coinbase_solution;
 pub use coinbase_solution::*;
 
@@ -23,13 +26,25 @@ pub use epoch_challenge::*;
 mod partial_solution;
 pub use partial_solution::*;
 
+mod prover_polynomial_cache;
+pub use prover_polynomial_cache::*;
+
 mod prover_solution;
 pub use prover_solution::*;
 
 mod puzzle_commitment;
 pub use puzzle_commitment::*;
 
-use crate::coinbase_puzzle::{hash_commitment, hash_commitments, CoinbasePuzzle};
+mod setup_contribution;
+pub use setup_contribution::*;
+
+mod share_record;
+pub use share_record::*;
+
+mod verification_cache;
+pub use verification_cache::*;
+
+use crate::coinbase_puzzle::{derive_challenges, hash_commitment, CoinbasePuzzle};
 use console::{account::Address, prelude::*, types::Field};
 use snarkvm_algorithms::{
     fft::{domain::FFTPrecomputation, DensePolynomial, EvaluationDomain},
@@ -53,6 +68,26 @@ pub struct PuzzleConfig {
     pub degree: u32,
 }
 
+impl PuzzleConfig {
+    /// Returns the product domain for the given degree, or an error if the degree is invalid.
+    /// This allows a node to validate its configured degree at startup, without loading the SRS.
+    pub fn validate<N: Network>(degree: u32) -> Result<EvaluationDomain<N::Field>> {
+        CoinbasePuzzle::<N>::product_domain(degree)
+            .map_err(|error| anyhow!("Invalid coinbase puzzle degree '{degree}': {error}"))
+    }
+}
+
+/// The outcome of a search for a prover solution over a range of nonces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchOutcome<N: Network> {
+    /// The first prover solution that met the proof target, if any.
+    pub solution: Option<ProverSolution<N>>,
+    /// The best target observed during the search.
+    pub best_target: u64,
+    /// The number of nonces attempted.
+    pub attempts: u64,
+}
+
 pub type CoinbaseVerifyingKey<N> = VerifierKey<<N as Environment>::PairingCurve>;
 
 #[derive(Clone, Debug)]
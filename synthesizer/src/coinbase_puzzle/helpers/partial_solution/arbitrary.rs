@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use proptest::prelude::*;
+
+impl<N: Network> Arbitrary for PartialSolution<N> {
+    /// The epoch challenge to derive the commitment for.
+    /// If `None`, the epoch challenge for epoch `0` at `EpochChallenge::ARBITRARY_DEGREE` is used.
+    type Parameters = Option<EpochChallenge<N>>;
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Returns a strategy for partial solutions whose commitment is derived for the given epoch challenge,
+    /// for an arbitrary prover address and nonce.
+    fn arbitrary_with(epoch_challenge: Self::Parameters) -> Self::Strategy {
+        any_with::<ProverSolution<N>>(epoch_challenge)
+            .prop_map(|solution| PartialSolution::new(solution.address(), solution.nonce(), solution.commitment()))
+            .boxed()
+    }
+}
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(any(test, feature = "proptest"))]
+mod arbitrary;
 mod bytes;
+mod random;
 mod serialize;
 mod string;
 
@@ -61,9 +64,78 @@ impl<N: Network> PartialSolution<N> {
         CoinbasePuzzle::prover_polynomial(epoch_challenge, self.address(), self.nonce())
     }
 
+    /// Returns the prover polynomial, reusing the polynomial from the given cache if present.
+    pub fn to_prover_polynomial_cached(
+        &self,
+        cache: &ProverPolynomialCache<N>,
+        epoch_challenge: &EpochChallenge<N>,
+    ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
+        cache.prover_polynomial(epoch_challenge, self.address(), self.nonce())
+    }
+
     /// Returns the target of the solution.
+    ///
+    /// Note: The division in this method is not constant time. To compare the target against a proof target
+    /// without dividing, use `target_meets`.
     pub fn to_target(&self) -> Result<u64> {
-        let hash_to_u64 = sha256d_to_u64(&self.commitment.to_bytes_le()?);
-        if hash_to_u64 == 0 { Ok(u64::MAX) } else { Ok(u64::MAX / hash_to_u64) }
+        commitment_to_target(&self.commitment)
+    }
+}
+
+/// Returns the target of the given puzzle commitment.
+fn commitment_to_target<N: Network>(commitment: &PuzzleCommitment<N>) -> Result<u64> {
+    let hash_to_u64 = sha256d_to_u64(&commitment.to_bytes_le()?);
+    if hash_to_u64 == 0 { Ok(u64::MAX) } else { Ok(u64::MAX / hash_to_u64) }
+}
+
+/// Returns `true` if the target of the given puzzle commitment meets the proof target.
+///
+/// This is the difficulty check for prover solutions, shared by proving and verification.
+/// The check runs in constant time, as it replaces the division in the target with a multiplication.
+pub fn target_meets<N: Network>(commitment: &PuzzleCommitment<N>, proof_target: u64) -> Result<bool> {
+    let hash_to_u64 = sha256d_to_u64(&commitment.to_bytes_le()?);
+    // For a nonzero hash, `u64::MAX / hash >= proof_target` if and only if `hash * proof_target <= u64::MAX`,
+    // which holds if and only if the upper 64 bits of the 128-bit product are zero. A zero hash always meets it.
+    let product = (hash_to_u64 as u128) * (proof_target as u128);
+    Ok(product >> 64 == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_target_meets() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let partial_solution: PartialSolution<CurrentNetwork> = rng.gen();
+            let commitment = partial_solution.commitment();
+
+            // Compute the target with the inline check previously used by `prove_abm`.
+            let hash_to_u64 = sha256d_to_u64(&commitment.to_bytes_le()?);
+            let expected_target = if hash_to_u64 == 0 { u64::MAX } else { u64::MAX / hash_to_u64 };
+            assert_eq!(expected_target, partial_solution.to_target()?);
+
+            // Ensure the helper agrees with the inline comparison, including at the boundaries.
+            let proof_targets = [
+                0,
+                1,
+                expected_target.saturating_sub(1),
+                expected_target,
+                expected_target.saturating_add(1),
+                rng.gen(),
+                u64::MAX,
+            ];
+            for proof_target in proof_targets {
+                assert_eq!(expected_target >= proof_target, target_meets(&commitment, proof_target)?);
+            }
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::ProjectiveCurve;
+
+impl<N: Network> Distribution<PartialSolution<N>> for Standard {
+    /// Samples a structurally-valid partial solution, with a random address, nonce, and commitment.
+    /// Note: The sampled commitment is not derived from a prover polynomial; use `Arbitrary` for a provable solution.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PartialSolution<N> {
+        let commitment = <N::PairingCurve as PairingEngine>::G1Projective::rand(rng).to_affine();
+        PartialSolution::new(Address::new(rng.gen()), rng.gen(), KZGCommitment(commitment))
+    }
+}
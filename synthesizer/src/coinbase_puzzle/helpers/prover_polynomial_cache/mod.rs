@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// The key of a prover polynomial, as `(epoch number, epoch block hash, degree, address, nonce)`.
+type ProverPolynomialKey<N> = (u32, <N as Network>::BlockHash, u32, Address<N>, u64);
+
+/// A cache of prover polynomials, keyed by the epoch challenge and the prover inputs.
+/// Prover solutions are `Copy`, so the cache is held by the caller and shared across solutions and epochs.
+///
+/// The cache holds at most `capacity` prover polynomials, and evicts the oldest prover polynomial when full.
+#[derive(Clone)]
+pub struct ProverPolynomialCache<N: Network> {
+    /// The maximum number of cached prover polynomials.
+    capacity: usize,
+    /// The mapping of the prover polynomial key to the prover polynomial, in insertion order.
+    polynomials: Arc<RwLock<IndexMap<ProverPolynomialKey<N>, DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>>>>,
+    /// The number of prover polynomials served from the cache.
+    num_hits: Arc<AtomicUsize>,
+}
+
+impl<N: Network> ProverPolynomialCache<N> {
+    /// The default maximum number of cached prover polynomials.
+    pub const DEFAULT_CAPACITY: usize = 1 << 10;
+
+    /// Initializes a new, empty prover polynomial cache, with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Initializes a new, empty prover polynomial cache, holding at most `capacity` prover polynomials.
+    ///
+    /// # Panics
+    /// This method panics if the capacity is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "The capacity of the prover polynomial cache must be nonzero");
+        Self { capacity, polynomials: Default::default(), num_hits: Default::default() }
+    }
+
+    /// Returns the maximum number of cached prover polynomials.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached prover polynomials.
+    pub fn len(&self) -> usize {
+        self.polynomials.read().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.polynomials.read().is_empty()
+    }
+
+    /// Returns the number of prover polynomials served from the cache.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::Relaxed)
+    }
+
+    /// Removes the prover polynomials of every epoch before the given epoch number.
+    pub fn prune(&self, epoch_number: u32) {
+        self.polynomials.write().retain(|(number, ..), _| *number >= epoch_number);
+    }
+
+    /// Returns the cached prover polynomial for the given inputs, or computes it and caches it.
+    pub(crate) fn prover_polynomial(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
+        // Construct the key from the prover polynomial inputs.
+        let key = (
+            epoch_challenge.epoch_number(),
+            epoch_challenge.epoch_block_hash(),
+            epoch_challenge.degree(),
+            address,
+            nonce,
+        );
+
+        // If the prover polynomial is cached, return it.
+        if let Some(polynomial) = self.polynomials.read().get(&key) {
+            self.num_hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(polynomial.clone());
+        }
+
+        // Otherwise, compute the prover polynomial.
+        let polynomial = CoinbasePuzzle::prover_polynomial(epoch_challenge, address, nonce)?;
+
+        // Cache the prover polynomial, evicting the oldest prover polynomials if the cache is full.
+        let mut polynomials = self.polynomials.write();
+        while polynomials.len() >= self.capacity && !polynomials.contains_key(&key) {
+            polynomials.shift_remove_index(0);
+        }
+        polynomials.insert(key, polynomial.clone());
+        Ok(polynomial)
+    }
+}
+
+impl<N: Network> Default for ProverPolynomialCache<N> {
+    /// Initializes a new, empty prover polynomial cache.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use proptest::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+impl<N: Network> Arbitrary for ProverSolution<N> {
+    /// The epoch challenge to prove the solution for.
+    /// If `None`, the epoch challenge for epoch `0` at `EpochChallenge::ARBITRARY_DEGREE` is used.
+    type Parameters = Option<EpochChallenge<N>>;
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Returns a strategy for prover solutions that verify against the given epoch challenge,
+    /// for an arbitrary prover address and nonce.
+    ///
+    /// Each solution is proven with a coinbase puzzle trimmed to the degree of the epoch challenge.
+    /// As the verifying key does not depend on the degree, the solutions verify with any coinbase puzzle.
+    fn arbitrary_with(epoch_challenge: Self::Parameters) -> Self::Strategy {
+        let epoch_challenge = match epoch_challenge {
+            Some(epoch_challenge) => epoch_challenge,
+            None => EpochChallenge::new(0, Default::default(), EpochChallenge::<N>::ARBITRARY_DEGREE)
+                .expect("Failed to construct the default epoch challenge"),
+        };
+        (any::<u64>(), any::<u64>())
+            .prop_map(move |(seed, nonce)| {
+                // Sample the address of the prover.
+                let private_key = console::account::PrivateKey::<N>::new(&mut StdRng::seed_from_u64(seed))
+                    .expect("Failed to sample an arbitrary private key");
+                let address = Address::try_from(private_key).expect("Failed to derive an arbitrary address");
+                // Prove the solution with a coinbase puzzle of the same degree as the epoch challenge.
+                let config = PuzzleConfig { degree: epoch_challenge.degree() };
+                let puzzle = CoinbasePuzzle::<N>::setup(config)
+                    .and_then(|srs| CoinbasePuzzle::trim(&srs, config))
+                    .expect("Failed to trim the arbitrary coinbase puzzle");
+                puzzle.prove(&epoch_challenge, address, nonce, None).expect("Failed to prove an arbitrary solution")
+            })
+            .boxed()
+    }
+}
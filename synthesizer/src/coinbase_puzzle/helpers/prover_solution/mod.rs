@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(any(test, feature = "proptest"))]
+mod arbitrary;
 mod bytes;
 mod serialize;
 mod string;
@@ -35,6 +37,39 @@ impl<N: Network> ProverSolution<N> {
         Self { partial_solution, proof }
     }
 
+    /// Initializes a new instance of the prover solution from the raw bytes of the commitment and proof,
+    /// such as those produced by an external prover.
+    pub fn from_components(
+        address: Address<N>,
+        nonce: u64,
+        commitment_bytes: &[u8],
+        proof_bytes: &[u8],
+    ) -> Result<Self> {
+        // Deserialize the commitment, ensuring all of the bytes are consumed.
+        let mut reader = commitment_bytes;
+        let commitment = PuzzleCommitment::<N>::read_le(&mut reader)
+            .map_err(|error| anyhow!("Invalid commitment bytes for the prover solution: {error}"))?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the prover solution commitment", reader.len());
+        // Ensure the commitment is a valid point on the curve.
+        ensure!(
+            commitment.0.is_on_curve() && commitment.0.is_in_correct_subgroup_assuming_on_curve(),
+            "The prover solution commitment is not a valid curve point"
+        );
+
+        // Deserialize the proof, ensuring all of the bytes are consumed.
+        let mut reader = proof_bytes;
+        let proof = PuzzleProof::<N>::read_le(&mut reader)
+            .map_err(|error| anyhow!("Invalid proof bytes for the prover solution: {error}"))?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the prover solution proof", reader.len());
+        // Ensure the proof is a valid point on the curve.
+        ensure!(
+            proof.w.is_on_curve() && proof.w.is_in_correct_subgroup_assuming_on_curve(),
+            "The prover solution proof is not a valid curve point"
+        );
+
+        Ok(Self::new(PartialSolution::new(address, nonce, commitment), proof))
+    }
+
     /// Returns `true` if the prover solution is valid.
     pub fn verify(
         &self,
@@ -48,7 +83,7 @@ impl<N: Network> ProverSolution<N> {
         }
 
         // Ensure that the prover solution is greater than the proof target.
-        if self.to_target()? < proof_target {
+        if !target_meets(&self.commitment(), proof_target)? {
             bail!("Prover puzzle does not meet the proof target requirements.")
         }
 
@@ -97,6 +132,15 @@ impl<N: Network> ProverSolution<N> {
         self.partial_solution.to_prover_polynomial(epoch_challenge)
     }
 
+    /// Returns the prover polynomial, reusing the polynomial from the given cache if present.
+    pub fn to_prover_polynomial_cached(
+        &self,
+        cache: &ProverPolynomialCache<N>,
+        epoch_challenge: &EpochChallenge<N>,
+    ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
+        self.partial_solution.to_prover_polynomial_cached(cache, epoch_challenge)
+    }
+
     /// Returns the target of the solution.
     pub fn to_target(&self) -> Result<u64> {
         self.partial_solution.to_target()
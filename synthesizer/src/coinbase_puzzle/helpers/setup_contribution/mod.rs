@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::coinbase_puzzle::hash_to_coefficients;
+use snarkvm_algorithms::{msm::VariableBase, polycommit::kzg10::UniversalParams as SRS};
+use snarkvm_curves::{AffineCurve, PairingCurve, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+
+use blake2::Digest;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+type G1Affine<N> = <<N as Environment>::PairingCurve as PairingEngine>::G1Affine;
+type G2Affine<N> = <<N as Environment>::PairingCurve as PairingEngine>::G2Affine;
+type Fr<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fr;
+type Pairing<N> = <N as Environment>::PairingCurve;
+
+/// The powers of beta from which the coinbase puzzle keys are derived.
+/// In a setup ceremony, each participant updates the powers with secret randomness, and proves they did so honestly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetupPowers<N: Network> {
+    /// The powers of beta times the generator of G1, i.e. `{ beta^i G }`.
+    powers_of_beta_g: Vec<G1Affine<N>>,
+    /// The generator of G2.
+    h: G2Affine<N>,
+    /// Beta times the generator of G2.
+    beta_h: G2Affine<N>,
+}
+
+impl<N: Network> SetupPowers<N> {
+    /// Initializes the setup powers for the given puzzle configuration from the given SRS.
+    pub fn from_srs(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<Self> {
+        let product_domain = CoinbasePuzzle::<N>::product_domain(config.degree)?;
+        let powers_of_beta_g = srs.powers_of_beta_g(0, product_domain.size())?;
+        Ok(Self { powers_of_beta_g, h: srs.h, beta_h: srs.beta_h() })
+    }
+
+    /// Returns the powers of beta times the generator of G1.
+    pub fn powers_of_beta_g(&self) -> &[G1Affine<N>] {
+        &self.powers_of_beta_g
+    }
+
+    /// Returns the generator of G2.
+    pub const fn h(&self) -> G2Affine<N> {
+        self.h
+    }
+
+    /// Returns beta times the generator of G2.
+    pub const fn beta_h(&self) -> G2Affine<N> {
+        self.beta_h
+    }
+
+    /// Returns the powers of beta in the Lagrange basis of the given domain.
+    pub(crate) fn lagrange_basis(&self, domain: EvaluationDomain<Fr<N>>) -> Result<Vec<G1Affine<N>>> {
+        ensure!(
+            self.powers_of_beta_g.len() >= domain.size(),
+            "The setup powers do not support the domain size ({} < {})",
+            self.powers_of_beta_g.len(),
+            domain.size()
+        );
+        let powers = self.powers_of_beta_g[..domain.size()].iter().map(|power| power.to_projective());
+        let basis = domain.ifft(&powers.collect::<Vec<_>>());
+        Ok(<Pairing<N> as PairingEngine>::G1Projective::batch_normalization_into_affine(basis))
+    }
+}
+
+/// A proof that a setup contribution updated the powers of beta with known randomness `tau`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ContributionProof<N: Network> {
+    /// The contributed randomness times the generator of G1, i.e. `tau G`.
+    tau_g: G1Affine<N>,
+    /// The contributed randomness times the generator of G2, i.e. `tau H`.
+    tau_h: G2Affine<N>,
+    /// The Schnorr commitment `r G`, for the proof of knowledge of `tau`.
+    commitment: G1Affine<N>,
+    /// The Schnorr response `r + c * tau`, for the proof of knowledge of `tau`.
+    response: Fr<N>,
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Updates the given setup powers with fresh randomness `tau`, such that `beta` becomes `beta * tau`,
+    /// and returns a proof that the update was computed with knowledge of `tau`.
+    ///
+    /// Participants in a setup ceremony contribute sequentially. As long as one participant discards their `tau`,
+    /// the resulting `beta` is unknown to everyone.
+    pub fn contribute<R: Rng + CryptoRng>(powers: &mut SetupPowers<N>, rng: &mut R) -> Result<ContributionProof<N>> {
+        let num_powers = powers.powers_of_beta_g.len();
+        ensure!(num_powers >= 2, "The setup powers must contain at least two powers of beta");
+
+        // Sample the contributed randomness.
+        let tau = loop {
+            let tau = Fr::<N>::rand(rng);
+            if !tau.is_zero() {
+                break tau;
+            }
+        };
+
+        // Compute the powers of tau.
+        let mut powers_of_tau = Vec::with_capacity(num_powers);
+        let mut power = Fr::<N>::one();
+        for _ in 0..num_powers {
+            powers_of_tau.push(power);
+            power *= tau;
+        }
+
+        // Update the powers of beta.
+        let previous = powers.clone();
+        let updated = cfg_iter!(powers.powers_of_beta_g)
+            .zip_eq(cfg_iter!(powers_of_tau))
+            .map(|(power_of_beta_g, power_of_tau)| *power_of_beta_g * *power_of_tau)
+            .collect::<Vec<_>>();
+        powers.powers_of_beta_g =
+            <Pairing<N> as PairingEngine>::G1Projective::batch_normalization_into_affine(updated);
+        powers.beta_h = (powers.beta_h * tau).to_affine();
+
+        // Prove knowledge of tau.
+        let g = powers.powers_of_beta_g[0];
+        let tau_g = (g * tau).to_affine();
+        let tau_h = (powers.h * tau).to_affine();
+        let randomizer = Fr::<N>::rand(rng);
+        let commitment = (g * randomizer).to_affine();
+        let challenge = Self::contribution_challenge(&previous, powers, tau_g, tau_h, commitment)?;
+
+        Ok(ContributionProof { tau_g, tau_h, commitment, response: randomizer + challenge * tau })
+    }
+
+    /// Returns `true` if the new setup powers are an honest update of the old setup powers,
+    /// with the given proof of the contribution.
+    pub fn verify_contribution(
+        old: &SetupPowers<N>,
+        new: &SetupPowers<N>,
+        proof: &ContributionProof<N>,
+    ) -> Result<bool> {
+        let num_powers = new.powers_of_beta_g.len();
+        ensure!(num_powers >= 2, "The setup powers must contain at least two powers of beta");
+        ensure!(
+            old.powers_of_beta_g.len() == num_powers,
+            "The number of setup powers changed in the contribution ({} != {num_powers})",
+            old.powers_of_beta_g.len()
+        );
+
+        // Ensure the generators are unchanged, and the contribution is nontrivial.
+        let (g, h) = (old.powers_of_beta_g[0], old.h);
+        if new.powers_of_beta_g[0] != g || new.h != h || proof.tau_g.is_zero() {
+            return Ok(false);
+        }
+
+        // Ensure the contributor knows tau, i.e. `s G == R + c (tau G)`.
+        let challenge = Self::contribution_challenge(old, new, proof.tau_g, proof.tau_h, proof.commitment)?;
+        if g * proof.response != proof.commitment.to_projective() + proof.tau_g * challenge {
+            return Ok(false);
+        }
+        // Ensure `tau G` and `tau H` contain the same tau.
+        if Pairing::<N>::pairing(proof.tau_g, h) != Pairing::<N>::pairing(g, proof.tau_h) {
+            return Ok(false);
+        }
+        // Ensure the new beta is the old beta times tau.
+        let new_beta_g = new.powers_of_beta_g[1];
+        if Pairing::<N>::pairing(new_beta_g, h) != Pairing::<N>::pairing(old.powers_of_beta_g[1], proof.tau_h) {
+            return Ok(false);
+        }
+        // Ensure the new beta in G2 matches the new beta in G1.
+        if Pairing::<N>::pairing(new_beta_g, h) != Pairing::<N>::pairing(g, new.beta_h) {
+            return Ok(false);
+        }
+
+        // Ensure the new powers are successive powers of beta, i.e. `e(beta^{i+1} G, H) == e(beta^i G, beta H)`,
+        // batched with coefficients derived from the new powers.
+        let mut bytes = challenge.to_bytes_le()?;
+        for power in &new.powers_of_beta_g {
+            power.write_le(&mut bytes)?;
+        }
+        let coefficients = hash_to_coefficients::<Fr<N>>(&bytes, u32::try_from(num_powers - 1)?);
+        let coefficients = coefficients.iter().map(|coefficient| coefficient.to_bigint()).collect::<Vec<_>>();
+        let shifted = VariableBase::msm(&new.powers_of_beta_g[1..], &coefficients);
+        let unshifted = VariableBase::msm(&new.powers_of_beta_g[..num_powers - 1], &coefficients);
+        Ok(Pairing::<N>::pairing(shifted, h) == Pairing::<N>::pairing(unshifted, new.beta_h))
+    }
+
+    /// Returns the coinbase puzzle for the given configuration, from the given setup powers.
+    pub fn trim_powers(powers: &SetupPowers<N>, config: PuzzleConfig) -> Result<Self> {
+        let product_domain = Self::product_domain(config.degree)?;
+        // Ensure there is one power of beta for each coefficient of the product polynomial.
+        let product_num_coefficients = product_domain.size();
+        ensure!(
+            powers.powers_of_beta_g.len() == product_num_coefficients,
+            "Expected {product_num_coefficients} setup powers of beta G for the product polynomial, found {}",
+            powers.powers_of_beta_g.len()
+        );
+        let lagrange_basis_at_beta_g = powers.lagrange_basis(product_domain)?;
+
+        let vk = CoinbaseVerifyingKey::<N> {
+            g: powers.powers_of_beta_g[0],
+            gamma_g: G1Affine::<N>::zero(), // We don't use gamma_g later on since we are not hiding.
+            h: powers.h,
+            beta_h: powers.beta_h,
+            prepared_h: powers.h.prepare(),
+            prepared_beta_h: powers.beta_h.prepare(),
+        };
+
+        Self::from_lagrange_basis(product_domain, lagrange_basis_at_beta_g, vk)
+    }
+
+    /// Returns the challenge for the proof of knowledge of the contribution.
+    fn contribution_challenge(
+        old: &SetupPowers<N>,
+        new: &SetupPowers<N>,
+        tau_g: G1Affine<N>,
+        tau_h: G2Affine<N>,
+        commitment: G1Affine<N>,
+    ) -> Result<Fr<N>> {
+        let mut bytes = Vec::new();
+        old.powers_of_beta_g[1].write_le(&mut bytes)?;
+        new.powers_of_beta_g[1].write_le(&mut bytes)?;
+        new.beta_h.write_le(&mut bytes)?;
+        tau_g.write_le(&mut bytes)?;
+        tau_h.write_le(&mut bytes)?;
+        commitment.write_le(&mut bytes)?;
+        Ok(Fr::<N>::from_bytes_le_mod_order(&blake2::Blake2b512::digest(&bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_tampered_contribution() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let config = PuzzleConfig { degree: (1 << 5) - 1 };
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(config)?;
+        let old = SetupPowers::<CurrentNetwork>::from_srs(&srs, config)?;
+        let mut new = old.clone();
+        let proof = CoinbasePuzzle::contribute(&mut new, &mut rng)?;
+        assert!(CoinbasePuzzle::verify_contribution(&old, &new, &proof)?);
+
+        // Ensure tampering with any single power is detected.
+        for index in [0, 1, 5, new.powers_of_beta_g.len() - 1] {
+            let mut tampered = new.clone();
+            let power = tampered.powers_of_beta_g[index].to_projective() + proof.tau_g.to_projective();
+            tampered.powers_of_beta_g[index] = power.to_affine();
+            assert!(!CoinbasePuzzle::verify_contribution(&old, &tampered, &proof)?, "Power {index}");
+        }
+
+        // Ensure tampering with the powers in G2 is detected.
+        let mut tampered = new.clone();
+        tampered.beta_h = proof.tau_h;
+        assert!(!CoinbasePuzzle::verify_contribution(&old, &tampered, &proof)?);
+        let mut tampered = new.clone();
+        tampered.h = proof.tau_h;
+        assert!(!CoinbasePuzzle::verify_contribution(&old, &tampered, &proof)?);
+
+        // Ensure the proof of knowledge is checked.
+        let forged = ContributionProof { response: proof.response + Fr::<CurrentNetwork>::one(), ..proof };
+        assert!(!CoinbasePuzzle::verify_contribution(&old, &new, &forged)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_truncated_powers() -> Result<()> {
+        let config = PuzzleConfig { degree: (1 << 5) - 1 };
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(config)?;
+        let powers = SetupPowers::<CurrentNetwork>::from_srs(&srs, config)?;
+        assert!(CoinbasePuzzle::trim_powers(&powers, config).is_ok());
+
+        // Ensure truncated powers are rejected, rather than producing a bad proving key.
+        let mut truncated = powers.clone();
+        truncated.powers_of_beta_g.pop();
+        let error = CoinbasePuzzle::trim_powers(&truncated, config).err().unwrap();
+        assert_eq!(
+            format!(
+                "Expected {} setup powers of beta G for the product polynomial, found {}",
+                powers.powers_of_beta_g.len(),
+                truncated.powers_of_beta_g.len()
+            ),
+            error.to_string()
+        );
+        Ok(())
+    }
+}
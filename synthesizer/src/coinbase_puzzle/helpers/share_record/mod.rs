@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+/// A flattened, per-share view of a partial solution, intended for analytics and export (e.g. JSON or CSV).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ShareRecord {
+    /// The address of the prover.
+    pub address: String,
+    /// The nonce for the solution.
+    pub nonce: u64,
+    /// The commitment for the solution.
+    pub commitment: String,
+    /// The target of the solution.
+    pub target: u64,
+}
+
+impl ShareRecord {
+    /// Initializes a new share record from the given partial solution.
+    pub fn from_partial_solution<N: Network>(partial_solution: &PartialSolution<N>) -> Result<Self> {
+        Ok(Self {
+            address: partial_solution.address().to_string(),
+            nonce: partial_solution.nonce(),
+            commitment: partial_solution.commitment().to_string(),
+            target: partial_solution.to_target()?,
+        })
+    }
+
+    /// Returns the given share records as CSV, with a header row of the field names.
+    pub fn to_csv(records: &[Self]) -> Result<String> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        for record in records {
+            writer.serialize(record)?;
+        }
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    /// Returns the share records from the given CSV, with a header row of the field names.
+    pub fn from_csv(csv: &str) -> Result<Vec<Self>> {
+        csv::Reader::from_reader(csv.as_bytes()).deserialize().map(|record| Ok(record?)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_share_records() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new coinbase solution.
+        let mut partial_solutions = vec![];
+        for _ in 0..rng.gen_range(1..10) {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+        }
+        let solution = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+
+        // Ensure there is one share record per partial solution.
+        let records = solution.to_share_records()?;
+        assert_eq!(solution.len(), records.len());
+        for (record, partial_solution) in records.iter().zip_eq(solution.partial_solutions()) {
+            assert_eq!(partial_solution.address().to_string(), record.address);
+            assert_eq!(partial_solution.nonce(), record.nonce);
+            assert_eq!(partial_solution.commitment().to_string(), record.commitment);
+            assert_eq!(partial_solution.to_target()?, record.target);
+        }
+
+        // Ensure the share records serialize to valid JSON.
+        let candidate = serde_json::to_string(&records)?;
+        assert_eq!(records, serde_json::from_str::<Vec<ShareRecord>>(&candidate)?);
+
+        // Ensure the share records round-trip through CSV, with one row per share record.
+        let candidate = ShareRecord::to_csv(&records)?;
+        assert_eq!(records.len() + 1, candidate.lines().count());
+        assert!(candidate.starts_with("address,nonce,commitment,target\n"));
+        assert_eq!(records, ShareRecord::from_csv(&candidate)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_algorithms::crypto_hash::sha256;
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// A cache of coinbase solution verification results, keyed by the coinbase puzzle ID.
+/// Puzzle instances that share a verifying key (e.g. a prover and a verifier) share cache entries.
+///
+/// The cache holds at most `capacity` verification results, and evicts the oldest result when full.
+#[derive(Clone)]
+pub struct VerificationCache<N: Network> {
+    /// The maximum number of cached verification results.
+    capacity: usize,
+    /// The mapping of `(coinbase puzzle ID, verification digest)` to the verification result, in insertion order.
+    results: Arc<RwLock<IndexMap<(Field<N>, [u8; 32]), bool>>>,
+    /// The number of verification results served from the cache.
+    num_hits: Arc<AtomicUsize>,
+}
+
+impl<N: Network> VerificationCache<N> {
+    /// The default maximum number of cached verification results.
+    pub const DEFAULT_CAPACITY: usize = 1 << 10;
+
+    /// Initializes a new, empty verification cache, with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Initializes a new, empty verification cache, holding at most `capacity` verification results.
+    ///
+    /// # Panics
+    /// This method panics if the capacity is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "The capacity of the verification cache must be nonzero");
+        Self { capacity, results: Default::default(), num_hits: Default::default() }
+    }
+
+    /// Returns the maximum number of cached verification results.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached verification results.
+    pub fn len(&self) -> usize {
+        self.results.read().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.results.read().is_empty()
+    }
+
+    /// Returns the number of verification results served from the cache.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the cached verification result for the given inputs, or computes it with `verify` and caches it.
+    /// Errors are not cached, and are returned as-is.
+    pub(crate) fn verify(
+        &self,
+        puzzle_id: Field<N>,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+        verify: impl FnOnce() -> Result<bool>,
+    ) -> Result<bool> {
+        // Construct the key from the verification inputs.
+        let mut preimage = coinbase_solution.to_bytes_le()?;
+        epoch_challenge.write_le(&mut preimage)?;
+        coinbase_target.write_le(&mut preimage)?;
+        proof_target.write_le(&mut preimage)?;
+        let key = (puzzle_id, sha256(&preimage));
+
+        // If the verification result is cached, return it.
+        if let Some(is_valid) = self.results.read().get(&key) {
+            self.num_hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(*is_valid);
+        }
+
+        // Otherwise, compute the verification result.
+        let is_valid = verify()?;
+
+        // Cache the verification result, evicting the oldest results if the cache is full.
+        let mut results = self.results.write();
+        while results.len() >= self.capacity && !results.contains_key(&key) {
+            results.shift_remove_index(0);
+        }
+        results.insert(key, is_valid);
+        Ok(is_valid)
+    }
+}
+
+impl<N: Network> Default for VerificationCache<N> {
+    /// Initializes a new, empty verification cache.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -27,9 +27,10 @@ mod tests;
 
 use crate::UniversalSRS;
 use console::{
-    account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    account::{Address, PrivateKey},
+    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, CryptoRng, Network, Result, Rng, ToBytes},
     program::cfg_into_iter,
+    types::Field,
 };
 use snarkvm_algorithms::{
     fft::{DensePolynomial, EvaluationDomain},
@@ -38,15 +39,24 @@ use snarkvm_algorithms::{
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{PrimeField, Zero};
-
-use std::sync::Arc;
+use snarkvm_utilities::ToBits;
+
+use std::{
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use snarkvm_algorithms::crypto_hash::sha256d_to_u64;
 
 pub const DIFFICULTY_NOT_MET: &str = "difficult not met";
 
+/// The maximum window size for the accumulator MSM in `verify_with_msm_config`.
+pub const MAX_MSM_WINDOW: usize = 20;
+
 #[derive(Clone)]
 pub enum CoinbasePuzzle<N: Network> {
     /// The prover contains the coinbase puzzle proving key.
@@ -75,6 +85,47 @@ impl<N: Network> CoinbasePuzzle<N> {
         Self::trim(&*universal_srs, PuzzleConfig { degree: max_degree })
     }
 
+    /// Load the coinbase puzzle, with a proving key trimmed to `prove_degree` and verifying up to `verify_degree`.
+    /// This saves memory for nodes that verify at the full degree, but only prove at a lower degree.
+    ///
+    /// # Note
+    /// The `prove_degree` must be less than or equal to the `verify_degree`.
+    pub fn load_hybrid(verify_degree: u32, prove_degree: u32) -> Result<Self> {
+        ensure!(
+            verify_degree <= N::COINBASE_PUZZLE_DEGREE,
+            "The verify degree exceeds the maximum degree ({verify_degree} > {})",
+            N::COINBASE_PUZZLE_DEGREE
+        );
+        // Load the universal SRS.
+        let universal_srs = UniversalSRS::<N>::load()?;
+        // Trim the universal SRS to the proving degree.
+        Self::trim_hybrid(&*universal_srs, PuzzleConfig { degree: verify_degree }, PuzzleConfig {
+            degree: prove_degree,
+        })
+    }
+
+    /// Trims the SRS to a proving key for `prove_config`, and a verifying key for `verify_config`.
+    /// The two keys are trimmed independently, so only the proving powers are reduced to `prove_config`.
+    pub fn trim_hybrid(
+        srs: &SRS<N::PairingCurve>,
+        verify_config: PuzzleConfig,
+        prove_config: PuzzleConfig,
+    ) -> Result<Self> {
+        ensure!(
+            prove_config.degree <= verify_config.degree,
+            "The prove degree must be <= the verify degree ({} > {})",
+            prove_config.degree,
+            verify_config.degree
+        );
+        // Trim the SRS to the verifying key for the verify degree.
+        let vk = Self::trim_verifying_key(srs, verify_config)?;
+        // Trim the SRS to the proving powers for the prove degree.
+        let product_domain = Self::product_domain(prove_config.degree)?;
+        let lagrange_basis_at_beta_g = srs.lagrange_basis(product_domain)?;
+
+        Self::from_lagrange_basis(product_domain, lagrange_basis_at_beta_g, vk)
+    }
+
     pub fn trim(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<Self> {
         // As above, we must support committing to the product of two degree `n` polynomials.
         // Thus, the SRS must support committing to a polynomial of degree `2n - 1`.
@@ -85,24 +136,54 @@ impl<N: Network> CoinbasePuzzle<N> {
         let product_domain = Self::product_domain(config.degree)?;
 
         let lagrange_basis_at_beta_g = srs.lagrange_basis(product_domain)?;
-        let fft_precomputation = product_domain.precompute_fft();
-        let product_domain_elements = product_domain.elements().collect();
 
-        let vk = CoinbaseVerifyingKey::<N> {
+        let vk = Self::trim_verifying_key(srs, config)?;
+
+        Self::from_lagrange_basis(product_domain, lagrange_basis_at_beta_g, vk)
+    }
+
+    /// Trims the SRS to the coinbase verifying key for the given config.
+    fn trim_verifying_key(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<CoinbaseVerifyingKey<N>> {
+        // Ensure the degree is valid.
+        Self::product_domain(config.degree)?;
+
+        Ok(CoinbaseVerifyingKey::<N> {
             g: srs.power_of_beta_g(0)?,
             gamma_g: <N::PairingCurve as PairingEngine>::G1Affine::zero(), // We don't use gamma_g later on since we are not hiding.
             h: srs.h,
             beta_h: srs.beta_h(),
             prepared_h: srs.prepared_h.clone(),
             prepared_beta_h: srs.prepared_beta_h.clone(),
-        };
+        })
+    }
+
+    /// Returns the coinbase puzzle for the given product domain, Lagrange basis, and verifying key.
+    ///
+    /// # Errors
+    /// This method returns an error if the Lagrange basis does not have one element for each element of the
+    /// product domain, as the resulting proving key would produce unverifiable proofs.
+    pub(crate) fn from_lagrange_basis(
+        product_domain: EvaluationDomain<N::Field>,
+        lagrange_basis_at_beta_g: Vec<<N::PairingCurve as PairingEngine>::G1Affine>,
+        verifying_key: CoinbaseVerifyingKey<N>,
+    ) -> Result<Self> {
+        // Ensure the Lagrange basis matches the product domain.
+        ensure!(
+            lagrange_basis_at_beta_g.len() == product_domain.size(),
+            "The Lagrange basis has {} elements, but the product domain has size {}",
+            lagrange_basis_at_beta_g.len(),
+            product_domain.size()
+        );
+
+        let fft_precomputation = product_domain.precompute_fft();
+        let product_domain_elements = product_domain.elements().collect();
 
         let pk = CoinbaseProvingKey {
             product_domain,
             product_domain_elements,
             lagrange_basis_at_beta_g,
             fft_precomputation,
-            verifying_key: vk,
+            verifying_key,
         };
 
         Ok(Self::Prover(Arc::new(pk)))
@@ -122,44 +203,151 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+        // Ensure the epoch challenge is compatible with the proving key.
+        Self::ensure_proving_key_supports(pk, epoch_challenge)?;
 
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
+        Self::prove_with_key(pk, epoch_challenge, address, nonce, minimum_proof_target)
+    }
+
+    /// Returns a prover solution to the coinbase puzzle for each of the given addresses, with the same nonce.
+    ///
+    /// The proving key and epoch challenge are checked once, and the precomputed evaluations of the epoch polynomial
+    /// are shared across the addresses, which are proven in parallel. Each prover solution is identical to the result
+    /// of calling `prove` for its address.
+    pub fn prove_batch(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        addresses: &[Address<N>],
+        nonce: u64,
+    ) -> Result<Vec<ProverSolution<N>>> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
-        let (commitment, _rand) =
-            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
 
-        let partial_solution = PartialSolution::new(address, nonce, commitment);
+        // Ensure the epoch challenge is compatible with the proving key.
+        Self::ensure_proving_key_supports(pk, epoch_challenge)?;
+
+        cfg_iter!(addresses).map(|address| Self::prove_with_key(pk, epoch_challenge, *address, nonce, None)).collect()
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, using the given proving key.
+    ///
+    /// # Note
+    /// This method does *not* check that the epoch challenge is compatible with the proving key.
+    fn prove_with_key(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        let (polynomial, product_evaluations, partial_solution) =
+            Self::commit_with_key(pk, epoch_challenge, address, nonce)?;
 
         // Check that the minimum target is met.
         if let Some(minimum_target) = minimum_proof_target {
-            let proof_target = partial_solution.to_target()?;
-            ensure!(
-                proof_target >= minimum_target,
-                "Prover solution was below the necessary proof target ({proof_target} < {minimum_target})"
-            );
+            if !target_meets(&partial_solution.commitment(), minimum_target)? {
+                let proof_target = partial_solution.to_target()?;
+                bail!("Prover solution was below the necessary proof target ({proof_target} < {minimum_target})");
+            }
         }
 
+        Self::open_with_key(pk, epoch_challenge, &polynomial, &product_evaluations, partial_solution)
+    }
+
+    /// Returns the prover polynomial, the product evaluations, and the partial solution for the given address
+    /// and nonce, by committing to the product of the prover polynomial and the epoch polynomial.
+    #[allow(clippy::type_complexity)]
+    fn commit_with_key(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<(
+        DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        Vec<<N::PairingCurve as PairingEngine>::Fr>,
+        PartialSolution<N>,
+    )> {
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+
+        let product_evaluations = Self::product_evaluations(pk, epoch_challenge, &polynomial);
+        let (commitment, _rand) =
+            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+
+        Ok((polynomial, product_evaluations, PartialSolution::new(address, nonce, commitment)))
+    }
+
+    /// Returns a prover solution for the given partial solution, by opening the commitment
+    /// to the product of the prover polynomial and the epoch polynomial.
+    fn open_with_key(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        product_evaluations: &[<N::PairingCurve as PairingEngine>::Fr],
+        partial_solution: PartialSolution<N>,
+    ) -> Result<ProverSolution<N>> {
+        let prover_solution =
+            Self::open_unchecked(pk, epoch_challenge, polynomial, product_evaluations, partial_solution)?;
+        debug_assert!(Self::check_opening(pk, epoch_challenge, polynomial, &prover_solution)?);
+        Ok(prover_solution)
+    }
+
+    /// Returns a prover solution for the given partial solution, without checking the opening proof.
+    fn open_unchecked(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        product_evaluations: &[<N::PairingCurve as PairingEngine>::Fr],
+        partial_solution: PartialSolution<N>,
+    ) -> Result<ProverSolution<N>> {
+        let commitment = partial_solution.commitment();
         let point = hash_commitment(&commitment)?;
         let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
 
         let proof = KZG10::open_lagrange(
             &pk.lagrange_basis(),
             pk.product_domain_elements(),
-            &product_evaluations,
+            product_evaluations,
             point,
             product_eval_at_point,
         )?;
         ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
 
-        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
+        Ok(ProverSolution::new(partial_solution, proof))
+    }
+
+    /// Returns `true` if the opening proof of the given prover solution is valid under the given proving key.
+    fn check_opening(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        prover_solution: &ProverSolution<N>,
+    ) -> Result<bool> {
+        let commitment = prover_solution.commitment();
+        let point = hash_commitment(&commitment)?;
+        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+
+        Ok(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, prover_solution.proof())?)
+    }
 
+    /// Returns a prover solution with a *hiding* proof, for testing the rejection of hiding proofs.
+    ///
+    /// As the coinbase proving key is trimmed without hiding powers, the blinding evaluation is sampled from `rng`
+    /// and attached to the non-hiding opening, rather than computed from a blinding polynomial.
+    #[cfg(test)]
+    pub(crate) fn prove_hiding<R: Rng>(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        rng: &mut R,
+    ) -> Result<ProverSolution<N>> {
+        let solution = self.prove(epoch_challenge, address, nonce, None)?;
+        let proof = PuzzleProof::<N> { w: solution.proof().w, random_v: Some(snarkvm_utilities::Uniform::rand(rng)) };
+        ensure!(proof.is_hiding(), "The prover solution must contain a hiding proof");
+        let partial_solution = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
         Ok(ProverSolution::new(partial_solution, proof))
     }
 
@@ -177,24 +365,19 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
+        // Ensure the epoch challenge is compatible with the proving key.
+        Self::ensure_proving_key_supports(pk, epoch_challenge)?;
+
         let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
 
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
+        let product_evaluations = Self::product_evaluations(pk, epoch_challenge, &polynomial);
         let (commitment, _rand) =
             KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
 
         // if difficulty not met,terminate current task
-        let hash_to_u64 = sha256d_to_u64(&commitment.to_bytes_le()?);
-        let solution_target = if hash_to_u64 == 0 { u64::MAX } else { u64::MAX / hash_to_u64 };
-        if solution_target < proof_target {
-            trace!("{} {} but {}", DIFFICULTY_NOT_MET, proof_target, solution_target);
+        let partial_solution = PartialSolution::new(address, nonce, commitment);
+        if !target_meets(&partial_solution.commitment(), proof_target)? {
+            trace!("{} {} but {}", DIFFICULTY_NOT_MET, proof_target, partial_solution.to_target()?);
             return Err(anyhow!(DIFFICULTY_NOT_MET));
         }
 
@@ -213,7 +396,107 @@ impl<N: Network> CoinbasePuzzle<N> {
 
         debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
 
-        Ok(ProverSolution::new(PartialSolution::new(address, nonce, commitment), proof))
+        Ok(ProverSolution::new(partial_solution, proof))
+    }
+
+    /// Searches the given nonces for a prover solution that meets the proof target.
+    ///
+    /// Unlike `prove`, a search that does not meet the proof target is not an error; the outcome
+    /// reports the best target observed, so a controller can estimate how close it is and adjust.
+    pub fn find_solution(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonces: impl IntoIterator<Item = u64>,
+        proof_target: u64,
+    ) -> Result<SearchOutcome<N>> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
+        };
+
+        // Ensure the epoch challenge is compatible with the proving key.
+        Self::ensure_proving_key_supports(pk, epoch_challenge)?;
+
+        let mut outcome = SearchOutcome { solution: None, best_target: 0, attempts: 0 };
+        for nonce in nonces {
+            outcome.attempts += 1;
+
+            // Compute the target of the commitment, before computing the proof.
+            let (polynomial, product_evaluations, partial_solution) =
+                Self::commit_with_key(pk, epoch_challenge, address, nonce)?;
+            outcome.best_target = outcome.best_target.max(partial_solution.to_target()?);
+
+            // If the proof target is met, open the commitment to compute the prover solution.
+            if target_meets(&partial_solution.commitment(), proof_target)? {
+                let solution =
+                    Self::open_with_key(pk, epoch_challenge, &polynomial, &product_evaluations, partial_solution)?;
+                outcome.solution = Some(solution);
+                break;
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// Searches the given range of nonces in parallel, returning a prover solution that meets the proof target,
+    /// or `None` if no nonce in the range meets it.
+    ///
+    /// Once a solution is found, the remaining nonces are skipped, and threads that have already committed to
+    /// a nonce abandon it before computing the opening proof. If several nonces meet the proof target,
+    /// any one of them may be returned.
+    pub fn search(
+        &self,
+        proof_target: u64,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce_range: Range<u64>,
+    ) -> Result<Option<ProverSolution<N>>> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
+        };
+
+        // Ensure the epoch challenge is compatible with the proving key.
+        Self::ensure_proving_key_supports(pk, epoch_challenge)?;
+
+        // Initialize the flag that cancels the search once a solution is found.
+        let is_found = AtomicBool::new(false);
+
+        let search_nonce = |nonce: u64| -> Result<Option<ProverSolution<N>>> {
+            // Skip the nonce if a solution has been found.
+            if is_found.load(Ordering::Relaxed) {
+                return Ok(None);
+            }
+
+            // Compute the commitment, and check the proof target before computing the proof.
+            let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+            let product_evaluations = Self::product_evaluations(pk, epoch_challenge, &polynomial);
+            let (commitment, _rand) =
+                KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+            let partial_solution = PartialSolution::new(address, nonce, commitment);
+            if !target_meets(&partial_solution.commitment(), proof_target)? {
+                return Ok(None);
+            }
+
+            // Abandon the nonce if a solution was found while committing.
+            if is_found.load(Ordering::Relaxed) {
+                return Ok(None);
+            }
+            let solution =
+                Self::open_with_key(pk, epoch_challenge, &polynomial, &product_evaluations, partial_solution)?;
+            is_found.store(true, Ordering::Relaxed);
+            Ok(Some(solution))
+        };
+
+        // Search the nonces, stopping at the first solution or error.
+        #[cfg(feature = "parallel")]
+        let solution = nonce_range.into_par_iter().map(search_nonce).find_map_any(Result::transpose);
+        #[cfg(not(feature = "parallel"))]
+        let solution = nonce_range.map(search_nonce).find_map(Result::transpose);
+
+        solution.transpose()
     }
 
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
@@ -225,18 +508,59 @@ impl<N: Network> CoinbasePuzzle<N> {
         epoch_challenge: &EpochChallenge<N>,
         prover_solutions: &[ProverSolution<N>],
     ) -> Result<CoinbaseSolution<N>> {
+        self.accumulate_unchecked_with_max_solutions(N::MAX_PROVER_SOLUTIONS, epoch_challenge, prover_solutions)
+    }
+
+    /// Returns a coinbase solution for the given epoch challenge and prover solutions,
+    /// reusing the prover polynomials from the given cache if present.
+    ///
+    /// # Note
+    /// This method does *not* check that the prover solutions are valid.
+    pub fn accumulate_unchecked_with_cache(
+        &self,
+        cache: &ProverPolynomialCache<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+    ) -> Result<CoinbaseSolution<N>> {
+        self.accumulate_internal(N::MAX_PROVER_SOLUTIONS, epoch_challenge, prover_solutions, Some(cache))
+    }
+
+    /// Returns a coinbase solution for the given epoch challenge and prover solutions,
+    /// accumulating at most `max_solutions` prover solutions.
+    ///
+    /// Testnets may lower the cap below `MAX_PROVER_SOLUTIONS`, and should verify with the same cap.
+    ///
+    /// # Note
+    /// This method does *not* check that the prover solutions are valid.
+    pub fn accumulate_unchecked_with_max_solutions(
+        &self,
+        max_solutions: usize,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+    ) -> Result<CoinbaseSolution<N>> {
+        self.accumulate_internal(max_solutions, epoch_challenge, prover_solutions, None)
+    }
+
+    /// Returns a coinbase solution for the given epoch challenge and prover solutions.
+    /// If the cache is `None`, the prover polynomials are computed from the prover solutions.
+    fn accumulate_internal(
+        &self,
+        max_solutions: usize,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+        cache: Option<&ProverPolynomialCache<N>>,
+    ) -> Result<CoinbaseSolution<N>> {
+        // Ensure the maximum number of prover solutions is valid.
+        Self::ensure_max_solutions(max_solutions)?;
+
         // Ensure there exists prover solutions.
         if prover_solutions.is_empty() {
             bail!("Cannot accumulate an empty list of prover solutions.");
         }
 
-        // Ensure the number of prover solutions does not exceed `MAX_PROVER_SOLUTIONS`.
-        if prover_solutions.len() > N::MAX_PROVER_SOLUTIONS {
-            bail!(
-                "Cannot accumulate beyond {} prover solutions, found {}.",
-                prover_solutions.len(),
-                N::MAX_PROVER_SOLUTIONS
-            );
+        // Ensure the number of prover solutions does not exceed the maximum.
+        if prover_solutions.len() > max_solutions {
+            bail!("Cannot accumulate beyond {max_solutions} prover solutions, found {}.", prover_solutions.len());
         }
 
         // Retrieve the coinbase proving key.
@@ -246,19 +570,42 @@ impl<N: Network> CoinbasePuzzle<N> {
         };
         ensure!(!has_duplicates(prover_solutions), "Cannot accumulate duplicate prover solutions");
 
-        let (prover_polynomials, partial_solutions): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
-            .filter_map(|solution| {
-                if solution.proof().is_hiding() {
-                    return None;
-                }
-                let polynomial = solution.to_prover_polynomial(epoch_challenge).ok()?;
-                Some((polynomial, PartialSolution::new(solution.address(), solution.nonce(), solution.commitment())))
+        // Filter out the prover solutions with hiding proofs.
+        let partial_solutions = prover_solutions
+            .iter()
+            .filter(|solution| !solution.proof().is_hiding())
+            .map(|solution| PartialSolution::new(solution.address(), solution.nonce(), solution.commitment()))
+            .collect();
+
+        Self::accumulate_partial_solutions(pk, epoch_challenge, partial_solutions, cache)
+    }
+
+    /// Returns a coinbase solution for the given epoch challenge and partial solutions.
+    /// If the cache is `None`, the prover polynomials are computed from the partial solutions.
+    ///
+    /// # Note
+    /// This method does *not* check that the partial solutions are valid.
+    fn accumulate_partial_solutions(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        partial_solutions: Vec<PartialSolution<N>>,
+        cache: Option<&ProverPolynomialCache<N>>,
+    ) -> Result<CoinbaseSolution<N>> {
+        let (prover_polynomials, partial_solutions): (Vec<_>, Vec<_>) = cfg_into_iter!(partial_solutions)
+            .map(|solution| {
+                let prover_polynomial = match cache {
+                    Some(cache) => solution.to_prover_polynomial_cached(cache, epoch_challenge)?,
+                    None => solution.to_prover_polynomial(epoch_challenge)?,
+                };
+                Ok((prover_polynomial, solution))
             })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
             .unzip();
 
         // Compute the challenge points.
-        let mut challenges = hash_commitments(partial_solutions.iter().map(|solution| *solution.commitment()))?;
-        ensure!(challenges.len() == partial_solutions.len() + 1, "Invalid number of challenge points");
+        let commitments = partial_solutions.iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let mut challenges = derive_challenges(&commitments)?;
 
         // Pop the last challenge as the accumulator challenge point.
         let accumulator_point = match challenges.pop() {
@@ -314,17 +661,155 @@ impl<N: Network> CoinbasePuzzle<N> {
         coinbase_target: u64,
         proof_target: u64,
     ) -> Result<bool> {
+        self.verify_internal(coinbase_solution, epoch_challenge, coinbase_target, proof_target, None, None, None)
+            .map(|(is_valid, _)| is_valid)
+    }
+
+    /// Returns `true` if the coinbase solution is valid and contains at most `max_solutions` partial solutions.
+    ///
+    /// Testnets may lower the cap below `MAX_PROVER_SOLUTIONS`, and should accumulate with the same cap.
+    pub fn verify_with_max_solutions(
+        &self,
+        max_solutions: usize,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        Self::ensure_max_solutions(max_solutions)?;
+        self.verify_internal(
+            coinbase_solution,
+            epoch_challenge,
+            coinbase_target,
+            proof_target,
+            None,
+            Some(max_solutions),
+            None,
+        )
+        .map(|(is_valid, _)| is_valid)
+    }
+
+    /// Returns `true` if the coinbase solution is valid, along with the computed accumulator commitment.
+    ///
+    /// The accumulator commitment allows a third party to independently re-run the final `KZG10::check`
+    /// against the accumulator point and evaluation derived from the coinbase solution.
+    pub fn verify_with_commitment(
+        &self,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<(bool, KZGCommitment<N::PairingCurve>)> {
+        self.verify_internal(coinbase_solution, epoch_challenge, coinbase_target, proof_target, None, None, None)
+    }
+
+    /// Returns `true` if the coinbase solution is valid, and `false` (rather than an error) if it misses a target.
+    ///
+    /// The coinbase target and proof targets are screened upfront, so solutions that miss a target
+    /// are rejected before computing the prover polynomials, the accumulator MSM, and the pairing check.
+    pub fn verify_lenient(
+        &self,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        // Ensure the coinbase solution meets the required coinbase target.
+        if coinbase_solution.to_cumulative_proof_target()? < coinbase_target as u128 {
+            return Ok(false);
+        }
+        // Ensure each of the partial solutions meets the required proof target.
+        for partial_solution in coinbase_solution.partial_solutions() {
+            if !target_meets(&partial_solution.commitment(), proof_target)? {
+                return Ok(false);
+            }
+        }
+        self.verify(coinbase_solution, epoch_challenge, coinbase_target, proof_target)
+    }
+
+    /// Returns `true` if the coinbase solution is valid, using the given window size for the accumulator MSM.
+    ///
+    /// The default window size is chosen empirically from the number of partial solutions. Operators may
+    /// benchmark their typical number of partial solutions per block, and pin the fastest window size here.
+    pub fn verify_with_msm_config(
+        &self,
+        msm_window: usize,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        ensure!(
+            (1..=MAX_MSM_WINDOW).contains(&msm_window),
+            "The MSM window must be between 1 and {MAX_MSM_WINDOW}, found {msm_window}"
+        );
+        let msm_window = Some(msm_window);
+        self.verify_internal(coinbase_solution, epoch_challenge, coinbase_target, proof_target, msm_window, None, None)
+            .map(|(is_valid, _)| is_valid)
+    }
+
+    /// Returns `true` if the coinbase solution is valid, reusing the prover polynomials from the given cache.
+    pub fn verify_with_cache(
+        &self,
+        cache: &ProverPolynomialCache<N>,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        let cache = Some(cache);
+        self.verify_internal(coinbase_solution, epoch_challenge, coinbase_target, proof_target, None, None, cache)
+            .map(|(is_valid, _)| is_valid)
+    }
+
+    /// Returns `true` if the coinbase solution is valid, reusing the result from the given cache if present.
+    ///
+    /// Results are keyed by the coinbase puzzle ID, so puzzle instances sharing a verifying key
+    /// (e.g. a prover downgraded to a verifier) share cache entries.
+    pub fn verify_cached(
+        &self,
+        cache: &VerificationCache<N>,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<bool> {
+        cache.verify(
+            self.id()?,
+            coinbase_solution,
+            epoch_challenge,
+            coinbase_target,
+            proof_target,
+            || self.verify(coinbase_solution, epoch_challenge, coinbase_target, proof_target),
+        )
+    }
+
+    /// Returns `true` if the coinbase solution is valid.
+    /// If the MSM window is `None`, the default window size is used.
+    /// If the maximum number of solutions is `None`, `MAX_PROVER_SOLUTIONS` is used.
+    /// If the cache is `None`, the prover polynomials are computed from the partial solutions.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_internal(
+        &self,
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+        msm_window: Option<usize>,
+        max_solutions: Option<usize>,
+        cache: Option<&ProverPolynomialCache<N>>,
+    ) -> Result<(bool, KZGCommitment<N::PairingCurve>)> {
         // Ensure the coinbase solution is not empty.
         if coinbase_solution.is_empty() {
             bail!("The coinbase solution does not contain any partial solutions");
         }
 
-        // Ensure the number of partial solutions does not exceed `MAX_PROVER_SOLUTIONS`.
-        if coinbase_solution.len() > N::MAX_PROVER_SOLUTIONS {
+        // Ensure the number of partial solutions does not exceed the maximum.
+        let max_solutions = max_solutions.unwrap_or(N::MAX_PROVER_SOLUTIONS);
+        if coinbase_solution.len() > max_solutions {
             bail!(
-                "The coinbase solution exceeds the allowed number of partial solutions. ({} > {})",
+                "The coinbase solution exceeds the allowed number of partial solutions. ({} > {max_solutions})",
                 coinbase_solution.len(),
-                N::MAX_PROVER_SOLUTIONS
             );
         }
 
@@ -346,20 +831,20 @@ impl<N: Network> CoinbasePuzzle<N> {
         // Compute the prover polynomials.
         let prover_polynomials = cfg_iter!(coinbase_solution.partial_solutions())
             // Ensure that each of the prover solutions meets the required proof target.
-            .map(|solution| match solution.to_target()? >= proof_target {
+            .map(|solution| match target_meets(&solution.commitment(), proof_target)? {
                 // Compute the prover polynomial.
-                true => solution.to_prover_polynomial(epoch_challenge),
+                true => match cache {
+                    Some(cache) => solution.to_prover_polynomial_cached(cache, epoch_challenge),
+                    None => solution.to_prover_polynomial(epoch_challenge),
+                },
                 false => bail!("Prover puzzle does not meet the proof target requirements."),
             })
             .collect::<Result<Vec<_>>>()?;
 
         // Compute the challenge points.
-        let mut challenge_points =
-            hash_commitments(coinbase_solution.partial_solutions().iter().map(|solution| *solution.commitment()))?;
-        ensure!(
-            challenge_points.len() == coinbase_solution.partial_solutions().len() + 1,
-            "Invalid number of challenge points"
-        );
+        let commitments =
+            coinbase_solution.partial_solutions().iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let mut challenge_points = derive_challenges(&commitments)?;
 
         // Pop the last challenge point as the accumulator challenge point.
         let accumulator_point = match challenge_points.pop() {
@@ -380,8 +865,10 @@ impl<N: Network> CoinbasePuzzle<N> {
         let commitments: Vec<_> =
             cfg_iter!(coinbase_solution.partial_solutions()).map(|solution| solution.commitment().0).collect();
         let fs_challenges = challenge_points.into_iter().map(|f| f.to_bigint()).collect::<Vec<_>>();
-        let accumulator_commitment =
-            KZGCommitment::<N::PairingCurve>(VariableBase::msm(&commitments, &fs_challenges).into());
+        let accumulator_commitment = KZGCommitment::<N::PairingCurve>(match msm_window {
+            Some(window) => VariableBase::msm_with_window(&commitments, &fs_challenges, window).into(),
+            None => VariableBase::msm(&commitments, &fs_challenges).into(),
+        });
 
         // Retrieve the coinbase verifying key.
         let coinbase_verifying_key = match self {
@@ -389,14 +876,63 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
         };
 
-        // Return the verification result.
-        Ok(KZG10::check(
+        // Return the verification result, along with the accumulator commitment.
+        let is_valid = KZG10::check(
             coinbase_verifying_key,
             &accumulator_commitment,
             accumulator_point,
             accumulator_evaluation,
             coinbase_solution.proof(),
-        )?)
+        )?;
+        Ok((is_valid, accumulator_commitment))
+    }
+
+    /// Ensures the given maximum number of prover solutions is between 1 and `MAX_PROVER_SOLUTIONS`.
+    fn ensure_max_solutions(max_solutions: usize) -> Result<()> {
+        ensure!(
+            (1..=N::MAX_PROVER_SOLUTIONS).contains(&max_solutions),
+            "The maximum number of prover solutions must be between 1 and {}, found {max_solutions}",
+            N::MAX_PROVER_SOLUTIONS
+        );
+        Ok(())
+    }
+
+    /// Proves, accumulates, and verifies a solution to a trivial epoch challenge,
+    /// returning an error if any step fails. This confirms the loaded keys are functional.
+    pub fn self_test<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<()> {
+        // Retrieve the coinbase proving key.
+        let pk = self.coinbase_proving_key()?;
+
+        // Recover the degree from the product domain, which is sized for `2 * degree + 1` coefficients.
+        let degree = u32::try_from(pk.product_domain.size() / 2)?
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("Invalid product domain size for the coinbase puzzle"))?;
+        // Construct a trivial epoch challenge.
+        let epoch_challenge = EpochChallenge::new(0, Default::default(), degree)?;
+
+        // Sample a prover address and nonce.
+        let address = Address::try_from(PrivateKey::new(rng)?)?;
+        let nonce = rng.gen();
+
+        // Prove a coinbase solution.
+        // Note: The opening proof is checked explicitly, as `prove` only asserts it in debug builds.
+        let (polynomial, product_evaluations, partial_solution) =
+            Self::commit_with_key(pk, &epoch_challenge, address, nonce)?;
+        let prover_solution =
+            Self::open_unchecked(pk, &epoch_challenge, &polynomial, &product_evaluations, partial_solution)?;
+        ensure!(
+            Self::check_opening(pk, &epoch_challenge, &polynomial, &prover_solution)?,
+            "The coinbase puzzle self-test failed to check the opening proof"
+        );
+
+        // Accumulate the coinbase solution.
+        let coinbase_solution = self.accumulate_unchecked(&epoch_challenge, &[prover_solution])?;
+
+        // Verify the coinbase solution.
+        match self.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64)? {
+            true => Ok(()),
+            false => bail!("The coinbase puzzle self-test failed to verify the coinbase solution"),
+        }
     }
 
     /// Returns the coinbase proving key.
@@ -414,6 +950,13 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
         }
     }
+
+    /// Returns the ID of the coinbase puzzle, as the BHP hash of the bytes of its verifying key.
+    ///
+    /// Note: The verifying key is independent of the degree, so puzzles trimmed from the same SRS share an ID.
+    pub fn id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.coinbase_verifying_key().to_bytes_le()?.to_bits_le())
+    }
 }
 
 impl<N: Network> CoinbasePuzzle<N> {
@@ -433,6 +976,56 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(product_domain)
     }
 
+    /// Returns the commitment to the product of the given prover polynomial and the epoch polynomial.
+    /// This allows harnesses to inject known polynomials, and check the FFT and commitment pipeline in isolation.
+    pub fn commit_prover_polynomial(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Result<PuzzleCommitment<N>> {
+        // Retrieve the coinbase proving key.
+        let pk = self.coinbase_proving_key()?;
+        // Ensure the epoch challenge is compatible with the proving key.
+        Self::ensure_proving_key_supports(pk, epoch_challenge)?;
+        // Ensure the prover polynomial matches the degree of the epoch challenge.
+        ensure!(
+            polynomial.degree() <= epoch_challenge.degree() as usize,
+            "The prover polynomial exceeds the epoch challenge degree ({} > {})",
+            polynomial.degree(),
+            epoch_challenge.degree()
+        );
+
+        let product_evaluations = Self::product_evaluations(pk, epoch_challenge, polynomial);
+        let (commitment, _rand) =
+            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+        Ok(commitment.into())
+    }
+
+    /// Returns the evaluations of the product of the given prover polynomial and the epoch polynomial.
+    fn product_evaluations(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Vec<<N::PairingCurve as PairingEngine>::Fr> {
+        let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(polynomial, &pk.fft_precomputation);
+        pk.product_domain.mul_polynomials_in_evaluation_domain(
+            polynomial_evaluations,
+            &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+        )
+    }
+
+    /// Ensures the product domain of the proving key matches the degree of the epoch challenge.
+    fn ensure_proving_key_supports(pk: &CoinbaseProvingKey<N>, epoch_challenge: &EpochChallenge<N>) -> Result<()> {
+        let num_evaluations = epoch_challenge.epoch_polynomial_evaluations().evaluations.len();
+        ensure!(
+            num_evaluations == pk.product_domain.size(),
+            "The epoch challenge (degree {}) is incompatible with the proving key (domain size {})",
+            epoch_challenge.degree(),
+            pk.product_domain.size()
+        );
+        Ok(())
+    }
+
     /// Returns the prover polynomial for the coinbase puzzle.
     pub fn prover_polynomial(
         epoch_challenge: &EpochChallenge<N>,
@@ -449,4 +1042,19 @@ impl<N: Network> CoinbasePuzzle<N> {
         };
         Ok(hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, epoch_challenge.degree()))
     }
+
+    /// Returns the prover polynomial from the given coefficients, in place of hashing the prover input.
+    /// The number of coefficients must be `degree + 1`.
+    pub fn prover_polynomial_from_coeffs(
+        coeffs: Vec<<N::PairingCurve as PairingEngine>::Fr>,
+        degree: u32,
+    ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
+        let num_coefficients = degree.checked_add(1).ok_or_else(|| anyhow!("Degree is too large"))?;
+        ensure!(
+            coeffs.len() == num_coefficients as usize,
+            "Expected {num_coefficients} coefficients for degree {degree}, found {}",
+            coeffs.len()
+        );
+        Ok(DensePolynomial::from_coefficients_vec(coeffs))
+    }
 }
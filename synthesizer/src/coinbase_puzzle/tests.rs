@@ -15,13 +15,48 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use console::{account::*, network::Testnet3};
-use snarkvm_utilities::Uniform;
+use console::{
+    account::*,
+    network::Testnet3,
+    prelude::{Environment, FromStr},
+};
+use snarkvm_algorithms::polycommit::kzg10::{KZGProof, KZGRandomness, Powers};
+use snarkvm_curves::{
+    bls12_377::{Fq, Fr, G1Affine},
+    AffineCurve,
+};
+use snarkvm_fields::One;
+use snarkvm_utilities::{FromBytes, Uniform};
 
+use proptest::{
+    collection::hash_set,
+    prelude::{any, any_with, prop_assert, prop_assert_eq, proptest, Just, ProptestConfig, Strategy},
+};
 use rand::RngCore;
+use std::borrow::Cow;
 
 const ITERATIONS: u64 = 100;
 
+/// The degree of the puzzles sampled by `sample_puzzle`.
+const DEGREE: u32 = (1 << 8) - 1;
+
+/// Returns an SRS that supports puzzles up to degree `2^15`.
+fn sample_srs() -> SRS<<Testnet3 as Environment>::PairingCurve> {
+    CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree: 1 << 15 }).unwrap()
+}
+
+/// Returns a puzzle of degree `DEGREE`, and an epoch challenge for it.
+fn sample_puzzle(rng: &mut TestRng) -> (CoinbasePuzzle<Testnet3>, EpochChallenge<Testnet3>) {
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&sample_srs(), PuzzleConfig { degree: DEGREE }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), DEGREE).unwrap();
+    (puzzle, epoch_challenge)
+}
+
+/// Returns the address of a new private key.
+fn sample_address(rng: &mut TestRng) -> Address<Testnet3> {
+    Address::try_from(PrivateKey::<Testnet3>::new(rng).unwrap()).unwrap()
+}
+
 #[test]
 fn test_coinbase_puzzle() {
     let mut rng = TestRng::default();
@@ -108,3 +143,825 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_self_test() {
+    let mut rng = TestRng::default();
+
+    let srs = sample_srs();
+
+    // Ensure the self-test passes for a correctly loaded puzzle.
+    let degree = (1 << 13) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    assert!(puzzle.self_test(&mut rng).is_ok());
+
+    // Corrupt the verifying key.
+    let mut proving_key = puzzle.coinbase_proving_key().unwrap().clone();
+    proving_key.verifying_key.beta_h = proving_key.verifying_key.h;
+    let corrupted_puzzle = CoinbasePuzzle::<Testnet3>::Prover(Arc::new(proving_key));
+
+    // Ensure the self-test fails for the corrupted puzzle.
+    assert!(corrupted_puzzle.self_test(&mut rng).is_err());
+}
+
+/// Returns a strategy for an arbitrary epoch challenge, and a set of prover solutions for it.
+fn arbitrary_epoch_and_solutions() -> impl Strategy<Value = (EpochChallenge<Testnet3>, Vec<ProverSolution<Testnet3>>)> {
+    any::<EpochChallenge<Testnet3>>().prop_flat_map(|epoch_challenge| {
+        let solutions = hash_set(any_with::<ProverSolution<Testnet3>>(Some(epoch_challenge.clone())), 1..4);
+        (Just(epoch_challenge), solutions.prop_map(|solutions| solutions.into_iter().collect()))
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+
+    #[test]
+    fn test_arbitrary_instances(
+        (epoch_challenge, solutions) in arbitrary_epoch_and_solutions(),
+        partial_solution in any::<PartialSolution<Testnet3>>(),
+    ) {
+        // Ensure the arbitrary instances round-trip through bytes.
+        prop_assert_eq!(&epoch_challenge, &EpochChallenge::from_bytes_le(&epoch_challenge.to_bytes_le().unwrap()).unwrap());
+        prop_assert_eq!(partial_solution, PartialSolution::from_bytes_le(&partial_solution.to_bytes_le().unwrap()).unwrap());
+        for solution in &solutions {
+            prop_assert_eq!(*solution, ProverSolution::from_bytes_le(&solution.to_bytes_le().unwrap()).unwrap());
+        }
+
+        // Ensure each arbitrary prover solution verifies on its own.
+        let config = PuzzleConfig { degree: EpochChallenge::<Testnet3>::ARBITRARY_DEGREE };
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&CoinbasePuzzle::<Testnet3>::setup(config).unwrap(), config).unwrap();
+        for solution in &solutions {
+            prop_assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+        }
+
+        // Ensure the arbitrary prover solutions verify after accumulation.
+        let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+        prop_assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    }
+}
+
+#[test]
+fn test_trim_hybrid() {
+    let mut rng = TestRng::default();
+
+    let srs = sample_srs();
+
+    let verify_degree = (1 << 13) - 1;
+    let prove_degree = (1 << 8) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: verify_degree }).unwrap();
+    let hybrid = CoinbasePuzzle::<Testnet3>::trim_hybrid(&srs, PuzzleConfig { degree: verify_degree }, PuzzleConfig {
+        degree: prove_degree,
+    })
+    .unwrap();
+
+    // Ensure the hybrid puzzle verifies solutions up to the verify degree.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), verify_degree).unwrap();
+    let address = sample_address(&mut rng);
+    let solution = puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+    assert!(hybrid.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Ensure the hybrid puzzle errors when proving beyond the prove degree.
+    assert!(hybrid.prove(&epoch_challenge, address, rng.gen(), None).is_err());
+
+    // Ensure the hybrid puzzle proves up to the prove degree.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), prove_degree).unwrap();
+    let solution = hybrid.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let coinbase_solution = hybrid.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+    assert!(hybrid.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Ensure the prove degree may not exceed the verify degree.
+    assert!(
+        CoinbasePuzzle::<Testnet3>::trim_hybrid(&srs, PuzzleConfig { degree: prove_degree }, PuzzleConfig {
+            degree: verify_degree,
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn test_prover_polynomial_from_coeffs() {
+    type Fr = <<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr;
+
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    // Ensure the hashed prover polynomial matches when injected as coefficients.
+    let address = sample_address(&mut rng);
+    let nonce = rng.gen();
+    let expected = CoinbasePuzzle::prover_polynomial(&epoch_challenge, address, nonce).unwrap();
+    let candidate = CoinbasePuzzle::<Testnet3>::prover_polynomial_from_coeffs(expected.coeffs.clone(), DEGREE).unwrap();
+    assert_eq!(expected, candidate);
+
+    // Ensure the injected polynomial commits to the same commitment as the prover solution.
+    let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+    assert_eq!(solution.commitment(), puzzle.commit_prover_polynomial(&epoch_challenge, &candidate).unwrap());
+
+    // Ensure the constant polynomial `1` commits to the epoch polynomial.
+    let mut coeffs = vec![Fr::zero(); DEGREE as usize + 1];
+    coeffs[0] = Fr::one();
+    let one = CoinbasePuzzle::<Testnet3>::prover_polynomial_from_coeffs(coeffs, DEGREE).unwrap();
+    let pk = puzzle.coinbase_proving_key().unwrap();
+    let (expected, _) = KZG10::commit_lagrange(
+        &pk.lagrange_basis(),
+        &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+        None,
+        &Default::default(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(PuzzleCommitment::from(expected), puzzle.commit_prover_polynomial(&epoch_challenge, &one).unwrap());
+
+    // Ensure the number of coefficients must match the degree.
+    let coeffs = vec![Fr::one(); DEGREE as usize];
+    assert!(CoinbasePuzzle::<Testnet3>::prover_polynomial_from_coeffs(coeffs, DEGREE).is_err());
+}
+
+#[test]
+fn test_verify_with_msm_config() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+    let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), DEGREE).unwrap();
+
+    for batch_size in [1, 16, 40] {
+        let solutions = (0..batch_size)
+            .map(|_| {
+                let address = sample_address(&mut rng);
+                puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+
+        // Ensure varying the MSM window produces identical results.
+        for msm_window in 1..=MAX_MSM_WINDOW {
+            assert!(puzzle.verify_with_msm_config(msm_window, &coinbase_solution, &epoch_challenge, 0, 0).unwrap());
+            assert!(
+                !puzzle.verify_with_msm_config(msm_window, &coinbase_solution, &bad_epoch_challenge, 0, 0).unwrap()
+            );
+        }
+
+        // Ensure an out-of-range MSM window is rejected.
+        assert!(puzzle.verify_with_msm_config(0, &coinbase_solution, &epoch_challenge, 0, 0).is_err());
+        assert!(puzzle.verify_with_msm_config(MAX_MSM_WINDOW + 1, &coinbase_solution, &epoch_challenge, 0, 0).is_err());
+    }
+}
+
+#[test]
+fn test_max_solutions() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..3)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure a cap of 2 accepts 2 solutions, in both accumulation and verification.
+    let coinbase_solution =
+        puzzle.accumulate_unchecked_with_max_solutions(2, &epoch_challenge, &solutions[..2]).unwrap();
+    assert!(puzzle.verify_with_max_solutions(2, &coinbase_solution, &epoch_challenge, 0, 0).unwrap());
+
+    // Ensure a cap of 2 rejects 3 solutions, in both accumulation and verification.
+    assert!(puzzle.accumulate_unchecked_with_max_solutions(2, &epoch_challenge, &solutions).is_err());
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0, 0).unwrap());
+    assert!(puzzle.verify_with_max_solutions(2, &coinbase_solution, &epoch_challenge, 0, 0).is_err());
+
+    // Ensure an out-of-range cap is rejected.
+    let max_solutions = <Testnet3 as Network>::MAX_PROVER_SOLUTIONS;
+    assert!(puzzle.accumulate_unchecked_with_max_solutions(0, &epoch_challenge, &solutions).is_err());
+    assert!(puzzle.verify_with_max_solutions(0, &coinbase_solution, &epoch_challenge, 0, 0).is_err());
+    assert!(puzzle.verify_with_max_solutions(max_solutions + 1, &coinbase_solution, &epoch_challenge, 0, 0).is_err());
+}
+
+#[test]
+fn test_verify_lenient() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..5)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    let cumulative_target = coinbase_solution.to_cumulative_proof_target().unwrap();
+    let minimum_target = solutions.iter().map(|solution| solution.to_target().unwrap()).min().unwrap();
+
+    // Ensure a valid solution verifies.
+    assert!(puzzle.verify_lenient(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Ensure a solution failing the proof target returns `false`, where `verify` returns an error.
+    let proof_target = minimum_target + 1;
+    assert!(!puzzle.verify_lenient(&coinbase_solution, &epoch_challenge, 0u64, proof_target).unwrap());
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, proof_target).is_err());
+
+    // Ensure a solution failing the coinbase target returns `false`, where `verify` returns an error.
+    if let Ok(coinbase_target) = u64::try_from(cumulative_target + 1) {
+        assert!(!puzzle.verify_lenient(&coinbase_solution, &epoch_challenge, coinbase_target, 0u64).unwrap());
+        assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, coinbase_target, 0u64).is_err());
+    }
+
+    // Ensure the target screen runs before the proof is checked, by pairing the solution with an invalid proof.
+    let partial_solutions = coinbase_solution.partial_solutions().to_vec();
+    let invalid_solution = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+    assert!(!puzzle.verify_lenient(&invalid_solution, &epoch_challenge, 0u64, proof_target).unwrap());
+}
+
+#[test]
+fn test_puzzle_config_validate() {
+    // Ensure valid degrees succeed, with a product domain large enough for the product polynomial.
+    for log_degree in 1..=20 {
+        for degree in [(1u32 << log_degree) - 1, 1u32 << log_degree] {
+            let domain = PuzzleConfig::validate::<Testnet3>(degree).unwrap();
+            assert!(domain.size() >= 2 * degree as usize + 1);
+            assert!(domain.size().is_power_of_two());
+        }
+    }
+
+    // Ensure impossible degrees return a descriptive error.
+    for degree in [0, u32::MAX] {
+        let error = PuzzleConfig::validate::<Testnet3>(degree).unwrap_err().to_string();
+        assert!(error.starts_with(&format!("Invalid coinbase puzzle degree '{degree}'")), "{error}");
+    }
+}
+
+#[test]
+fn test_find_solution() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+    let address = sample_address(&mut rng);
+
+    // Ensure an unreachable target reports the best target observed, without a solution.
+    let outcome = puzzle.find_solution(&epoch_challenge, address, 0..5, u64::MAX).unwrap();
+    let targets = (0..5).map(|nonce| {
+        let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+        solution.to_target().unwrap()
+    });
+    assert!(outcome.solution.is_none());
+    assert_eq!(outcome.attempts, 5);
+    assert_eq!(outcome.best_target, targets.max().unwrap());
+
+    // Ensure a reachable target returns the first solution that meets it.
+    let outcome = puzzle.find_solution(&epoch_challenge, address, 0..5, 0).unwrap();
+    assert_eq!(outcome.attempts, 1);
+    let solution = outcome.solution.unwrap();
+    assert_eq!(solution.nonce(), 0);
+    assert_eq!(solution.to_target().unwrap(), outcome.best_target);
+    assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+    // Ensure opening the commitment from the search matches proving the nonce directly.
+    assert_eq!(solution, puzzle.prove(&epoch_challenge, address, 0, None).unwrap());
+}
+
+#[test]
+fn test_search() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+    let address = sample_address(&mut rng);
+
+    // Compute the best target in the range, which guarantees a solution at that proof target.
+    let nonces = 0..16;
+    let best_target = nonces
+        .clone()
+        .map(|nonce| puzzle.prove(&epoch_challenge, address, nonce, None).unwrap().to_target().unwrap())
+        .max()
+        .unwrap();
+
+    // Ensure a range containing a solution returns one that meets the proof target.
+    let solution = puzzle.search(best_target, &epoch_challenge, address, nonces.clone()).unwrap().unwrap();
+    assert!(nonces.contains(&solution.nonce()));
+    assert!(solution.to_target().unwrap() >= best_target);
+    assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, best_target).unwrap());
+
+    // Ensure any solution is returned when every nonce meets the proof target.
+    let solution = puzzle.search(0, &epoch_challenge, address, nonces.clone()).unwrap().unwrap();
+    assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+
+    // Ensure a range without a solution returns `None`.
+    if let Some(proof_target) = best_target.checked_add(1) {
+        assert!(puzzle.search(proof_target, &epoch_challenge, address, nonces).unwrap().is_none());
+    }
+    assert!(puzzle.search(0, &epoch_challenge, address, 3..3).unwrap().is_none());
+}
+
+#[test]
+fn test_accumulator() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..10)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Push the prover solutions one at a time.
+    let mut accumulator = Accumulator::new(puzzle.clone());
+    for solution in &solutions {
+        accumulator.push(*solution).unwrap();
+    }
+    assert_eq!(solutions.len(), accumulator.len());
+
+    // Ensure duplicate and hiding prover solutions are rejected on push.
+    assert!(accumulator.push(solutions[0]).is_err());
+    let hiding_proof = KZGProof { w: solutions[0].proof().w, random_v: Some(rng.gen()) };
+    let partial_solution = PartialSolution::new(solutions[0].address(), rng.gen(), KZGCommitment(rng.gen()));
+    assert!(accumulator.push(ProverSolution::new(partial_solution, hiding_proof)).is_err());
+    assert_eq!(solutions.len(), accumulator.len());
+
+    // Ensure finalizing matches a single accumulation of the same prover solutions.
+    let expected = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    let candidate = accumulator.finalize(&epoch_challenge).unwrap();
+    assert_eq!(expected, candidate);
+    assert!(puzzle.verify(&candidate, &epoch_challenge, 0u64, 0u64).unwrap());
+}
+
+#[test]
+fn test_merge() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..10)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Accumulate each half independently, and the whole set at once.
+    let (first, second) = solutions.split_at(solutions.len() / 2);
+    let first = puzzle.accumulate_unchecked(&epoch_challenge, first).unwrap();
+    let second = puzzle.accumulate_unchecked(&epoch_challenge, second).unwrap();
+    let expected = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+
+    // Ensure merging the halves matches accumulating the whole set.
+    let candidate = first.merge(&second, &epoch_challenge, &puzzle).unwrap();
+    assert_eq!(expected, candidate);
+    assert!(puzzle.verify(&candidate, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Ensure merging overlapping solutions keeps a single copy of each partial solution.
+    assert_eq!(expected, candidate.merge(&first, &epoch_challenge, &puzzle).unwrap());
+
+    // Ensure a verifier cannot merge.
+    let verifier = CoinbasePuzzle::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    assert!(first.merge(&second, &epoch_challenge, &verifier).is_err());
+}
+
+#[test]
+fn test_verify_with_commitment() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..5)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+
+    // Ensure the result matches `verify`.
+    let (is_valid, commitment) = puzzle.verify_with_commitment(&solution, &epoch_challenge, 0u64, 0u64).unwrap();
+    assert!(is_valid);
+    assert_eq!(is_valid, puzzle.verify(&solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Recompute the accumulator point and evaluation independently.
+    let mut challenge_points =
+        hash_commitments(solution.partial_solutions().iter().map(|solution| *solution.commitment())).unwrap();
+    let accumulator_point = challenge_points.pop().unwrap();
+    let mut evaluation = solution.partial_solutions().iter().zip(&challenge_points).fold(
+        <<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr::zero(),
+        |accumulator, (partial_solution, challenge_point)| {
+            let polynomial = partial_solution.to_prover_polynomial(&epoch_challenge).unwrap();
+            accumulator + polynomial.evaluate(accumulator_point) * challenge_point
+        },
+    );
+    evaluation *= &epoch_challenge.epoch_polynomial().evaluate(accumulator_point);
+
+    // Ensure the returned commitment reproduces the verification result with a manual `KZG10::check`.
+    let vk = puzzle.coinbase_verifying_key();
+    assert!(KZG10::check(vk, &commitment, accumulator_point, evaluation, solution.proof()).unwrap());
+    // Ensure a mismatched evaluation is rejected.
+    let mismatched = evaluation + <<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr::one();
+    assert!(!KZG10::check(vk, &commitment, accumulator_point, mismatched, solution.proof()).unwrap());
+}
+
+#[test]
+fn test_hiding_proofs_are_rejected() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let address = sample_address(&mut rng);
+    let nonce = rng.gen();
+    let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+    let hiding_solution = puzzle.prove_hiding(&epoch_challenge, address, nonce, &mut rng).unwrap();
+    assert!(hiding_solution.proof().is_hiding());
+    assert_eq!(solution.commitment(), hiding_solution.commitment());
+
+    // Ensure the prover solution verifier rejects the hiding proof.
+    let vk = puzzle.coinbase_verifying_key();
+    assert!(solution.verify(vk, &epoch_challenge, 0).unwrap());
+    assert!(!hiding_solution.verify(vk, &epoch_challenge, 0).unwrap());
+
+    // Ensure accumulation skips the hiding prover solution.
+    let other = puzzle.prove(&epoch_challenge, address, nonce.wrapping_add(1), None).unwrap();
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[other, hiding_solution]).unwrap();
+    let expected = PartialSolution::new(other.address(), other.nonce(), other.commitment());
+    assert_eq!(coinbase_solution.partial_solutions(), &[expected]);
+
+    // Ensure the accumulator rejects the hiding prover solution on push.
+    let error = Accumulator::new(puzzle.clone()).push(hiding_solution).unwrap_err();
+    assert_eq!("Cannot accumulate a prover solution with a hiding proof", error.to_string());
+
+    // Ensure the coinbase verifier rejects a hiding coinbase proof.
+    let hiding_proof = *hiding_solution.proof();
+    let hiding_coinbase_solution = CoinbaseSolution::new(coinbase_solution.partial_solutions().to_vec(), hiding_proof);
+    let error = puzzle.verify(&hiding_coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap_err();
+    assert_eq!("The coinbase proof must be non-hiding", error.to_string());
+}
+
+#[test]
+fn test_string_roundtrip() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..5)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    for solution in &solutions {
+        // Check the prover solution.
+        let candidate = ProverSolution::<Testnet3>::from_str(&solution.to_string()).unwrap();
+        assert_eq!(solution.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+
+        // Check the partial solution.
+        let expected = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+        let candidate = PartialSolution::<Testnet3>::from_str(&expected.to_string()).unwrap();
+        assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+    }
+
+    // Check the coinbase solution.
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    let candidate = CoinbaseSolution::<Testnet3>::from_str(&coinbase_solution.to_string()).unwrap();
+    assert_eq!(coinbase_solution.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+    assert!(puzzle.verify(&candidate, &epoch_challenge, 0u64, 0u64).unwrap());
+}
+
+#[test]
+fn test_prover_solution_from_components() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let address = sample_address(&mut rng);
+    let solution = puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let commitment_bytes = solution.commitment().to_bytes_le().unwrap();
+    let proof_bytes = solution.proof().to_bytes_le().unwrap();
+
+    // Ensure the prover solution is reconstructed from its components.
+    let candidate =
+        ProverSolution::from_components(solution.address(), solution.nonce(), &commitment_bytes, &proof_bytes).unwrap();
+    assert_eq!(solution, candidate);
+    assert!(candidate.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+
+    // Ensure truncated and extended components are rejected.
+    let truncated = &commitment_bytes[1..];
+    assert!(ProverSolution::<Testnet3>::from_components(address, 0, truncated, &proof_bytes).is_err());
+    let extended = [&proof_bytes[..], &[0u8]].concat();
+    assert!(ProverSolution::<Testnet3>::from_components(address, 0, &commitment_bytes, &extended).is_err());
+
+    // Find an x-coordinate without a corresponding point on the curve.
+    let x = (1u64..).map(Fq::from).find(|x| G1Affine::from_x_coordinate(*x, true).is_none()).unwrap();
+    // Ensure the bytes of an off-curve commitment are rejected.
+    let off_curve = KZGCommitment::<<Testnet3 as Environment>::PairingCurve>(G1Affine::new(x, Fq::one(), false));
+    let off_curve_bytes = off_curve.to_bytes_le().unwrap();
+    let error = ProverSolution::<Testnet3>::from_components(address, 0, &off_curve_bytes, &proof_bytes).unwrap_err();
+    assert!(error.to_string().starts_with("Invalid commitment bytes for the prover solution"));
+}
+
+#[test]
+fn test_verify_cached() {
+    let mut rng = TestRng::default();
+
+    let srs = sample_srs();
+
+    let degree = (1 << 8) - 1;
+    let prover = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Construct a verifier with the same verifying key.
+    let verifier = CoinbasePuzzle::Verifier(Arc::new(prover.coinbase_verifying_key().clone()));
+    assert_eq!(prover.id().unwrap(), verifier.id().unwrap());
+
+    let address = sample_address(&mut rng);
+    let solution = prover.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let coinbase_solution = prover.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+
+    let cache = VerificationCache::new();
+    assert!(cache.is_empty());
+
+    // Ensure the first verification populates the cache.
+    assert!(prover.verify_cached(&cache, &coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (1, 0));
+
+    // Ensure the verifier hits the cache entry populated by the prover.
+    assert!(verifier.verify_cached(&cache, &coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (1, 1));
+
+    // Ensure a different epoch challenge does not hit the cache.
+    let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    assert!(!verifier.verify_cached(&cache, &coinbase_solution, &bad_epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (2, 1));
+
+    // Ensure a verifier with a different verifying key does not hit the cache.
+    let mut verifying_key = prover.coinbase_verifying_key().clone();
+    verifying_key.g = verifying_key.gamma_g;
+    let other = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(verifying_key));
+    assert_ne!(prover.id().unwrap(), other.id().unwrap());
+    assert!(!other.verify_cached(&cache, &coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (3, 1));
+
+    // Ensure a full cache evicts the oldest verification result.
+    let cache = VerificationCache::with_capacity(2);
+    let id = prover.id().unwrap();
+    for coinbase_target in 0..3u64 {
+        assert!(cache.verify(id, &coinbase_solution, &epoch_challenge, coinbase_target, 0, || Ok(true)).unwrap());
+    }
+    assert_eq!((cache.capacity(), cache.len(), cache.num_hits()), (2, 2, 0));
+    assert!(cache.verify(id, &coinbase_solution, &epoch_challenge, 2, 0, || bail!("The result is cached")).unwrap());
+    assert!(!cache.verify(id, &coinbase_solution, &epoch_challenge, 0, 0, || Ok(false)).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (2, 1));
+}
+
+#[test]
+fn test_prover_polynomial_cached() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let address = sample_address(&mut rng);
+    let solution = puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let expected = solution.to_prover_polynomial(&epoch_challenge).unwrap();
+
+    let cache = ProverPolynomialCache::new();
+    assert!(cache.is_empty());
+
+    // Ensure repeated calls return the same prover polynomial, and only the first call computes it.
+    for i in 0..3 {
+        assert_eq!(expected, solution.to_prover_polynomial_cached(&cache, &epoch_challenge).unwrap());
+        assert_eq!((cache.len(), cache.num_hits()), (1, i));
+    }
+
+    // Ensure a different epoch challenge does not hit the cache.
+    let next_epoch_number = epoch_challenge.epoch_number().wrapping_add(1);
+    let next_epoch_challenge = EpochChallenge::new(next_epoch_number, Default::default(), DEGREE).unwrap();
+    let next_expected = solution.to_prover_polynomial(&next_epoch_challenge).unwrap();
+    assert_ne!(expected, next_expected);
+    assert_eq!(next_expected, solution.to_prover_polynomial_cached(&cache, &next_epoch_challenge).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (2, 2));
+
+    // Ensure pruning removes the prover polynomials of the earlier epoch.
+    cache.prune(next_epoch_number);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(next_expected, solution.to_prover_polynomial_cached(&cache, &next_epoch_challenge).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (1, 3));
+
+    // Ensure a full cache evicts the oldest prover polynomial.
+    let cache = ProverPolynomialCache::with_capacity(1);
+    assert_eq!(expected, solution.to_prover_polynomial_cached(&cache, &epoch_challenge).unwrap());
+    assert_eq!(next_expected, solution.to_prover_polynomial_cached(&cache, &next_epoch_challenge).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (1, 0));
+    assert_eq!(next_expected, solution.to_prover_polynomial_cached(&cache, &next_epoch_challenge).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (1, 1));
+}
+
+#[test]
+fn test_accumulate_and_verify_with_cache() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let solutions = (0..4)
+        .map(|_| {
+            let address = sample_address(&mut rng);
+            puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure accumulating with the cache matches accumulating without it, and caches the prover polynomials.
+    let cache = ProverPolynomialCache::new();
+    let coinbase_solution = puzzle.accumulate_unchecked_with_cache(&cache, &epoch_challenge, &solutions).unwrap();
+    assert!(coinbase_solution == puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (4, 0));
+
+    // Ensure verifying with the cache reads the prover polynomials from the cache.
+    assert!(puzzle.verify_with_cache(&cache, &coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!((cache.len(), cache.num_hits()), (4, 4));
+}
+
+#[test]
+fn test_prove_batch() {
+    let mut rng = TestRng::default();
+
+    let (puzzle, epoch_challenge) = sample_puzzle(&mut rng);
+
+    let addresses = (0..4)
+        .map(|_| sample_address(&mut rng))
+        .collect::<Vec<_>>();
+    let nonce = rng.gen();
+
+    // Ensure the batched prover solutions match the prover solutions for each address.
+    let solutions = puzzle.prove_batch(&epoch_challenge, &addresses, nonce).unwrap();
+    assert_eq!(solutions.len(), addresses.len());
+    for (address, solution) in addresses.iter().zip(&solutions) {
+        assert_eq!(*solution, puzzle.prove(&epoch_challenge, *address, nonce, None).unwrap());
+        assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+    }
+
+    // Ensure an empty batch produces no prover solutions.
+    assert!(puzzle.prove_batch(&epoch_challenge, &[], nonce).unwrap().is_empty());
+
+    // Ensure a verifier cannot prove a batch.
+    let verifier = CoinbasePuzzle::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    assert!(verifier.prove_batch(&epoch_challenge, &addresses, nonce).is_err());
+
+    // Ensure an incompatible epoch challenge is rejected.
+    let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), (1 << 9) - 1).unwrap();
+    assert!(puzzle.prove_batch(&bad_epoch_challenge, &addresses, nonce).is_err());
+}
+
+#[test]
+fn test_prove_with_mismatched_degree() {
+    let mut rng = TestRng::default();
+
+    let srs = sample_srs();
+
+    let degree = (1 << 8) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let address = sample_address(&mut rng);
+
+    // Ensure proving an epoch challenge with a smaller or larger degree returns an error, rather than panicking.
+    for mismatched_degree in [(1 << 7) - 1, (1 << 9) - 1] {
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), mismatched_degree).unwrap();
+        let error = puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap_err();
+        assert!(error.to_string().contains("is incompatible with the proving key"), "Unexpected error: {error}");
+        assert!(puzzle.prove_abm(0, &epoch_challenge, address, rng.gen()).is_err());
+        assert!(puzzle.find_solution(&epoch_challenge, address, [rng.gen()], 0).is_err());
+    }
+
+    // Ensure a compatible epoch challenge is proven.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    assert!(puzzle.prove(&epoch_challenge, address, rng.gen(), None).is_ok());
+}
+
+#[test]
+fn test_setup_contributions() {
+    let mut rng = TestRng::default();
+
+    let srs = sample_srs();
+
+    let config = PuzzleConfig { degree: (1 << 8) - 1 };
+    let initial = SetupPowers::<Testnet3>::from_srs(&srs, config).unwrap();
+
+    // Simulate two sequential contributions.
+    let mut first = initial.clone();
+    let first_proof = CoinbasePuzzle::contribute(&mut first, &mut rng).unwrap();
+    let mut second = first.clone();
+    let second_proof = CoinbasePuzzle::contribute(&mut second, &mut rng).unwrap();
+    assert_ne!(initial, first);
+    assert_ne!(first, second);
+
+    // Ensure the chain of contribution proofs verifies.
+    assert!(CoinbasePuzzle::verify_contribution(&initial, &first, &first_proof).unwrap());
+    assert!(CoinbasePuzzle::verify_contribution(&first, &second, &second_proof).unwrap());
+
+    // Ensure the proofs do not verify out of order, or for skipped contributions.
+    assert!(!CoinbasePuzzle::verify_contribution(&initial, &first, &second_proof).unwrap());
+    assert!(!CoinbasePuzzle::verify_contribution(&first, &second, &first_proof).unwrap());
+    assert!(!CoinbasePuzzle::verify_contribution(&initial, &second, &second_proof).unwrap());
+
+    // Ensure the contributed powers yield a working coinbase puzzle.
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim_powers(&second, config).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), config.degree).unwrap();
+    let address = sample_address(&mut rng);
+    let solution = puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap();
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Ensure the untouched powers match the puzzle trimmed from the SRS.
+    let expected = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+    let candidate = CoinbasePuzzle::<Testnet3>::trim_powers(&initial, config).unwrap();
+    assert_eq!(expected.coinbase_verifying_key(), candidate.coinbase_verifying_key());
+    assert_eq!(
+        expected.coinbase_proving_key().unwrap().lagrange_basis_at_beta_g,
+        candidate.coinbase_proving_key().unwrap().lagrange_basis_at_beta_g
+    );
+}
+
+#[test]
+fn test_epoch_polynomial_commitment() {
+    let mut rng = TestRng::default();
+
+    let srs = sample_srs();
+
+    let config = PuzzleConfig { degree: (1 << 8) - 1 };
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+    let epoch_challenge = EpochChallenge::<Testnet3>::new(rng.next_u32(), Default::default(), config.degree).unwrap();
+    let commitment = epoch_challenge.polynomial_commitment(&srs).unwrap();
+
+    // Ensure the commitment matches a KZG commitment to the epoch polynomial.
+    let num_coefficients = epoch_challenge.num_coefficients().unwrap() as usize;
+    let powers = Powers {
+        powers_of_beta_g: Cow::Owned(srs.powers_of_beta_g(0, num_coefficients).unwrap()),
+        powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
+    };
+    let commit = |polynomial: &DensePolynomial<Fr>| {
+        KZG10::commit(&powers, &polynomial.into(), None, &Default::default(), None).unwrap().0
+    };
+    assert_eq!(commit(epoch_challenge.epoch_polynomial()), commitment);
+
+    // Ensure a prover can check its locally-derived epoch polynomial against the commitment, with an opening.
+    let point = Fr::rand(&mut rng);
+    let evaluation = epoch_challenge.epoch_polynomial().evaluate(point);
+    let proof = KZG10::open(&powers, epoch_challenge.epoch_polynomial(), point, &KZGRandomness::empty()).unwrap();
+    assert!(KZG10::check(puzzle.coinbase_verifying_key(), &commitment, point, evaluation, &proof).unwrap());
+
+    // Ensure a tampered epoch polynomial produces a different commitment.
+    let mut coefficients = epoch_challenge.epoch_polynomial().coeffs().to_vec();
+    coefficients[0] += Fr::one();
+    assert_ne!(commit(&DensePolynomial::from_coefficients_vec(coefficients)), commitment);
+
+    // Ensure the epoch challenge of another epoch produces a different commitment.
+    let other_challenge = EpochChallenge::<Testnet3>::new(rng.next_u32(), Default::default(), config.degree).unwrap();
+    assert_ne!(other_challenge.polynomial_commitment(&srs).unwrap(), commitment);
+}
+
+#[test]
+fn test_trim_truncated_powers() {
+    let config = PuzzleConfig { degree: (1 << 5) - 1 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(config).unwrap();
+
+    // Prepare setup powers for a smaller degree, as from a truncated SRS.
+    let truncated = SetupPowers::<Testnet3>::from_srs(&srs, PuzzleConfig { degree: (1 << 4) - 1 }).unwrap();
+    let num_powers = truncated.powers_of_beta_g().len();
+
+    // Ensure trimming the truncated powers returns an error, rather than producing a bad proving key.
+    let error = CoinbasePuzzle::<Testnet3>::trim_powers(&truncated, config).err().unwrap();
+    let product_domain = CoinbasePuzzle::<Testnet3>::product_domain(config.degree).unwrap();
+    assert_eq!(
+        format!(
+            "Expected {} setup powers of beta G for the product polynomial, found {num_powers}",
+            product_domain.size()
+        ),
+        error.to_string()
+    );
+}
+
+#[test]
+fn test_puzzle_id() {
+    let srs = sample_srs();
+
+    // Trim two puzzles from the same SRS to the same degree.
+    let degree = (1 << 13) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let other = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+
+    // Ensure the puzzles have equal verifying keys, and share an ID.
+    assert_eq!(puzzle.coinbase_verifying_key(), other.coinbase_verifying_key());
+    assert_eq!(puzzle.id().unwrap(), other.id().unwrap());
+
+    // Ensure a verifier with the same verifying key shares the ID.
+    let verifier = CoinbasePuzzle::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    assert_eq!(puzzle.id().unwrap(), verifier.id().unwrap());
+
+    // Ensure the verifying keys may be used as map keys.
+    let mut verifying_keys = std::collections::HashSet::new();
+    assert!(verifying_keys.insert(puzzle.coinbase_verifying_key().clone()));
+    assert!(!verifying_keys.insert(other.coinbase_verifying_key().clone()));
+
+    // Ensure a different verifying key has a different ID.
+    let mut verifying_key = puzzle.coinbase_verifying_key().clone();
+    verifying_key.g = verifying_key.gamma_g;
+    let modified = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(verifying_key.clone()));
+    assert_ne!(puzzle.coinbase_verifying_key(), &verifying_key);
+    assert_ne!(puzzle.id().unwrap(), modified.id().unwrap());
+    assert!(verifying_keys.insert(verifying_key));
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::program::finalize::{Command, Decrement, FinalizeCommand, Increment};
+
+use std::collections::HashSet;
+
+impl<N: Network> Process<N> {
+    /// Returns a copy of the process that only permits programs using the given opcodes.
+    /// Programs containing any other opcode are rejected when they are added or deployed.
+    #[inline]
+    pub fn with_allowed_opcodes(&self, allowed: &HashSet<&str>) -> Self {
+        let mut process = self.clone();
+        process.allowed_opcodes = Some(allowed.iter().map(|opcode| opcode.to_string()).collect());
+        process
+    }
+
+    /// Returns the opcodes permitted by the process, if the process is restricted.
+    #[inline]
+    pub const fn allowed_opcodes(&self) -> Option<&IndexSet<String>> {
+        self.allowed_opcodes.as_ref()
+    }
+
+    /// Ensures the given program only uses opcodes that are permitted by the process.
+    pub(crate) fn ensure_opcodes_are_allowed(&self, program: &Program<N>) -> Result<()> {
+        // If the process is unrestricted, return early.
+        let allowed = match &self.allowed_opcodes {
+            Some(allowed) => allowed,
+            None => return Ok(()),
+        };
+
+        // Collect the opcodes of the closures.
+        let closure_opcodes =
+            program.closures().values().flat_map(|closure| closure.instructions().iter().map(|i| i.opcode()));
+        // Collect the opcodes of the functions, including the finalize scopes.
+        let function_opcodes = program.functions().values().flat_map(|function| {
+            let instructions = function.instructions().iter().map(|instruction| instruction.opcode());
+            let finalize = function.finalize().into_iter().flat_map(|(_, finalize)| {
+                let commands = finalize.commands().iter().map(|command| match command {
+                    Command::Decrement(..) => Decrement::<N>::opcode(),
+                    Command::Instruction(instruction) => instruction.opcode(),
+                    Command::Increment(..) => Increment::<N>::opcode(),
+                });
+                std::iter::once(FinalizeCommand::<N>::opcode()).chain(commands)
+            });
+            instructions.chain(finalize)
+        });
+
+        // Ensure each opcode is permitted.
+        for opcode in closure_opcodes.chain(function_opcodes) {
+            ensure!(allowed.contains(*opcode), "opcode '{opcode}' not permitted in this context");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_allowed_opcodes() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as u64.private;",
+        )?;
+
+        let process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+
+        // Ensure an unrestricted process accepts the program.
+        assert!(process.allowed_opcodes().is_none());
+        process.clone().add_program(&program)?;
+
+        // Ensure a process permitting every opcode in the program accepts it.
+        let mut restricted = process.with_allowed_opcodes(&HashSet::from(["add", "mul"]));
+        restricted.add_program(&program)?;
+
+        // Ensure a process missing an opcode in the program rejects it.
+        let mut restricted = process.with_allowed_opcodes(&HashSet::from(["add", "hash.bhp256"]));
+        let error = restricted.add_program(&program).unwrap_err();
+        assert_eq!("opcode 'mul' not permitted in this context", error.to_string());
+        assert!(!restricted.contains_program(program.id()));
+        Ok(())
+    }
+}
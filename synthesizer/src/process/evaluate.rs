@@ -16,6 +16,9 @@
 
 use super::*;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Evaluates a program function on the given request.
     #[inline]
@@ -37,4 +40,127 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Evaluates a program function on the given request, recording each evaluated instruction in the given trace.
+    ///
+    /// If an instruction halts, the trace ends with that instruction, and the halt reason is recorded.
+    #[inline]
+    pub fn evaluate_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        trace: &mut ExecutionTrace<N>,
+    ) -> Result<Response<N>> {
+        // Retrieve the main request (without popping it).
+        let request = authorization.peek_next()?;
+        // Evaluate the function.
+        let stack = self.get_stack(request.program_id())?;
+        stack.evaluate_function_with_trace::<A>(CallStack::evaluate(authorization)?, trace)
+    }
+
+    /// Evaluates a program function once for each of the given input sets, returning the outputs of each.
+    ///
+    /// The calls are authorized in order, and then evaluated in parallel, sharing the stack of the program.
+    /// A failure, such as a halt, is returned in the position of its input set, and does not affect the others.
+    #[inline]
+    pub fn evaluate_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        input_sets: &[Vec<Value<N>>],
+        rng: &mut R,
+    ) -> Result<Vec<Result<Vec<Value<N>>>>> {
+        let timer = timer!("Process::evaluate_batch");
+
+        // Retrieve the stack, and prepare the function name.
+        let stack = self.get_stack(program_id)?;
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+
+        // Authorize the call for each input set.
+        let authorizations = input_sets
+            .iter()
+            .map(|inputs| stack.authorize::<A, R>(private_key, function_name, inputs.iter(), rng))
+            .collect::<Vec<_>>();
+        lap!(timer, "Authorize the calls");
+
+        // Evaluate the function for each input set.
+        let outputs = cfg_into_iter!(authorizations)
+            .map(|authorization| -> Result<Vec<Value<N>>> {
+                let response = stack.evaluate_function::<A>(CallStack::evaluate(authorization?)?)?;
+                Ok(response.outputs().to_vec())
+            })
+            .collect();
+        lap!(timer, "Evaluate the calls");
+
+        finish!(timer);
+
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_evaluate_batch() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.public;
+    add r0 r1 into r2;
+    mul r0 r1 into r3;
+    output r2 as u8.private;
+    output r3 as u8.public;",
+        )?;
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+        process.add_program(&program)?;
+
+        // Prepare the input sets, where the second set halts on overflow.
+        let input_sets = [["3u8", "5u8"], ["200u8", "100u8"], ["0u8", "255u8"], ["15u8", "17u8"]]
+            .iter()
+            .map(|inputs| inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()?;
+
+        // Evaluate the input sets as a batch.
+        let (program_id, function_name) = (program.id(), Identifier::from_str("compute")?);
+        let candidates =
+            process.evaluate_batch::<CurrentAleo, _>(&caller_private_key, program_id, function_name, &input_sets, rng)?;
+        assert_eq!(input_sets.len(), candidates.len());
+
+        // Ensure the halting input set fails, without affecting the others.
+        let is_ok = candidates.iter().map(|candidate| candidate.is_ok()).collect::<Vec<_>>();
+        assert_eq!(vec![true, false, true, true], is_ok);
+
+        // Ensure each result matches the result of evaluating the input set individually.
+        for (inputs, candidate) in input_sets.iter().zip_eq(candidates) {
+            let authorization = process.authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                rng,
+            )?;
+            match process.evaluate::<CurrentAleo>(authorization) {
+                Ok(response) => assert_eq!(response.outputs(), candidate?.as_slice()),
+                Err(_) => assert!(candidate.is_err()),
+            }
+        }
+        Ok(())
+    }
 }
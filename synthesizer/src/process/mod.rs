@@ -17,12 +17,19 @@
 mod stack;
 pub use stack::*;
 
+mod allowed_opcodes;
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod execute_fee;
 
+mod overflow_policy;
+pub use overflow_policy::*;
+
+mod replay_cache;
+pub use replay_cache::*;
+
 use crate::{
     block::{Input, Transition},
     program::{Instruction, Operand, Program},
@@ -37,7 +44,7 @@ use console::{
 };
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -53,6 +60,14 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The opcodes permitted in programs, if the process is restricted.
+    allowed_opcodes: Option<IndexSet<String>>,
+    /// The cache of `add` and `mul` outputs, if replaying is enabled.
+    replay_cache: Option<ReplayCache<N>>,
+    /// The proof target provided by the host, if any, read by `load.proof_target`.
+    proof_target: Option<u64>,
+    /// The behavior of `add`, `sub`, and `mul` on overflow, during evaluation.
+    overflow_policy: OverflowPolicy,
 }
 
 impl<N: Network> Process<N> {
@@ -62,7 +77,14 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            allowed_opcodes: None,
+            replay_cache: None,
+            proof_target: None,
+            overflow_policy: OverflowPolicy::Halt,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -108,7 +130,14 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            allowed_opcodes: None,
+            replay_cache: None,
+            proof_target: None,
+            overflow_policy: OverflowPolicy::Halt,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -155,7 +184,14 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn load_with_cache(cache: &mut HashMap<String, (ProvingKey<N>, VerifyingKey<N>)>) -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            allowed_opcodes: None,
+            replay_cache: None,
+            proof_target: None,
+            overflow_policy: OverflowPolicy::Halt,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -190,6 +226,21 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns a copy of the process, with the given proof target provided to `load.proof_target`.
+    #[inline]
+    pub fn with_proof_target(&self, proof_target: u64) -> Self {
+        let mut process = self.clone();
+        process.stacks.values_mut().for_each(|stack| stack.set_proof_target(Some(proof_target)));
+        process.proof_target = Some(proof_target);
+        process
+    }
+
+    /// Returns the proof target provided to the process, if any.
+    #[inline]
+    pub const fn proof_target(&self) -> Option<u64> {
+        self.proof_target
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -884,6 +935,91 @@ function hello_world:
         );
     }
 
+    #[test]
+    fn test_process_load_proof_target() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program target.aleo;
+
+  function meets_target:
+    input r0 as u64.private;
+    load.proof_target into r1;
+    gte r0 r1 into r2;
+    output r1 as u64.private;
+    output r2 as boolean.private;
+
+  function is_target:
+    input r0 as u64.public;
+    load.proof_target into r1;
+    assert.eq r0 r1;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        assert_eq!(process.proof_target(), None);
+
+        // Ensure authorization fails if the proof target is not provided.
+        let function_name = Identifier::from_str("meets_target").unwrap();
+        let inputs = [Value::<CurrentNetwork>::from_str("100u64").unwrap()];
+        assert!(
+            process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .is_err()
+        );
+
+        for (proof_target, is_met) in [(50, true), (100, true), (101, false)] {
+            // Provide the proof target to the process.
+            let process = process.with_proof_target(proof_target);
+            assert_eq!(process.proof_target(), Some(proof_target));
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap();
+
+            let expected = [
+                Value::from_str(&format!("{proof_target}u64")).unwrap(),
+                Value::from_str(&format!("{is_met}")).unwrap(),
+            ];
+
+            // Ensure the loaded proof target matches the provided one when evaluating.
+            let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+            assert_eq!(response.outputs(), expected);
+
+            // Ensure the loaded proof target matches the provided one when executing.
+            let (response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization.replicate(), rng).unwrap();
+            assert_eq!(response.outputs(), expected);
+            process.verify_execution::<true>(&execution).unwrap();
+        }
+
+        // Ensure the circuit is only satisfied by the provided proof target.
+        let function_name = Identifier::from_str("is_target").unwrap();
+        let process = process.with_proof_target(100);
+        let authorize = |input: &str, rng: &mut TestRng| {
+            let inputs = [Value::<CurrentNetwork>::from_str(input).unwrap()];
+            process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+                .unwrap()
+        };
+        let (_response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorize("100u64", rng), rng).unwrap();
+        process.verify_execution::<true>(&execution).unwrap();
+        assert!(process.execute::<CurrentAleo, _>(authorize("101u64", rng), rng).is_err());
+
+        // Ensure an execution for one proof target does not verify under the keys for another proof target.
+        let other_process = process.with_proof_target(101);
+        other_process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        assert!(other_process.verify_execution::<true>(&execution).is_err());
+    }
+
     #[test]
     fn test_process_execute_call_closure() {
         // Initialize a new program.
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Opcode;
+use console::{
+    program::Literal,
+    types::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+};
+
+/// Applies the given primitive method to a pair of integer literals of the same type.
+macro_rules! integer_binary {
+    ($inputs:expr, $method:ident) => {
+        match $inputs {
+            [Literal::I8(a), Literal::I8(b)] => Some(Literal::I8(I8::new((**a).$method(**b)))),
+            [Literal::I16(a), Literal::I16(b)] => Some(Literal::I16(I16::new((**a).$method(**b)))),
+            [Literal::I32(a), Literal::I32(b)] => Some(Literal::I32(I32::new((**a).$method(**b)))),
+            [Literal::I64(a), Literal::I64(b)] => Some(Literal::I64(I64::new((**a).$method(**b)))),
+            [Literal::I128(a), Literal::I128(b)] => Some(Literal::I128(I128::new((**a).$method(**b)))),
+            [Literal::U8(a), Literal::U8(b)] => Some(Literal::U8(U8::new((**a).$method(**b)))),
+            [Literal::U16(a), Literal::U16(b)] => Some(Literal::U16(U16::new((**a).$method(**b)))),
+            [Literal::U32(a), Literal::U32(b)] => Some(Literal::U32(U32::new((**a).$method(**b)))),
+            [Literal::U64(a), Literal::U64(b)] => Some(Literal::U64(U64::new((**a).$method(**b)))),
+            [Literal::U128(a), Literal::U128(b)] => Some(Literal::U128(U128::new((**a).$method(**b)))),
+            _ => None,
+        }
+    };
+}
+
+/// The behavior of the checked integer instructions `add`, `sub`, and `mul` on overflow, during evaluation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Halts on overflow, as the instructions are specified.
+    Halt,
+    /// Wraps around on overflow, as in `add.w`, `sub.w`, and `mul.w`.
+    Wrap,
+    /// Clamps the output to the minimum or maximum value of the integer type on overflow.
+    Saturate,
+}
+
+impl OverflowPolicy {
+    /// Returns the output of the given opcode on the given operands under the policy.
+    /// Opcodes other than `add`, `sub`, and `mul`, and non-integer operands, are always computed with `evaluate`.
+    pub(crate) fn evaluate<N: Network>(
+        &self,
+        opcode: Opcode,
+        inputs: &[Literal<N>],
+        evaluate: impl FnOnce() -> Result<Literal<N>>,
+    ) -> Result<Literal<N>> {
+        let output = match (self, *opcode) {
+            (Self::Wrap, "add") => integer_binary!(inputs, wrapping_add),
+            (Self::Wrap, "sub") => integer_binary!(inputs, wrapping_sub),
+            (Self::Wrap, "mul") => integer_binary!(inputs, wrapping_mul),
+            (Self::Saturate, "add") => integer_binary!(inputs, saturating_add),
+            (Self::Saturate, "sub") => integer_binary!(inputs, saturating_sub),
+            (Self::Saturate, "mul") => integer_binary!(inputs, saturating_mul),
+            _ => None,
+        };
+        match output {
+            Some(output) => Ok(output),
+            None => evaluate(),
+        }
+    }
+}
+
+impl Default for OverflowPolicy {
+    /// Returns the `Halt` policy, which matches the specified behavior of the instructions.
+    fn default() -> Self {
+        Self::Halt
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a copy of the process that applies the given overflow policy to `add`, `sub`, and `mul`.
+    /// Note: The policy only applies to `evaluate`. As the circuit enforces the specified behavior,
+    /// an `execute` that overflows under the `Wrap` or `Saturate` policy fails.
+    #[inline]
+    pub fn with_overflow_policy(&self, overflow_policy: OverflowPolicy) -> Self {
+        let mut process = self.clone();
+        process.stacks.values_mut().for_each(|stack| stack.set_overflow_policy(overflow_policy));
+        process.overflow_policy = overflow_policy;
+        process
+    }
+
+    /// Returns the overflow policy of the process.
+    #[inline]
+    pub const fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_overflow_policy() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    sub r1 r0 into r3;
+    mul r0 r1 into r4;
+    output r2 as u8.private;
+    output r3 as u8.private;
+    output r4 as u8.private;",
+        )?;
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+        assert_eq!(process.overflow_policy(), OverflowPolicy::Halt);
+        process.add_program(&program)?;
+
+        // Evaluates the function on the given inputs, under the given policy.
+        let evaluate = |overflow_policy, inputs: [&str; 2]| -> Result<Vec<Value<CurrentNetwork>>> {
+            let process = process.with_overflow_policy(overflow_policy);
+            assert_eq!(process.overflow_policy(), overflow_policy);
+            let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>()?;
+            let authorization = process.authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("compute")?,
+                inputs.iter(),
+                &mut TestRng::default(),
+            )?;
+            Ok(process.evaluate::<CurrentAleo>(authorization)?.outputs().to_vec())
+        };
+        let values = |outputs: [&str; 3]| outputs.map(|output| Value::from_str(output).unwrap()).to_vec();
+
+        // Ensure the policies agree when there is no overflow.
+        for overflow_policy in [OverflowPolicy::Halt, OverflowPolicy::Wrap, OverflowPolicy::Saturate] {
+            assert_eq!(evaluate(overflow_policy, ["3u8", "5u8"])?, values(["8u8", "2u8", "15u8"]));
+        }
+
+        // Ensure the `Halt` policy halts on overflow.
+        assert!(evaluate(OverflowPolicy::Halt, ["200u8", "100u8"]).is_err());
+        // Ensure the `Wrap` policy wraps around on overflow.
+        assert_eq!(evaluate(OverflowPolicy::Wrap, ["200u8", "100u8"])?, values(["44u8", "156u8", "32u8"]));
+        // Ensure the `Saturate` policy clamps on overflow.
+        assert_eq!(evaluate(OverflowPolicy::Saturate, ["200u8", "100u8"])?, values(["255u8", "0u8", "255u8"]));
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Opcode;
+use console::program::Literal;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The opcodes whose outputs may be replayed from the cache.
+const REPLAYABLE_OPCODES: [&str; 2] = ["add", "mul"];
+
+/// A cache of evaluated `add` and `mul` outputs, keyed by the opcode and the operand values.
+/// The cache is shared by every stack in the process, and trades memory for fewer recomputations.
+///
+/// The cache holds at most `capacity` outputs, and evicts the oldest output when full.
+#[derive(Clone)]
+pub struct ReplayCache<N: Network> {
+    /// The maximum number of cached outputs.
+    capacity: usize,
+    /// The mapping of `(opcode, operand bytes)` to the output literal, in insertion order.
+    outputs: Arc<RwLock<IndexMap<(Opcode, Vec<u8>), Literal<N>>>>,
+    /// The number of outputs replayed from the cache.
+    num_hits: Arc<AtomicUsize>,
+}
+
+impl<N: Network> ReplayCache<N> {
+    /// The default maximum number of cached outputs.
+    pub const DEFAULT_CAPACITY: usize = 1 << 10;
+
+    /// Initializes a new, empty replay cache, with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Initializes a new, empty replay cache, holding at most `capacity` outputs.
+    ///
+    /// # Panics
+    /// This method panics if the capacity is zero.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "The capacity of the replay cache must be nonzero");
+        Self { capacity, outputs: Default::default(), num_hits: Default::default() }
+    }
+
+    /// Returns the maximum number of cached outputs.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached outputs.
+    pub fn len(&self) -> usize {
+        self.outputs.read().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.outputs.read().is_empty()
+    }
+
+    /// Returns the number of outputs replayed from the cache.
+    pub fn num_hits(&self) -> usize {
+        self.num_hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the cached output for the given opcode and operands, or computes it with `evaluate` and caches it.
+    /// Opcodes other than `add` and `mul` are always computed with `evaluate`.
+    pub(crate) fn evaluate(
+        &self,
+        opcode: Opcode,
+        inputs: &[Literal<N>],
+        evaluate: impl FnOnce() -> Result<Literal<N>>,
+    ) -> Result<Literal<N>> {
+        // If the opcode is not replayable, compute the output.
+        if !REPLAYABLE_OPCODES.contains(&*opcode) {
+            return evaluate();
+        }
+
+        // Construct the key from the operand values.
+        let mut operands = Vec::new();
+        for input in inputs {
+            input.write_le(&mut operands)?;
+        }
+        let key = (opcode, operands);
+
+        // If the output is cached, replay it.
+        if let Some(output) = self.outputs.read().get(&key) {
+            self.num_hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(output.clone());
+        }
+
+        // Otherwise, compute the output.
+        let output = evaluate()?;
+
+        // Cache the output, evicting the oldest outputs if the cache is full.
+        let mut outputs = self.outputs.write();
+        while outputs.len() >= self.capacity && !outputs.contains_key(&key) {
+            outputs.shift_remove_index(0);
+        }
+        outputs.insert(key, output.clone());
+        Ok(output)
+    }
+}
+
+impl<N: Network> Default for ReplayCache<N> {
+    /// Initializes a new, empty replay cache.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a copy of the process that replays the outputs of identical `add` and `mul` evaluations from a cache,
+    /// holding at most `ReplayCache::DEFAULT_CAPACITY` outputs.
+    /// Note: The cache only applies to `evaluate`, as `execute` must synthesize every instruction into the circuit.
+    #[inline]
+    pub fn with_replay_cache(&self) -> Self {
+        self.with_replay_cache_capacity(ReplayCache::<N>::DEFAULT_CAPACITY)
+    }
+
+    /// Returns a copy of the process that replays the outputs of identical `add` and `mul` evaluations from a cache,
+    /// holding at most `capacity` outputs.
+    ///
+    /// # Panics
+    /// This method panics if the capacity is zero.
+    #[inline]
+    pub fn with_replay_cache_capacity(&self, capacity: usize) -> Self {
+        let mut process = self.clone();
+        let replay_cache = ReplayCache::with_capacity(capacity);
+        process.stacks.values_mut().for_each(|stack| stack.set_replay_cache(Some(replay_cache.clone())));
+        process.replay_cache = Some(replay_cache);
+        process
+    }
+
+    /// Returns the replay cache of the process, if it is enabled.
+    #[inline]
+    pub const fn replay_cache(&self) -> Option<&ReplayCache<N>> {
+        self.replay_cache.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::network::AleoV0;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_replay_cache() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    add r0 r1 into r3;
+    mul r2 r3 into r4;
+    mul r3 r2 into r5;
+    add r4 r5 into r6;
+    output r6 as u64.private;",
+        )?;
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Construct the process, and enable the replay cache.
+        let mut process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+        assert!(process.replay_cache().is_none());
+        process.add_program(&program)?;
+        let process = process.with_replay_cache();
+
+        // Evaluate the function twice.
+        let inputs = [Value::<CurrentNetwork>::from_str("3u64")?, Value::from_str("4u64")?];
+        for expected_hits in [2, 7] {
+            let authorization = process.authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("compute")?,
+                inputs.iter(),
+                rng,
+            )?;
+            let response = process.evaluate::<CurrentAleo>(authorization)?;
+            // Ensure the output is (3 + 4) * (3 + 4) * 2.
+            assert_eq!(response.outputs(), [Value::from_str("98u64")?]);
+
+            // Ensure the cache only holds the distinct computations, and was consulted for the repeated ones.
+            let replay_cache = process.replay_cache().unwrap();
+            assert_eq!(replay_cache.len(), 3);
+            assert_eq!(replay_cache.num_hits(), expected_hits);
+        }
+        Ok(())
+    }
+    #[test]
+    fn test_replay_cache_eviction() -> Result<()> {
+        let replay_cache = ReplayCache::<CurrentNetwork>::with_capacity(2);
+        let opcode = Opcode::Literal("add");
+        let output = |value: u64| Literal::<CurrentNetwork>::from_str(&format!("{value}u64"));
+        let inputs = |value: u64| [output(value).unwrap(), output(1).unwrap()];
+
+        // Fill the cache beyond its capacity.
+        for value in 0..3 {
+            replay_cache.evaluate(opcode, &inputs(value), || output(value + 1))?;
+        }
+        // Ensure the cache holds at most its capacity.
+        assert_eq!(replay_cache.capacity(), 2);
+        assert_eq!(replay_cache.len(), 2);
+
+        // Ensure the newest outputs are replayed.
+        for value in 1..3 {
+            let replayed = replay_cache.evaluate(opcode, &inputs(value), || bail!("The output must be replayed"))?;
+            assert_eq!(replayed, output(value + 1)?);
+        }
+        assert_eq!(replay_cache.num_hits(), 2);
+
+        // Ensure the oldest output was evicted, and is recomputed.
+        assert_eq!(replay_cache.evaluate(opcode, &inputs(0), || output(1))?, output(1)?);
+        assert_eq!(replay_cache.num_hits(), 2);
+        assert_eq!(replay_cache.len(), 2);
+        Ok(())
+    }
+}
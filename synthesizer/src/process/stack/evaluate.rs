@@ -29,6 +29,64 @@ impl<N: Network> Stack<N> {
         call_stack: CallStack<N>,
         caller: Address<N>,
         tvk: Field<N>,
+    ) -> Result<Vec<Value<N>>> {
+        self.evaluate_closure_internal::<A>(closure, inputs, call_stack, caller, tvk, None)
+    }
+
+    /// Evaluates a program closure on the given inputs, recording each evaluated instruction in the given trace.
+    ///
+    /// If an instruction halts, the trace ends with that instruction, and the halt reason is recorded.
+    /// Note: The instructions of a called closure are not recorded.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn evaluate_closure_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: &[Value<N>],
+        call_stack: CallStack<N>,
+        caller: Address<N>,
+        tvk: Field<N>,
+        trace: &mut ExecutionTrace<N>,
+    ) -> Result<Vec<Value<N>>> {
+        self.evaluate_closure_internal::<A>(closure, inputs, call_stack, caller, tvk, Some(trace))
+    }
+
+    /// Evaluates a program function on the given inputs.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn evaluate_function<A: circuit::Aleo<Network = N>>(&self, call_stack: CallStack<N>) -> Result<Response<N>> {
+        self.evaluate_function_internal::<A>(call_stack, None)
+    }
+
+    /// Evaluates a program function on the given inputs, recording each evaluated instruction in the given trace.
+    ///
+    /// If an instruction halts, the trace ends with that instruction, and the halt reason is recorded.
+    /// Note: The instructions of a called closure or function are not recorded.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn evaluate_function_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        trace: &mut ExecutionTrace<N>,
+    ) -> Result<Response<N>> {
+        self.evaluate_function_internal::<A>(call_stack, Some(trace))
+    }
+
+    /// Evaluates a program closure on the given inputs, recording the instructions if a trace is given.
+    fn evaluate_closure_internal<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: &[Value<N>],
+        call_stack: CallStack<N>,
+        caller: Address<N>,
+        tvk: Field<N>,
+        mut trace: Option<&mut ExecutionTrace<N>>,
     ) -> Result<Vec<Value<N>>> {
         let timer = timer!("Stack::evaluate_closure");
 
@@ -54,8 +112,13 @@ impl<N: Network> Stack<N> {
 
         // Evaluate the instructions.
         for instruction in closure.instructions() {
+            let result = instruction.evaluate(self, &mut registers);
+            // If tracing is enabled, record the instruction.
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.record(self, &registers, instruction, &result);
+            }
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
+            if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
         }
@@ -86,12 +149,12 @@ impl<N: Network> Stack<N> {
         outputs
     }
 
-    /// Evaluates a program function on the given inputs.
-    ///
-    /// # Errors
-    /// This method will halt if the given inputs are not the same length as the input statements.
-    #[inline]
-    pub fn evaluate_function<A: circuit::Aleo<Network = N>>(&self, call_stack: CallStack<N>) -> Result<Response<N>> {
+    /// Evaluates a program function on the given inputs, recording the instructions if a trace is given.
+    fn evaluate_function_internal<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        trace: Option<&mut ExecutionTrace<N>>,
+    ) -> Result<Response<N>> {
         let timer = timer!("Stack::evaluate_function");
 
         // Retrieve the next request, based on the call stack mode.
@@ -148,11 +211,19 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        for instruction in function.instructions() {
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+        match trace {
+            // If tracing is enabled, record each instruction.
+            Some(trace) => {
+                for instruction in function.instructions() {
+                    let result = instruction.evaluate(self, &mut registers);
+                    trace.record(self, &registers, instruction, &result);
+                    // If the evaluation fails, bail and return the error.
+                    if let Err(error) = result {
+                        bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    }
+                }
             }
+            None => function.evaluate(self, &mut registers)?,
         }
         lap!(timer, "Evaluate the instructions");
 
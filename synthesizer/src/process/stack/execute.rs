@@ -29,6 +29,40 @@ impl<N: Network> Stack<N> {
         call_stack: CallStack<N>,
         caller: circuit::Address<A>,
         tvk: circuit::Field<A>,
+    ) -> Result<Vec<circuit::Value<A>>> {
+        self.execute_closure_internal(closure, inputs, call_stack, caller, tvk, None)
+    }
+
+    /// Executes a program closure on the given inputs, recording each executed instruction in the given trace,
+    /// along with the circuit modes of its destination registers.
+    ///
+    /// If an instruction halts, the trace ends with that instruction, and the halt reason is recorded.
+    /// Note: The instructions of a called closure are not recorded.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    pub fn execute_closure_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: &[circuit::Value<A>],
+        call_stack: CallStack<N>,
+        caller: circuit::Address<A>,
+        tvk: circuit::Field<A>,
+        trace: &mut ExecutionTrace<N>,
+    ) -> Result<Vec<circuit::Value<A>>> {
+        self.execute_closure_internal(closure, inputs, call_stack, caller, tvk, Some(trace))
+    }
+
+    /// Executes a program closure on the given inputs, recording the instructions if a trace is given.
+    fn execute_closure_internal<A: circuit::Aleo<Network = N>>(
+        &self,
+        closure: &Closure<N>,
+        inputs: &[circuit::Value<A>],
+        call_stack: CallStack<N>,
+        caller: circuit::Address<A>,
+        tvk: circuit::Field<A>,
+        mut trace: Option<&mut ExecutionTrace<N>>,
     ) -> Result<Vec<circuit::Value<A>>> {
         let timer = timer!("Stack::execute_closure");
 
@@ -67,15 +101,20 @@ impl<N: Network> Stack<N> {
 
         // Execute the instructions.
         for instruction in closure.instructions() {
-            // If the circuit is in execute mode, then evaluate the instructions.
-            if let CallStack::Execute(..) = registers.call_stack() {
-                // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
-                }
+            let result = match registers.call_stack() {
+                // If the circuit is in execute mode, then evaluate the instruction before executing it.
+                CallStack::Execute(..) => instruction
+                    .evaluate(self, &mut registers)
+                    .map_err(|error| anyhow!("Failed to evaluate instruction ({instruction}): {error}")),
+                _ => Ok(()),
             }
-            // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            .and_then(|()| instruction.execute(self, &mut registers));
+            // If tracing is enabled, record the instruction.
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.record(self, &registers, instruction, &result);
+            }
+            // If the evaluation or execution fails, return the error.
+            result?;
         }
         lap!(timer, "Execute the instructions");
 
@@ -25,11 +25,7 @@ impl<N: Network> FinalizeRegisters<N> {
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
     pub fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
-            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
-            Value::Record(..) => bail!("Operand must be a literal"),
-        }
+        self.load(stack, operand)?.into_literal("Operand")
     }
 
     /// Loads the plaintext of a given operand from the registers.
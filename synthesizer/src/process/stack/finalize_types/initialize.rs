@@ -379,6 +379,20 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Bytes(opcode) => {
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "add.bytes" => ensure!(
+                        matches!(instruction, Instruction::AddBytes(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "xor.bytes" => ensure!(
+                        matches!(instruction, Instruction::XorBytes(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
@@ -487,6 +501,8 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.ped64.raw",
+                    "hash.ped128.raw",
                 ]
                 .contains(&opcode)
                 {
@@ -530,6 +546,14 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.ped64.raw" => ensure!(
+                        matches!(instruction, Instruction::HashPED64Raw(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.ped128.raw" => ensure!(
+                        matches!(instruction, Instruction::HashPED128Raw(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -551,6 +575,33 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Load(opcode) => {
+                bail!("Instruction '{opcode}' is not allowed in 'finalize'");
+            }
+            Opcode::Sign(opcode) => {
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
+            Opcode::Pack => {
+                // Ensure the instruction is the pack operation.
+                ensure!(
+                    matches!(instruction, Instruction::Pack(..)),
+                    "Instruction '{instruction}' is not a pack operation."
+                );
+            }
+            Opcode::Unpack => {
+                // Ensure the instruction is the unpack operation.
+                ensure!(
+                    matches!(instruction, Instruction::Unpack(..)),
+                    "Instruction '{instruction}' is not an unpack operation."
+                );
+            }
         }
         Ok(())
     }
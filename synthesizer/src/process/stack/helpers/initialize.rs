@@ -29,6 +29,9 @@ impl<N: Network> Stack<N> {
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            replay_cache: process.replay_cache().cloned(),
+            proof_target: process.proof_target(),
+            overflow_policy: process.overflow_policy(),
         };
 
         // Add all of the imports into the stack.
@@ -55,6 +58,24 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Ok(stack)
     }
+
+    /// Initializes a stack for the given program outside of a process, without type-checking it.
+    /// Note: As the stack has no imports, it cannot resolve calls or records of imported programs.
+    #[inline]
+    pub(crate) fn for_program(program: &Program<N>) -> Result<Self> {
+        Ok(Self {
+            program: program.clone(),
+            external_stacks: Default::default(),
+            register_types: Default::default(),
+            finalize_types: Default::default(),
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            proving_keys: Default::default(),
+            verifying_keys: Default::default(),
+            replay_cache: None,
+            proof_target: None,
+            overflow_policy: OverflowPolicy::Halt,
+        })
+    }
 }
 
 impl<N: Network> Stack<N> {
@@ -54,9 +54,11 @@ use crate::{
     Function,
     Instruction,
     Operand,
+    OverflowPolicy,
     Process,
     Program,
     ProvingKey,
+    ReplayCache,
     Transition,
     UniversalSRS,
     VerifyingKey,
@@ -209,6 +211,12 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The cache of `add` and `mul` outputs, if replaying is enabled.
+    replay_cache: Option<ReplayCache<N>>,
+    /// The proof target provided by the host, if any.
+    proof_target: Option<u64>,
+    /// The behavior of `add`, `sub`, and `mul` on overflow, during evaluation.
+    overflow_policy: OverflowPolicy,
 }
 
 impl<N: Network> Stack<N> {
@@ -223,6 +231,8 @@ impl<N: Network> Stack<N> {
         ensure!(program_id.is_aleo(), "Program '{program_id}' has an incorrect network-level domain (NLD)");
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
+        // Ensure the program only uses opcodes permitted by the process.
+        process.ensure_opcodes_are_allowed(program)?;
 
         // Serialize the program into bytes.
         let program_bytes = program.to_bytes_le()?;
@@ -250,6 +260,53 @@ impl<N: Network> Stack<N> {
         self.program.id()
     }
 
+    /// Returns the replay cache of the stack, if it is enabled.
+    #[inline]
+    pub const fn replay_cache(&self) -> Option<&ReplayCache<N>> {
+        self.replay_cache.as_ref()
+    }
+
+    /// Sets the replay cache of the stack and its external stacks.
+    #[inline]
+    pub(crate) fn set_replay_cache(&mut self, replay_cache: Option<ReplayCache<N>>) {
+        self.external_stacks.values_mut().for_each(|stack| stack.set_replay_cache(replay_cache.clone()));
+        self.replay_cache = replay_cache;
+    }
+
+    /// Returns the proof target provided to the stack, if any.
+    #[inline]
+    pub const fn proof_target(&self) -> Option<u64> {
+        self.proof_target
+    }
+
+    /// Sets the proof target of the stack and its external stacks.
+    ///
+    /// As `load.proof_target` injects the proof target as a circuit constant, the circuit keys depend on it.
+    /// If the proof target changes, the stack starts from fresh key mappings, rather than sharing the keys
+    /// synthesized for the previous proof target.
+    #[inline]
+    pub(crate) fn set_proof_target(&mut self, proof_target: Option<u64>) {
+        self.external_stacks.values_mut().for_each(|stack| stack.set_proof_target(proof_target));
+        if self.proof_target != proof_target {
+            self.proving_keys = Default::default();
+            self.verifying_keys = Default::default();
+        }
+        self.proof_target = proof_target;
+    }
+
+    /// Returns the overflow policy of the stack.
+    #[inline]
+    pub const fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Sets the overflow policy of the stack and its external stacks.
+    #[inline]
+    pub(crate) fn set_overflow_policy(&mut self, overflow_policy: OverflowPolicy) {
+        self.external_stacks.values_mut().for_each(|stack| stack.set_overflow_policy(overflow_policy));
+        self.overflow_policy = overflow_policy;
+    }
+
     /// Returns `true` if the stack contains the external record.
     #[inline]
     pub fn contains_external_record(&self, locator: &Locator<N>) -> bool {
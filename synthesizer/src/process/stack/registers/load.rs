@@ -25,17 +25,22 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
     pub fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
-            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
-            Value::Record(..) => bail!("Operand must be a literal"),
-        }
+        self.load(stack, operand)?.into_literal("Operand")
+    }
+
+    /// Loads the values of the given operands from the registers, in order.
+    ///
+    /// # Errors
+    /// This method will halt on the first operand that fails to load.
+    #[inline]
+    pub fn load_many(&self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<Vec<Value<N>>> {
+        operands.iter().map(|operand| self.load(stack, operand)).collect()
     }
 
     /// Loads the value of a given operand from the registers.
     ///
     /// # Errors
-    /// This method will halt if the register locator is not found.
+    /// This method will halt if the register locator is not defined, or has not been assigned.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
     pub fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
@@ -54,8 +59,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         };
 
         // Retrieve the stack value.
-        let stack_value =
-            self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+        let stack_value = self
+            .console_registers
+            .get(&register.locator())
+            .ok_or_else(|| self.unassigned_register_error(stack, register))?;
 
         // Return the value for the given register or register member.
         let stack_value = match register {
@@ -104,10 +111,19 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
     }
 
+    /// Loads the circuit values of the given operands from the registers, in order.
+    ///
+    /// # Errors
+    /// This method will halt on the first operand that fails to load.
+    #[inline]
+    pub fn load_many_circuit(&self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<Vec<circuit::Value<A>>> {
+        operands.iter().map(|operand| self.load_circuit(stack, operand)).collect()
+    }
+
     /// Loads the value of a given operand from the registers.
     ///
     /// # Errors
-    /// This method will halt if the register locator is not found.
+    /// This method will halt if the register locator is not defined, or has not been assigned.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
     pub fn load_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Value<A>> {
@@ -138,8 +154,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         };
 
         // Retrieve the circuit value.
-        let circuit_value =
-            self.circuit_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+        let circuit_value = self
+            .circuit_registers
+            .get(&register.locator())
+            .ok_or_else(|| self.unassigned_register_error(stack, register))?;
 
         // Return the value for the given register or register member.
         let circuit_value = match register {
@@ -176,3 +194,96 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         Ok(circuit_value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::AleoV0;
+    use console::{network::Testnet3, program::Identifier};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_load_literal_from_struct_fails() -> Result<()> {
+        // Initialize a program whose input is a struct.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+function run:
+    input r0 as point.private;
+    add r0.x r0.y into r1;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` set to a point.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let point = Value::Plaintext(Plaintext::from_str("{ x: 1field, y: 2field }")?);
+        registers.store(&stack, &Register::Locator(0), point)?;
+
+        // Ensure loading the struct as a literal returns an error, rather than halting.
+        let error = registers.load_literal(&stack, &Operand::Register(Register::Locator(0))).unwrap_err();
+        assert_eq!("Operand must be a literal", error.to_string());
+
+        // Ensure loading a member of the struct as a literal succeeds.
+        let x = registers.load_literal(&stack, &Operand::Register(Register::from_str("r0.x")?))?;
+        assert_eq!(Literal::from_str("1field")?, x);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_many() -> Result<()> {
+        // Initialize a program that defines the registers `r0` to `r3`.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    input r2 as u8.private;
+    add r0 r1 into r3;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` to `r2` assigned.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let values = [Value::from_str("1u8")?, Value::from_str("2u8")?, Value::from_str("3u8")?];
+        for (locator, value) in values.iter().enumerate() {
+            registers.store(&stack, &Register::Locator(locator as u64), value.clone())?;
+        }
+
+        let operands = |registers: &[&str]| -> Result<Vec<_>> {
+            registers.iter().map(|register| Ok(Operand::Register(Register::from_str(register)?))).collect()
+        };
+
+        // Ensure the values are loaded in the order of the operands.
+        let candidate = registers.load_many(&stack, &operands(&["r2", "r0", "r1"])?)?;
+        assert_eq!(vec![values[2].clone(), values[0].clone(), values[1].clone()], candidate);
+
+        // Ensure loading an undefined register halts, naming the register.
+        let error = registers.load_many(&stack, &operands(&["r0", "r1", "r7", "r2"])?).unwrap_err();
+        assert_eq!("Register 'r7' is not defined in the function", error.to_string());
+        Ok(())
+    }
+}
@@ -17,7 +17,13 @@
 mod load;
 mod store;
 
-use crate::{CallStack, Operand, RegisterTypes, Stack};
+mod snapshot;
+pub use snapshot::*;
+
+mod trace;
+pub use trace::*;
+
+use crate::{CallStack, EvaluationError, Operand, RegisterTypes, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
@@ -116,6 +122,16 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.tvk_circuit = Some(tvk_circuit);
     }
 
+    /// Returns the error for loading the given register, which has not been assigned.
+    fn unassigned_register_error(&self, stack: &Stack<N>, register: &Register<N>) -> Error {
+        // Note: The locator is checked, as the members of a register are defined if the register is defined.
+        let message = match self.register_types.get_type(stack, &Register::Locator(register.locator())) {
+            Ok(..) => format!("Register '{register}' is defined, but has not been assigned yet"),
+            Err(..) => format!("Register '{register}' is not defined in the function"),
+        };
+        EvaluationError::MissingRegister(message).into()
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
@@ -136,3 +152,21 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         Ok(())
     }
 }
+
+/// Initializes a stack for the given program, along with empty registers for the given function.
+#[cfg(test)]
+pub(crate) fn sample_registers<N: Network, A: circuit::Aleo<Network = N>>(
+    program: &crate::Program<N>,
+    function_name: &console::program::Identifier<N>,
+) -> Result<(Stack<N>, Registers<N, A>)> {
+    use crate::{Authorization, Process};
+
+    // Initialize the stack.
+    let stack = Stack::new(&Process::load_with_cache(&mut std::collections::HashMap::new())?, program)?;
+    // Initialize the registers.
+    let registers = Registers::new(
+        CallStack::evaluate(Authorization::new(&[]))?,
+        stack.get_register_types(function_name)?.clone(),
+    );
+    Ok((stack, registers))
+}
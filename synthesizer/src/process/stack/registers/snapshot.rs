@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A checkpoint of the assigned registers, used to roll back speculative execution.
+#[derive(Clone)]
+pub struct RegistersSnapshot<N: Network, A: circuit::Aleo<Network = N>> {
+    /// The mapping of assigned console registers to their values.
+    console_registers: IndexMap<u64, Value<N>>,
+    /// The mapping of assigned circuit registers to their values.
+    circuit_registers: IndexMap<u64, circuit::Value<A>>,
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns a snapshot of the currently-assigned registers.
+    #[inline]
+    pub fn snapshot(&self) -> RegistersSnapshot<N, A> {
+        RegistersSnapshot {
+            console_registers: self.console_registers.clone(),
+            circuit_registers: self.circuit_registers.clone(),
+        }
+    }
+
+    /// Restores the registers to the given snapshot, discarding any assignments made since.
+    #[inline]
+    pub fn restore(&mut self, snapshot: RegistersSnapshot<N, A>) {
+        self.console_registers = snapshot.console_registers;
+        self.circuit_registers = snapshot.circuit_registers;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::AleoV0;
+    use console::{network::Testnet3, program::Identifier};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_snapshot_and_restore() -> Result<()> {
+        // Initialize a program that doubles its input.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    add r0 r0 into r1;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let r0 = Operand::Register(Register::Locator(0));
+        let r1 = Operand::Register(Register::Locator(1));
+
+        // Assign `r0`, and take a snapshot.
+        let one = Value::Plaintext(Plaintext::from(Literal::Field(Field::one())));
+        registers.store(&stack, &Register::Locator(0), one.clone())?;
+        let snapshot = registers.snapshot();
+
+        // Assign `r1`.
+        let two = Value::Plaintext(Plaintext::from(Literal::Field(Field::one() + Field::one())));
+        registers.store(&stack, &Register::Locator(1), two.clone())?;
+        assert_eq!(two, registers.load(&stack, &r1)?);
+
+        // Restore the snapshot, and ensure `r1` is unassigned while `r0` is intact.
+        registers.restore(snapshot);
+        assert_eq!(one, registers.load(&stack, &r0)?);
+        assert!(registers.load(&stack, &r1).is_err());
+
+        // Ensure `r1` may be assigned again.
+        registers.store(&stack, &Register::Locator(1), two.clone())?;
+        assert_eq!(two, registers.load(&stack, &r1)?);
+        Ok(())
+    }
+}
@@ -21,8 +21,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     ///
     /// # Errors
     /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
-    /// This method will halt if the register is already used.
+    /// This method will halt if the given register is not defined in the function.
+    /// This method will halt if the register is already assigned.
+    /// This method will halt if the register is not the next register to be assigned.
     #[inline]
     pub fn store_literal(&mut self, stack: &Stack<N>, register: &Register<N>, literal: Literal<N>) -> Result<()> {
         self.store(stack, register, Value::Plaintext(Plaintext::from(literal)))
@@ -32,28 +33,25 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     ///
     /// # Errors
     /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
-    /// This method will halt if the register is already used.
+    /// This method will halt if the given register is not defined in the function.
+    /// This method will halt if the register is already assigned.
+    /// This method will halt if the register is not the next register to be assigned.
     #[inline]
     pub fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()> {
         match register {
             Register::Locator(locator) => {
+                // Ensure the register has not already been assigned.
+                ensure!(!self.console_registers.contains_key(locator), "Register '{register}' is already assigned");
+                // Ensure the register is defined.
+                let register_type = self
+                    .register_types
+                    .get_type(stack, register)
+                    .map_err(|error| anyhow!("Register '{register}' is not defined in the function: {error}"))?;
                 // Ensure the register assignments are monotonically increasing.
                 let expected_locator = self.console_registers.len() as u64;
                 ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
-                // Ensure the register does not already exist.
-                ensure!(
-                    !self.console_registers.contains_key(locator),
-                    "Cannot write to occupied register '{register}'"
-                );
-
-                // Ensure the register type is valid.
-                match self.register_types.get_type(stack, register) {
-                    // Ensure the stack value matches the register type.
-                    Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
-                    // Ensure the register is defined.
-                    Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
-                };
+                // Ensure the stack value matches the register type.
+                stack.matches_register_type(&stack_value, &register_type)?;
 
                 // Store the stack value.
                 match self.console_registers.insert(*locator, stack_value) {
@@ -74,8 +72,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     ///
     /// # Errors
     /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
-    /// This method will halt if the register is already used.
+    /// This method will halt if the given register is not defined in the function.
+    /// This method will halt if the register is already assigned.
+    /// This method will halt if the register is not the next register to be assigned.
     #[inline]
     pub fn store_literal_circuit(
         &mut self,
@@ -90,8 +89,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     ///
     /// # Errors
     /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
-    /// This method will halt if the register is already used.
+    /// This method will halt if the given register is not defined in the function.
+    /// This method will halt if the register is already assigned.
+    /// This method will halt if the register is not the next register to be assigned.
     #[inline]
     pub fn store_circuit(
         &mut self,
@@ -101,24 +101,18 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     ) -> Result<()> {
         match register {
             Register::Locator(locator) => {
+                // Ensure the register has not already been assigned.
+                ensure!(!self.circuit_registers.contains_key(locator), "Register '{register}' is already assigned");
+                // Ensure the register is defined.
+                let register_type = self
+                    .register_types
+                    .get_type(stack, register)
+                    .map_err(|error| anyhow!("Register '{register}' is not defined in the function: {error}"))?;
                 // Ensure the register assignments are monotonically increasing.
                 let expected_locator = self.circuit_registers.len() as u64;
                 ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
-                // Ensure the register does not already exist.
-                ensure!(
-                    !self.circuit_registers.contains_key(locator),
-                    "Cannot write to occupied register '{register}'"
-                );
-
-                // Ensure the register type is valid.
-                match self.register_types.get_type(stack, register) {
-                    // Ensure the stack value matches the register type.
-                    Ok(register_type) => {
-                        stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
-                    }
-                    // Ensure the register is defined.
-                    Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
-                };
+                // Ensure the stack value matches the register type.
+                stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?;
 
                 // Store the stack value.
                 match self.circuit_registers.insert(*locator, circuit_value) {
@@ -133,3 +127,79 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, EvaluationError, Operand, Process, Program};
+    use circuit::{AleoV0, Environment, Inject};
+    use console::{network::Testnet3, program::Identifier};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_single_assignment() -> Result<()> {
+        // Initialize a program that defines the registers `r0` to `r2`.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.private;
+    add r0 1u8 into r1;
+    add r1 1u8 into r2;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let value = Value::from_str("1u8")?;
+        let r = |locator| Register::<CurrentNetwork>::Locator(locator);
+
+        // Ensure loading a defined register before it is assigned fails.
+        let error = registers.load(&stack, &Operand::Register(r(0))).unwrap_err();
+        assert_eq!("Register 'r0' is defined, but has not been assigned yet", error.to_string());
+        assert!(matches!(error.downcast_ref(), Some(EvaluationError::MissingRegister(..))));
+        // Ensure loading an undefined register fails.
+        let error = registers.load(&stack, &Operand::Register(r(5))).unwrap_err();
+        assert_eq!("Register 'r5' is not defined in the function", error.to_string());
+
+        // Ensure assigning an undefined register fails.
+        let error = registers.store(&stack, &r(5), value.clone()).unwrap_err();
+        assert!(error.to_string().starts_with("Register 'r5' is not defined in the function"), "{error}");
+
+        // Ensure assigning a register twice fails.
+        registers.store(&stack, &r(0), value.clone())?;
+        let error = registers.store(&stack, &r(0), value.clone()).unwrap_err();
+        assert_eq!("Register 'r0' is already assigned", error.to_string());
+        assert_eq!(value, registers.load(&stack, &Operand::Register(r(0)))?);
+
+        // Ensure assigning a register out of order fails.
+        let error = registers.store(&stack, &r(2), value.clone()).unwrap_err();
+        assert_eq!("Out-of-order write operation at 'r2'", error.to_string());
+
+        // Ensure the circuit registers enforce the same rules.
+        let circuit_value = || circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, value.clone());
+        match registers.load_circuit(&stack, &Operand::Register(r(0))) {
+            Ok(_) => panic!("Loading an unassigned circuit register should fail"),
+            Err(error) => assert_eq!("Register 'r0' is defined, but has not been assigned yet", error.to_string()),
+        }
+        registers.store_circuit(&stack, &r(0), circuit_value())?;
+        let error = registers.store_circuit(&stack, &r(0), circuit_value()).unwrap_err();
+        assert_eq!("Register 'r0' is already assigned", error.to_string());
+        let error = registers.store_circuit(&stack, &r(5), circuit_value()).unwrap_err();
+        assert!(error.to_string().starts_with("Register 'r5' is not defined in the function"), "{error}");
+
+        CurrentAleo::reset();
+        Ok(())
+    }
+}
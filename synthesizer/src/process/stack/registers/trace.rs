@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Instruction;
+
+/// A single evaluated instruction in an execution trace.
+#[derive(Clone)]
+pub struct TraceStep<N: Network> {
+    /// The instruction that was evaluated.
+    instruction: Instruction<N>,
+    /// The source operands of the instruction, with their values.
+    inputs: Vec<(Operand<N>, Option<Value<N>>)>,
+    /// The destination registers of the instruction, with their values and circuit modes.
+    outputs: Vec<(Register<N>, Option<Value<N>>, Option<circuit::Mode>)>,
+}
+
+impl<N: Network> TraceStep<N> {
+    /// Returns the instruction that was evaluated.
+    #[inline]
+    pub const fn instruction(&self) -> &Instruction<N> {
+        &self.instruction
+    }
+
+    /// Returns the source operands of the instruction, with their values.
+    ///
+    /// A value is `None` if the operand could not be loaded.
+    #[inline]
+    pub fn inputs(&self) -> &[(Operand<N>, Option<Value<N>>)] {
+        &self.inputs
+    }
+
+    /// Returns the destination registers of the instruction, with their values and circuit modes.
+    ///
+    /// A value is `None` if the instruction halted before assigning the register.
+    /// A mode is `None` if the register has no circuit value, which is the case during evaluation.
+    #[inline]
+    pub fn outputs(&self) -> &[(Register<N>, Option<Value<N>>, Option<circuit::Mode>)] {
+        &self.outputs
+    }
+}
+
+/// An opt-in record of the instructions evaluated in a closure or function, used for debugging.
+///
+/// If an instruction halts, the trace ends with that instruction, and the reason is recorded.
+/// Note: Only the instructions of the traced closure or function are recorded. A `call` is recorded as one step,
+/// with the outputs of the callee, but the instructions of the callee are not recorded.
+#[derive(Clone)]
+pub struct ExecutionTrace<N: Network> {
+    /// The evaluated instructions, in order.
+    steps: Vec<TraceStep<N>>,
+    /// The reason the evaluation halted, if it did.
+    halt: Option<String>,
+}
+
+impl<N: Network> Default for ExecutionTrace<N> {
+    /// Initializes a new, empty execution trace.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ExecutionTrace<N> {
+    /// Initializes a new, empty execution trace.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { steps: Vec::new(), halt: None }
+    }
+
+    /// Returns the evaluated instructions, in order.
+    #[inline]
+    pub fn steps(&self) -> &[TraceStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the reason the evaluation halted, if it did.
+    #[inline]
+    pub fn halt(&self) -> Option<&str> {
+        self.halt.as_deref()
+    }
+
+    /// Records the given instruction, which was just evaluated or executed with the given result.
+    pub(crate) fn record<A: circuit::Aleo<Network = N>>(
+        &mut self,
+        stack: &Stack<N>,
+        registers: &Registers<N, A>,
+        instruction: &Instruction<N>,
+        result: &Result<()>,
+    ) {
+        use circuit::Eject;
+
+        // Load the source operands. Registers are assigned once, so their values are unchanged by the instruction.
+        let inputs = instruction
+            .operands()
+            .iter()
+            .map(|operand| (operand.clone(), registers.load(stack, operand).ok()))
+            .collect();
+        // Load the destination registers, along with their modes if they are assigned as circuits.
+        let outputs = instruction
+            .destinations()
+            .into_iter()
+            .map(|register| {
+                let value = registers.load(stack, &Operand::Register(register.clone())).ok();
+                let mode = registers.circuit_registers.get(&register.locator()).map(|value| value.eject_mode());
+                (register, value, mode)
+            })
+            .collect();
+        self.steps.push(TraceStep { instruction: instruction.clone(), inputs, outputs });
+
+        // If the instruction halted, record the reason.
+        if let Err(error) = result {
+            self.halt = Some(error.to_string());
+        }
+    }
+}
+
+impl<N: Network> Display for ExecutionTrace<N> {
+    /// Prints the execution trace, with one block per instruction.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        /// Prints the given optional value.
+        fn fmt_value<N: Network>(value: &Option<Value<N>>) -> String {
+            match value {
+                Some(value) => value.to_string(),
+                None => "(unassigned)".to_string(),
+            }
+        }
+
+        for step in &self.steps {
+            writeln!(f, "{}", step.instruction)?;
+            for (operand, value) in &step.inputs {
+                writeln!(f, "  {operand} = {}", fmt_value(value))?;
+            }
+            for (register, value, mode) in &step.outputs {
+                match mode {
+                    Some(mode) => writeln!(f, "  {register} <- {} ({mode})", fmt_value(value))?,
+                    None => writeln!(f, "  {register} <- {}", fmt_value(value))?,
+                }
+            }
+        }
+        if let Some(halt) = &self.halt {
+            writeln!(f, "halted: {halt}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sample_registers, Authorization, CallStack, Execution, Inclusion, Program};
+    use circuit::AleoV0;
+    use console::{network::Testnet3, program::Identifier};
+
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_execution_trace() -> Result<()> {
+        use circuit::{Environment, Inject};
+
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with a closure that halts unless its inputs sum to five.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program testing.aleo;
+
+closure compute:
+    input r0 as u32;
+    input r1 as u32;
+    add r0 r1 into r2;
+    mul r2 2u32 into r3;
+    assert.eq r3 10u32;
+    output r3 as u32;
+
+function run:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )?;
+        let closure = program.get_closure(&Identifier::from_str("compute")?)?;
+
+        // Initialize the stack.
+        let (stack, _) = sample_registers::<_, CurrentAleo>(&program, &Identifier::from_str("run")?)?;
+
+        // Evaluates the closure on the given inputs, returning the result and the trace.
+        let mut evaluate = |r0: &str, r1: &str| -> Result<(Result<Vec<Value<CurrentNetwork>>>, ExecutionTrace<_>)> {
+            let inputs = [Value::from_str(r0)?, Value::from_str(r1)?];
+            let mut trace = ExecutionTrace::new();
+            let result = stack.evaluate_closure_with_trace::<CurrentAleo>(
+                &closure,
+                &inputs,
+                CallStack::evaluate(Authorization::new(&[]))?,
+                Address::new(Uniform::rand(rng)),
+                Field::rand(rng),
+                &mut trace,
+            );
+            Ok((result, trace))
+        };
+        let value = |value: &str| Some(Value::<CurrentNetwork>::from_str(value).unwrap());
+
+        // Ensure the trace of a successful evaluation records every instruction.
+        let (result, trace) = evaluate("2u32", "3u32")?;
+        assert_eq!(vec![Value::from_str("10u32")?], result?);
+        assert!(trace.halt().is_none());
+        assert_eq!(3, trace.steps().len());
+
+        let step = &trace.steps()[0];
+        assert_eq!("add r0 r1 into r2;", step.instruction().to_string());
+        let (r0, r1) = (Operand::Register(Register::Locator(0)), Operand::Register(Register::Locator(1)));
+        assert_eq!(vec![(r0, value("2u32")), (r1, value("3u32"))], step.inputs());
+        assert_eq!(vec![(Register::Locator(2), value("5u32"), None)], step.outputs());
+
+        let step = &trace.steps()[1];
+        assert_eq!(*step.instruction().opcode(), "mul");
+        assert_eq!(value("5u32"), step.inputs()[0].1);
+        assert_eq!(value("2u32"), step.inputs()[1].1);
+        assert_eq!(vec![(Register::Locator(3), value("10u32"), None)], step.outputs());
+
+        let step = &trace.steps()[2];
+        assert_eq!(*step.instruction().opcode(), "assert.eq");
+        assert!(step.outputs().is_empty());
+
+        // Ensure the trace of a halted evaluation ends with the halting instruction, and records the reason.
+        let (result, trace) = evaluate("1u32", "2u32")?;
+        assert!(result.is_err());
+        assert_eq!(3, trace.steps().len());
+        assert_eq!(vec![(Register::Locator(3), value("6u32"), None)], trace.steps()[1].outputs());
+        assert_eq!("assert.eq r3 10u32;", trace.steps()[2].instruction().to_string());
+        assert!(trace.halt().is_some());
+
+        // Ensure the text rendering includes the values and the halt reason.
+        let text = trace.to_string();
+        assert!(text.starts_with("add r0 r1 into r2;\n  r0 = 1u32\n  r1 = 2u32\n  r2 <- 3u32\n"));
+        assert!(text.contains("  r3 = 6u32\n  10u32 = 10u32\nhalted: "));
+
+        // Ensure the trace of an execution records the circuit modes of the destination registers.
+        let inputs = [
+            circuit::Value::<CurrentAleo>::new(circuit::Mode::Public, Value::from_str("2u32")?),
+            circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, Value::from_str("3u32")?),
+        ];
+        let call_stack = CallStack::execute(
+            Authorization::new(&[]),
+            Arc::new(RwLock::new(Execution::new())),
+            Arc::new(RwLock::new(Inclusion::new())),
+            Default::default(),
+        )?;
+        let mut trace = ExecutionTrace::new();
+        stack.execute_closure_with_trace::<CurrentAleo>(
+            &closure,
+            &inputs,
+            call_stack,
+            circuit::Address::new(circuit::Mode::Private, Address::new(Uniform::rand(rng))),
+            circuit::Field::new(circuit::Mode::Private, Field::rand(rng)),
+            &mut trace,
+        )?;
+        assert!(trace.halt().is_none());
+        let expected = vec![(Register::Locator(2), value("5u32"), Some(circuit::Mode::Private))];
+        assert_eq!(expected, trace.steps()[0].outputs());
+        assert!(trace.to_string().contains("  r2 <- 5u32 (private)\n"));
+        CurrentAleo::reset();
+        Ok(())
+    }
+}
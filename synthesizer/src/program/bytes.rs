@@ -73,14 +73,22 @@ impl<N: Network> ToBytes for Program<N> {
         self.id.write_le(&mut writer)?;
 
         // Write the number of program imports.
-        (self.imports.len() as u8).write_le(&mut writer)?;
+        let num_imports = self.imports.len();
+        match num_imports <= u8::MAX as usize {
+            true => (num_imports as u8).write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {num_imports} imports as bytes"))),
+        }
         // Write the program imports.
         for import in self.imports.values() {
             import.write_le(&mut writer)?;
         }
 
         // Write the number of components.
-        (self.identifiers.len() as u16).write_le(&mut writer)?;
+        let num_components = self.identifiers.len();
+        match num_components <= u16::MAX as usize {
+            true => (num_components as u16).write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {num_components} components as bytes"))),
+        }
         // Write the components.
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
@@ -170,4 +178,43 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_rejects_corrupted_bytes() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    token_amount as u64.private;
+
+function compute:
+    input r0 as token.record;
+    add r0.token_amount r0.token_amount into r1;
+    output r1 as u64.private;",
+        )?;
+        let expected_bytes = program.to_bytes_le()?;
+
+        // Ensure truncated bytes are rejected.
+        for length in 0..expected_bytes.len() {
+            assert!(Program::<CurrentNetwork>::from_bytes_le(&expected_bytes[..length]).is_err());
+        }
+
+        // Ensure an unknown version is rejected.
+        let mut bytes = expected_bytes.clone();
+        bytes[0] = 1;
+        assert!(Program::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+
+        // Ensure an unknown component variant is rejected.
+        let mut bytes = 0u16.to_bytes_le()?;
+        program.id().write_le(&mut bytes)?;
+        0u8.write_le(&mut bytes)?;
+        1u16.write_le(&mut bytes)?;
+        5u8.write_le(&mut bytes)?;
+        assert!(Program::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+
+        Ok(())
+    }
 }
@@ -25,6 +25,9 @@ impl<N: Network> FromBytes for Function<N> {
 
         // Read the inputs.
         let num_inputs = u16::read_le(&mut reader)?;
+        if num_inputs as usize > N::MAX_INPUTS {
+            return Err(error(format!("Failed to deserialize a function: too many inputs ({num_inputs})")));
+        }
         let mut inputs = Vec::with_capacity(num_inputs as usize);
         for _ in 0..num_inputs {
             inputs.push(Input::read_le(&mut reader)?);
@@ -42,6 +45,9 @@ impl<N: Network> FromBytes for Function<N> {
 
         // Read the outputs.
         let num_outputs = u16::read_le(&mut reader)?;
+        if num_outputs as usize > N::MAX_OUTPUTS {
+            return Err(error(format!("Failed to deserialize a function: too many outputs ({num_outputs})")));
+        }
         let mut outputs = Vec::with_capacity(num_outputs as usize);
         for _ in 0..num_outputs {
             outputs.push(Output::read_le(&mut reader)?);
@@ -162,4 +168,80 @@ function main:
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    /// Returns a function that contains every instruction.
+    fn sample_function_with_every_instruction() -> Result<Function<CurrentNetwork>> {
+        let unary = "abs abs.w double inv mul.gen neg not square sqrt hash.bhp256 hash.bhp512 hash.bhp768 hash.bhp1024 \
+            hash.ped64 hash.ped128 hash.psd2 hash.psd4 hash.psd8 hash.ped64.raw hash.ped128.raw";
+        let binary = "add add.w and div div.w gt gte is.eq is.neq lt lte max min mod mul mul.w nand nor or pow pow.w \
+            rem rem.w shl shl.w shr shr.w sub sub.w xor commit.bhp256 commit.bhp512 commit.bhp768 commit.bhp1024 \
+            commit.ped64 commit.ped128 add.bytes xor.bytes";
+
+        // Construct the instructions, assigning each destination to the next register.
+        let mut instructions = vec!["assert.eq r0 r1;".to_string(), "assert.neq r0 r1;".to_string()];
+        let mut destination = 2..;
+        for opcode in unary.split_whitespace() {
+            instructions.push(format!("{opcode} r0 into r{};", destination.next().unwrap()));
+        }
+        for opcode in binary.split_whitespace() {
+            instructions.push(format!("{opcode} r0 r1 into r{};", destination.next().unwrap()));
+        }
+        instructions.push(format!("ternary r0 r1 r1 into r{};", destination.next().unwrap()));
+        instructions.push(format!("clamp r0 r1 r1 into r{};", destination.next().unwrap()));
+        instructions.push(format!("cast r0 r1 into r{} as point;", destination.next().unwrap()));
+        instructions.push(format!("call foo r0 r1 into r{};", destination.next().unwrap()));
+        instructions.push(format!("pack point r0 r1 into r{};", destination.next().unwrap()));
+        instructions.push(format!("unpack r0 into r{} r{};", destination.next().unwrap(), destination.next().unwrap()));
+        instructions.push(format!("load.proof_target into r{};", destination.next().unwrap()));
+        instructions.push(format!("sign.verify r0 r1 r0 into r{};", destination.next().unwrap()));
+
+        let function = Function::<CurrentNetwork>::from_str(&format!(
+            "function main:\n    input r0 as field.public;\n    input r1 as field.private;\n    {}\n    {}",
+            instructions.join("\n    "),
+            "output r2 as field.private;"
+        ))?;
+
+        // Ensure the function contains every instruction.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES {
+            assert!(function.instructions().iter().any(|instruction| instruction.opcode() == *opcode), "{opcode}");
+        }
+        Ok(function)
+    }
+
+    #[test]
+    fn test_function_bytes_every_instruction() -> Result<()> {
+        let expected = sample_function_with_every_instruction()?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Function::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_function_bytes_rejects_corrupted_bytes() -> Result<()> {
+        let expected_bytes = sample_function_with_every_instruction()?.to_bytes_le()?;
+
+        // Ensure truncated bytes are rejected.
+        for length in 0..expected_bytes.len() {
+            assert!(Function::<CurrentNetwork>::from_bytes_le(&expected_bytes[..length]).is_err());
+        }
+
+        // Construct the bytes of a function, up to the instructions.
+        let mut prefix = Identifier::<CurrentNetwork>::from_str("main")?.to_bytes_le()?;
+        0u16.write_le(&mut prefix)?;
+
+        // Ensure an unknown opcode is rejected.
+        let mut bytes = prefix.clone();
+        1u32.write_le(&mut bytes)?;
+        (Instruction::<CurrentNetwork>::OPCODES.len() as u16).write_le(&mut bytes)?;
+        assert!(Function::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+
+        // Ensure too many instructions are rejected.
+        let mut bytes = prefix;
+        (CurrentNetwork::MAX_INSTRUCTIONS as u32 + 1).write_le(&mut bytes)?;
+        assert!(Function::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Operand;
+use console::program::PlaintextType;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Function<N> {
+    /// Returns an estimate of the number of constants, public inputs, private inputs, and constraints
+    /// in the circuit of the function, as the sum of the circuit costs of its instructions.
+    ///
+    /// The types and modes of the operands are inferred from the input statements and preceding instructions.
+    /// The literal operands are synthesized with their values, as the cost of an operation on a constant may depend
+    /// on its value. The other constant operands, such as constant inputs, are synthesized on sample values,
+    /// so their cost is an estimate for a constant that takes no special case.
+    /// Note: The circuit environment is reset.
+    pub fn count<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Count> {
+        // Reset the circuit environment.
+        A::reset();
+        // Sum the circuit costs of the instructions.
+        let count = self.count_instructions::<A>();
+        // Reset the circuit environment.
+        A::reset();
+        count
+    }
+
+    /// Returns the sum of the circuit costs of the instructions in the function.
+    fn count_instructions<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Count> {
+        // Initialize a mapping of the registers to their literal types and modes.
+        let mut register_types = IndexMap::new();
+        for input in self.inputs() {
+            let (plaintext_type, mode) = match input.value_type() {
+                ValueType::Constant(plaintext_type) => (plaintext_type, circuit::Mode::Constant),
+                ValueType::Public(plaintext_type) => (plaintext_type, circuit::Mode::Public),
+                ValueType::Private(plaintext_type) => (plaintext_type, circuit::Mode::Private),
+                value_type => bail!("Cannot count the '{value_type}' input in function '{}'", self.name),
+            };
+            let literal_type = match plaintext_type {
+                PlaintextType::Literal(literal_type) => *literal_type,
+                PlaintextType::Struct(..) => {
+                    bail!("Cannot count the '{plaintext_type}' input in function '{}'", self.name)
+                }
+            };
+            register_types.insert(input.register().clone(), (literal_type, mode));
+        }
+
+        let mut count = circuit::Count::zero();
+        for instruction in self.instructions() {
+            // Retrieve the literal types and modes of the operands.
+            let input_types = instruction
+                .operands()
+                .iter()
+                .map(|operand| match operand {
+                    Operand::Literal(literal) => Ok((literal.to_type(), circuit::Mode::Constant)),
+                    Operand::Register(register) => register_types
+                        .get(register)
+                        .copied()
+                        .ok_or_else(|| anyhow!("Register '{register}' is not a literal in function '{}'", self.name)),
+                    _ => bail!("Cannot count the operand '{operand}' in function '{}'", self.name),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Count the instruction, and assign the output type and mode to the destination.
+            let (instruction_count, output_type, output_mode) = instruction.count::<A>(&input_types)?;
+            for destination in instruction.destinations() {
+                register_types.insert(destination, (output_type, output_mode));
+            }
+            count = count + instruction_count;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{
+        prelude::{Compare, Environment, Inject, Inverse, Metrics, MinMax, Mode, Ternary},
+        AleoV0,
+    };
+    use console::network::Testnet3;
+
+    use core::ops::{Add, Mul};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+    type FieldCircuit = circuit::Field<CurrentAleo>;
+    type FieldAdd = dyn Add<FieldCircuit, Output = FieldCircuit>;
+    type FieldMul = dyn Mul<FieldCircuit, Output = FieldCircuit>;
+    type FieldInverse = dyn Inverse<Output = FieldCircuit>;
+
+    #[test]
+    fn test_count() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    mul r2 r0 into r3;
+    inv r3 into r4;
+    output r4 as field.private;",
+        )?;
+        let candidate = function.count::<CurrentAleo>()?;
+
+        // Ensure the aggregate count is the sum of the counts of each instruction.
+        let expected = <FieldCircuit as Metrics<FieldAdd>>::count(&(Mode::Private, Mode::Public))
+            + <FieldCircuit as Metrics<FieldMul>>::count(&(Mode::Private, Mode::Private))
+            + <FieldCircuit as Metrics<FieldInverse>>::count(&Mode::Private);
+        assert_eq!(expected.0, candidate.0);
+        assert_eq!(expected.1, candidate.1);
+        assert_eq!(expected.2, candidate.2);
+        assert_eq!(expected.3, candidate.3);
+
+        // Ensure the aggregate count matches the synthesized function.
+        let r0 = FieldCircuit::new(Mode::Private, console::types::Field::from_u64(3));
+        let r1 = FieldCircuit::new(Mode::Public, console::types::Field::from_u64(5));
+        let (num_constants, num_public, num_private, num_constraints, _) = CurrentAleo::count();
+        let r2 = &r0 + &r1;
+        let r3 = r2 * &r0;
+        let _r4 = r3.inverse();
+        let (constants, public, private, constraints, _) = CurrentAleo::count();
+        assert!(candidate.matches(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints
+        ));
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_integers() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function clamp_sum:
+    input r0 as u8.private;
+    input r1 as u8.public;
+    min r0 r1 into r2;
+    max r0 r1 into r3;
+    add r2 r3 into r4;
+    lt r4 r0 into r5;
+    ternary r5 r2 r3 into r6;
+    output r6 as u8.private;",
+        )?;
+        let candidate = function.count::<CurrentAleo>()?;
+
+        // Ensure the aggregate count matches the synthesized function.
+        let r0 = circuit::U8::<CurrentAleo>::new(Mode::Private, console::types::U8::new(3));
+        let r1 = circuit::U8::<CurrentAleo>::new(Mode::Public, console::types::U8::new(7));
+        let (num_constants, num_public, num_private, num_constraints, _) = CurrentAleo::count();
+        let r2 = r0.minimum(&r1);
+        let r3 = r0.maximum(&r1);
+        let r4 = &r2 + &r3;
+        let r5 = r4.is_less_than(&r0);
+        let _r6 = circuit::U8::<CurrentAleo>::ternary(&r5, &r2, &r3);
+        let (constants, public, private, constraints, _) = CurrentAleo::count();
+        assert!(candidate.matches(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints
+        ));
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_constants() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function scale:
+    input r0 as u64.private;
+    mul r0 7u64 into r1;
+    mul r1 1u64 into r2;
+    output r2 as u64.private;",
+        )?;
+        let candidate = function.count::<CurrentAleo>()?;
+
+        // Ensure multiplying by `7u64` is counted, while multiplying by `1u64` is free.
+        assert!(!candidate.3.matches(0));
+
+        // Ensure the aggregate count matches the synthesized function, with the literal operands as given.
+        let r0 = circuit::U64::<CurrentAleo>::new(Mode::Private, console::types::U64::new(3));
+        let seven = circuit::U64::new(Mode::Constant, console::types::U64::new(7));
+        let one = circuit::U64::new(Mode::Constant, console::types::U64::new(1));
+        let (num_constants, num_public, num_private, num_constraints, _) = CurrentAleo::count();
+        let r1 = &r0 * &seven;
+        let _r2 = &r1 * &one;
+        let (constants, public, private, constraints, _) = CurrentAleo::count();
+        assert!(candidate.matches(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints
+        ));
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_fails() -> Result<()> {
+        // Ensure instructions without a circuit cost estimate are rejected.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1;
+    output r1 as field.private;",
+        )?;
+        let error = function.count::<CurrentAleo>().unwrap_err();
+        assert_eq!("Instruction 'hash.bhp256' does not support circuit cost estimation", error.to_string());
+
+        // Ensure record inputs are rejected.
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as token.record;
+    output r0.owner as address.private;",
+        )?;
+        assert!(function.count::<CurrentAleo>().is_err());
+        Ok(())
+    }
+}
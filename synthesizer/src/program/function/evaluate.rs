@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Registers, Stack};
+
+impl<N: Network> Function<N> {
+    /// Evaluates the instructions of the function on the given registers, in order of execution.
+    ///
+    /// # Errors
+    /// This method returns an error, instead of halting, if an instruction fails to evaluate (e.g. on an overflow).
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        self.instructions.iter().try_for_each(|instruction| {
+            instruction
+                .evaluate(stack, registers)
+                .map_err(|error| anyhow!("Failed to evaluate instruction ({instruction}): {error}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sample_registers, Operand, Program};
+    use circuit::AleoV0;
+    use console::{network::Testnet3, program::Value};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_evaluate() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+        )?;
+        let function = program.get_function(&Identifier::from_str("compute")?)?;
+
+        // Evaluates the function on the given inputs, and returns the value of its destination.
+        let evaluate = |inputs: [&str; 2]| -> Result<Value<CurrentNetwork>> {
+            let (stack, mut registers) = sample_registers::<_, CurrentAleo>(&program, function.name())?;
+            for (input, value) in function.inputs().iter().zip(inputs) {
+                registers.store(&stack, input.register(), Value::from_str(value)?)?;
+            }
+            function.evaluate(&stack, &mut registers)?;
+            registers.load(&stack, &Operand::Register(Register::Locator(2)))
+        };
+
+        // Ensure a valid evaluation stores its output.
+        assert_eq!(evaluate(["200u8", "55u8"])?, Value::from_str("255u8")?);
+        // Ensure an overflow returns an error, instead of halting.
+        let error = evaluate(["200u8", "100u8"]).unwrap_err();
+        assert!(error.to_string().contains("Integer addition failed on: 200u8 and 100u8"), "{error}");
+        Ok(())
+    }
+}
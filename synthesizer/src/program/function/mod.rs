@@ -21,7 +21,13 @@ mod output;
 use output::*;
 
 mod bytes;
+mod count;
+mod evaluate;
+mod optimize;
+mod output_modes;
 mod parse;
+mod type_check;
+pub use type_check::TypeError;
 
 use crate::{
     program::finalize::{Finalize, FinalizeCommand},
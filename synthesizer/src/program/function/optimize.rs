@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Literals, Operand};
+use console::{
+    program::Literal,
+    types::{Field, I128, I16, I32, I64, I8},
+};
+
+impl<N: Network> Function<N> {
+    /// Returns the function with its instructions rewritten into cheaper equivalents.
+    ///
+    /// The following rewrites are applied:
+    ///   - `add r0 -c into r1` becomes `sub r0 c into r1` (and `add.w` becomes `sub.w`), for a signed integer `c`.
+    ///   - `mul r0 -1 into r1` becomes `neg r0 into r1`, for a signed integer or field `-1`.
+    /// Each rewrite produces the same output as the original instruction, and halts on the same inputs.
+    pub fn optimize(&self) -> Result<Self> {
+        let mut function = self.clone();
+        function.instructions = self.instructions.iter().map(Self::optimize_instruction).collect::<Result<_>>()?;
+        Ok(function)
+    }
+
+    /// Returns the cheaper equivalent of the given instruction, or the instruction itself if there is none.
+    fn optimize_instruction(instruction: &Instruction<N>) -> Result<Instruction<N>> {
+        // Retrieve the register operand and the constant operand of the instruction.
+        let (first, constant) = match instruction.operands() {
+            [first @ Operand::Register(..), Operand::Literal(constant)]
+            | [Operand::Literal(constant), first @ Operand::Register(..)] => (first.clone(), constant),
+            _ => return Ok(instruction.clone()),
+        };
+        // Retrieve the destination register of the instruction.
+        let destination = match &instruction.destinations()[..] {
+            [destination] => destination.clone(),
+            _ => return Ok(instruction.clone()),
+        };
+
+        let optimized = match (instruction, Self::negate_signed(constant)) {
+            (Instruction::Add(..), Some(negated)) => {
+                Instruction::Sub(Literals::new(vec![first, Operand::Literal(negated)], destination)?)
+            }
+            (Instruction::AddWrapped(..), Some(negated)) => {
+                Instruction::SubWrapped(Literals::new(vec![first, Operand::Literal(negated)], destination)?)
+            }
+            // Note: `mul.w` is not rewritten, as `neg` halts on the minimum value instead of wrapping.
+            (Instruction::Mul(..), _) if Self::is_negative_one(constant) => {
+                Instruction::Neg(Literals::new(vec![first], destination)?)
+            }
+            _ => instruction.clone(),
+        };
+        Ok(optimized)
+    }
+
+    /// Returns the negation of the given literal, if it is a negative signed integer whose negation does not overflow.
+    fn negate_signed(literal: &Literal<N>) -> Option<Literal<N>> {
+        match literal {
+            Literal::I8(value) if value.is_negative() => {
+                value.checked_neg().map(|value| Literal::I8(I8::new(value)))
+            }
+            Literal::I16(value) if value.is_negative() => {
+                value.checked_neg().map(|value| Literal::I16(I16::new(value)))
+            }
+            Literal::I32(value) if value.is_negative() => {
+                value.checked_neg().map(|value| Literal::I32(I32::new(value)))
+            }
+            Literal::I64(value) if value.is_negative() => {
+                value.checked_neg().map(|value| Literal::I64(I64::new(value)))
+            }
+            Literal::I128(value) if value.is_negative() => {
+                value.checked_neg().map(|value| Literal::I128(I128::new(value)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the given literal is `-1`, as a signed integer or a field element.
+    fn is_negative_one(literal: &Literal<N>) -> bool {
+        match literal {
+            Literal::Field(value) => *value == -Field::one(),
+            Literal::I8(value) => **value == -1,
+            Literal::I16(value) => **value == -1,
+            Literal::I32(value) => **value == -1,
+            Literal::I64(value) => **value == -1,
+            Literal::I128(value) => **value == -1,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program, Registers, Stack};
+    use circuit::AleoV0;
+    use console::{network::Testnet3, program::Value};
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Evaluates the instructions of the function on the given inputs, and returns the values of their destinations.
+    fn evaluate(function: &Function<CurrentNetwork>, inputs: &[&str]) -> Result<Vec<Value<CurrentNetwork>>> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(&format!("program testing.aleo;\n\n{function}"))?;
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, and store the inputs.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(function.name())?.clone(),
+        );
+        for (input, value) in function.inputs().iter().zip(inputs) {
+            registers.store(&stack, input.register(), Value::from_str(value)?)?;
+        }
+
+        // Evaluate the instructions, and load their destinations.
+        function.evaluate(&stack, &mut registers)?;
+        function
+            .instructions()
+            .iter()
+            .flat_map(|instruction| instruction.destinations())
+            .map(|destination| registers.load(&stack, &Operand::Register(destination)))
+            .collect()
+    }
+
+    #[test]
+    fn test_optimize() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as i8.private;
+    input r1 as field.private;
+    add r0 -3i8 into r2;
+    add.w r2 -128i8 into r3;
+    add.w -5i8 r3 into r4;
+    mul r4 -1i8 into r5;
+    mul.w r4 -1i8 into r6;
+    mul r1 -1field into r7;
+    add r1 -1field into r8;
+    output r5 as i8.private;
+    output r7 as field.private;",
+        )?;
+        let expected = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as i8.private;
+    input r1 as field.private;
+    sub r0 3i8 into r2;
+    add.w r2 -128i8 into r3;
+    sub.w r3 5i8 into r4;
+    neg r4 into r5;
+    mul.w r4 -1i8 into r6;
+    neg r1 into r7;
+    add r1 -1field into r8;
+    output r5 as i8.private;
+    output r7 as field.private;",
+        )?;
+
+        // Ensure the instructions are rewritten, and the constant `-128i8`, `mul.w` and field `add` are kept.
+        let optimized = function.optimize()?;
+        assert_eq!(expected, optimized);
+
+        // Ensure the optimized function produces the same register values on sample inputs.
+        let samples =
+            [["0i8", "0field"], ["5i8", "3field"], ["100i8", "-7field"], ["-100i8", "1field"], ["-125i8", "2field"]];
+        for inputs in samples {
+            assert_eq!(evaluate(&function, &inputs)?, evaluate(&optimized, &inputs)?);
+        }
+
+        // Ensure optimizing is idempotent.
+        assert_eq!(optimized, optimized.optimize()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_halts_at_type_bounds() -> Result<()> {
+        // Each instruction is sampled on an input at the bound of the type, and on an input past it.
+        let cases = [
+            // The rewritten `sub` overflows below the minimum, as the `add` does.
+            ("add r0 -3i8 into r1;", "sub r0 3i8 into r1;", "-125i8", "-126i8"),
+            ("add r0 -1i8 into r1;", "sub r0 1i8 into r1;", "-127i8", "-128i8"),
+            // The rewritten `neg` overflows on the minimum, as the `mul` does, i.e. `-128i8 * -1i8`.
+            ("mul r0 -1i8 into r1;", "neg r0 into r1;", "-127i8", "-128i8"),
+            // The minimum constant is not rewritten, as its negation overflows.
+            ("add r0 -128i8 into r1;", "add r0 -128i8 into r1;", "0i8", "-1i8"),
+        ];
+        for (instruction, expected, bound, past_bound) in cases {
+            let function = |instruction: &str| {
+                Function::<CurrentNetwork>::from_str(&format!(
+                    "function compute:\n    input r0 as i8.private;\n    {instruction}\n    output r1 as i8.private;"
+                ))
+            };
+            let (function, expected) = (function(instruction)?, function(expected)?);
+
+            // Ensure the instruction is rewritten as expected.
+            let optimized = function.optimize()?;
+            assert_eq!(expected, optimized);
+
+            // Ensure both functions produce the same output at the bound.
+            assert_eq!(evaluate(&function, &[bound])?, evaluate(&optimized, &[bound])?);
+            // Ensure both functions halt past the bound.
+            assert!(evaluate(&function, &[past_bound]).is_err(), "'{instruction}' did not halt on {past_bound}");
+            assert!(evaluate(&optimized, &[past_bound]).is_err(), "'{expected}' did not halt on {past_bound}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{sample_constant_operand, sample_operand, Operand};
+use console::program::PlaintextType;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Function<N> {
+    /// Returns the modes of the outputs of the function, for inputs of the given modes.
+    ///
+    /// The inputs are synthesized on sample values, while the literal operands are used as given,
+    /// so the modes account for constant operands, such as adding `0group` or multiplying by `1scalar`.
+    /// The constant inputs are sampled as values that take no special case, as their values are not known.
+    /// Note: The circuit environment is reset.
+    pub fn output_modes<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_modes: &[circuit::Mode],
+    ) -> Result<Vec<circuit::Mode>> {
+        // Reset the circuit environment.
+        A::reset();
+        // Synthesize the function, and retrieve the modes of the outputs.
+        let output_modes = self.synthesize_output_modes::<A>(input_modes);
+        // Reset the circuit environment.
+        A::reset();
+        output_modes
+    }
+
+    /// Returns the modes of the outputs of the function, synthesized on inputs of the given modes.
+    fn synthesize_output_modes<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_modes: &[circuit::Mode],
+    ) -> Result<Vec<circuit::Mode>> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the number of input modes is correct.
+        ensure!(
+            self.inputs().len() == input_modes.len(),
+            "Function '{}' expects {} input modes, found {} input modes",
+            self.name,
+            self.inputs().len(),
+            input_modes.len()
+        );
+
+        // Initialize a mapping of the registers to their circuit literals, sampling the inputs.
+        let mut registers = IndexMap::new();
+        for (input, mode) in self.inputs().iter().zip(input_modes) {
+            let literal_type = match input.value_type() {
+                ValueType::Constant(PlaintextType::Literal(literal_type))
+                | ValueType::Public(PlaintextType::Literal(literal_type))
+                | ValueType::Private(PlaintextType::Literal(literal_type)) => *literal_type,
+                value_type => bail!("Cannot infer the mode of the '{value_type}' input in function '{}'", self.name),
+            };
+            // Sample a constant input as a value that takes no special case, such as multiplying by one.
+            let literal = match mode {
+                circuit::Mode::Constant => sample_constant_operand(literal_type)?,
+                _ => sample_operand(literal_type)?,
+            };
+            registers.insert(input.register().clone(), circuit::Literal::new(*mode, literal));
+        }
+
+        for instruction in self.instructions() {
+            // Retrieve the circuit operands, injecting the literal operands as constants.
+            let inputs = instruction
+                .operands()
+                .iter()
+                .map(|operand| match operand {
+                    Operand::Literal(literal) => Ok(circuit::Literal::new(circuit::Mode::Constant, literal.clone())),
+                    Operand::Register(register) => registers
+                        .get(register)
+                        .cloned()
+                        .ok_or_else(|| anyhow!("Register '{register}' is not a literal in function '{}'", self.name)),
+                    _ => bail!("Cannot infer the mode of the operand '{operand}' in function '{}'", self.name),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Synthesize the instruction, and assign the output to the destination.
+            let output = instruction.synthesize_output::<A>(&inputs)?;
+            for destination in instruction.destinations() {
+                registers.insert(destination, output.clone());
+            }
+        }
+
+        // Retrieve the modes of the outputs.
+        self.outputs()
+            .iter()
+            .map(|output| match output.operand() {
+                Operand::Literal(..) => Ok(circuit::Mode::Constant),
+                Operand::Register(register) => registers
+                    .get(register)
+                    .map(|literal| literal.eject_mode())
+                    .ok_or_else(|| anyhow!("Register '{register}' is not a literal in function '{}'", self.name)),
+                operand => bail!("Cannot infer the mode of the output '{operand}' in function '{}'", self.name),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{AleoV0, Mode};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_output_modes_group() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as group.private;
+    add r0 0group into r1;
+    sub r1 0group into r2;
+    mul r2 1scalar into r3;
+    add r3 r3 into r4;
+    output r1 as group.private;
+    output r2 as group.private;
+    output r3 as group.private;
+    output r4 as group.private;",
+        )?;
+
+        // Ensure adding or subtracting `0group` and multiplying by `1scalar` preserve the mode of the group,
+        // as in the synthesized operations, while adding two variables yields a private output.
+        assert_eq!(vec![Mode::Constant; 4], function.output_modes::<CurrentAleo>(&[Mode::Constant])?);
+        assert_eq!(
+            vec![Mode::Public, Mode::Public, Mode::Public, Mode::Private],
+            function.output_modes::<CurrentAleo>(&[Mode::Public])?
+        );
+        assert_eq!(vec![Mode::Private; 4], function.output_modes::<CurrentAleo>(&[Mode::Private])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_modes_field() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    mul 1field r0 into r2;
+    mul 2field r0 into r3;
+    add r0 r1 into r4;
+    output r2 as field.public;
+    output r3 as field.private;
+    output r4 as field.private;",
+        )?;
+
+        // Ensure `constant * public` yields a public output only if the constant is one.
+        let candidate = function.output_modes::<CurrentAleo>(&[Mode::Public, Mode::Private])?;
+        assert_eq!(vec![Mode::Public, Mode::Private, Mode::Private], candidate);
+
+        // Ensure constant inputs yield constant outputs.
+        let candidate = function.output_modes::<CurrentAleo>(&[Mode::Constant, Mode::Constant])?;
+        assert_eq!(vec![Mode::Constant; 3], candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_modes_constant_input() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.public;
+    mul r0 r1 into r2;
+    add r0 r1 into r3;
+    output r2 as field.private;
+    output r3 as field.private;",
+        )?;
+
+        // Ensure a constant input is not assumed to be one or zero, as its value is not known.
+        let candidate = function.output_modes::<CurrentAleo>(&[Mode::Constant, Mode::Public])?;
+        assert_eq!(vec![Mode::Private, Mode::Private], candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_modes_fails() -> Result<()> {
+        let function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1;
+    output r1 as field.private;",
+        )?;
+
+        // Ensure the number of input modes is checked.
+        assert!(function.output_modes::<CurrentAleo>(&[]).is_err());
+        // Ensure instructions without output mode inference are rejected.
+        let error = function.output_modes::<CurrentAleo>(&[Mode::Private]).unwrap_err();
+        assert_eq!("Instruction 'hash.bhp256' does not support output mode inference", error.to_string());
+        Ok(())
+    }
+}
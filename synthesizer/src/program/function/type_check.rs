@@ -0,0 +1,295 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Operand, Program, Stack};
+use console::program::{EntryType, LiteralType, PlaintextType, RegisterType};
+
+use indexmap::IndexMap;
+
+/// An error from type-checking a function, found before any value is loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeError {
+    /// An instruction has operands of mismatched or invalid types.
+    InvalidInstruction(String),
+    /// A register is used before it is assigned.
+    UnassignedRegister(String),
+    /// A struct, record, or member is not defined in the program.
+    UndefinedType(String),
+    /// An output does not match its declared type.
+    InvalidOutput(String),
+}
+
+impl Display for TypeError {
+    /// Prints the type error as its message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidInstruction(message)
+            | Self::UnassignedRegister(message)
+            | Self::UndefinedType(message)
+            | Self::InvalidOutput(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for TypeError {}
+
+impl<N: Network> Function<N> {
+    /// Checks that the function is well-typed in the given program, without evaluating it,
+    /// and returns the inferred register types of its outputs.
+    ///
+    /// The register type of each destination is inferred from its operand types, with the same rules as deployment.
+    /// Note: As the program is checked on its own, calls to imported programs are rejected.
+    ///
+    /// # Errors
+    /// This method returns an error if an instruction has operands of mismatched types.
+    /// This method returns an error if a register is used before it is assigned.
+    /// This method returns an error if a struct, record, or member is not defined in the program.
+    /// This method returns an error if an output does not match its declared type.
+    pub fn type_check(&self, program: &Program<N>) -> Result<Vec<RegisterType<N>>, TypeError> {
+        // Initialize a stack for the program, to compute the output types of the instructions.
+        let stack = Stack::for_program(program).map_err(|error| TypeError::UndefinedType(error.to_string()))?;
+        // Initialize a map from each assigned register locator to its register type.
+        let mut register_types = IndexMap::new();
+
+        // Assign the input registers, ensuring their types are defined.
+        for input in self.inputs.iter() {
+            let register_type = RegisterType::from(*input.value_type());
+            Self::ensure_type_is_defined(program, &register_type)?;
+            register_types.insert(input.register().locator(), register_type);
+        }
+
+        // Assign the destination registers, inferring their types from the operand types.
+        for instruction in self.instructions.iter() {
+            // Retrieve the operand types, which must be assigned.
+            let operand_types = instruction
+                .operands()
+                .iter()
+                .map(|operand| Self::type_of_operand(program, &register_types, operand))
+                .collect::<Result<Vec<_>, _>>()?;
+            // Compute the destination types.
+            let destination_types = instruction.output_types(&stack, &operand_types).map_err(|error| {
+                TypeError::InvalidInstruction(format!("Invalid instruction '{instruction}': {error}"))
+            })?;
+            // Ensure there is one type for each destination.
+            let destinations = instruction.destinations();
+            if destinations.len() != destination_types.len() {
+                return Err(TypeError::InvalidInstruction(format!(
+                    "Instruction '{instruction}' has {} destinations, but {} output types",
+                    destinations.len(),
+                    destination_types.len()
+                )));
+            }
+            // Assign the destination registers.
+            for (destination, destination_type) in destinations.into_iter().zip(destination_types) {
+                register_types.insert(destination.locator(), destination_type);
+            }
+        }
+
+        // Return the output types, ensuring each matches its declared type.
+        self.outputs
+            .iter()
+            .map(|output| {
+                let register_type = Self::type_of_operand(program, &register_types, output.operand())?;
+                let expected_type = RegisterType::from(*output.value_type());
+                match register_type == expected_type {
+                    true => Ok(register_type),
+                    false => Err(TypeError::InvalidOutput(format!(
+                        "Output '{}' is a '{register_type}', but is declared as a '{expected_type}'",
+                        output.operand()
+                    ))),
+                }
+            })
+            .collect()
+    }
+
+    /// Ensures the given register type is defined in the program.
+    fn ensure_type_is_defined(program: &Program<N>, register_type: &RegisterType<N>) -> Result<(), TypeError> {
+        let is_defined = match register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(..)) => true,
+            RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => program.contains_struct(struct_name),
+            RegisterType::Record(record_name) => program.contains_record(record_name),
+            RegisterType::ExternalRecord(locator) => program.contains_import(locator.program_id()),
+        };
+        match is_defined {
+            true => Ok(()),
+            false => Err(TypeError::UndefinedType(format!("'{register_type}' is not defined in '{}'", program.id()))),
+        }
+    }
+
+    /// Returns the register type of the given operand, from the types of the assigned registers.
+    fn type_of_operand(
+        program: &Program<N>,
+        register_types: &IndexMap<u64, RegisterType<N>>,
+        operand: &Operand<N>,
+    ) -> Result<RegisterType<N>, TypeError> {
+        let register = match operand {
+            Operand::Literal(literal) => return Ok(RegisterType::Plaintext(PlaintextType::from(literal.to_type()))),
+            Operand::ProgramID(..) | Operand::Caller => {
+                return Ok(RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)));
+            }
+            Operand::Register(register) => register,
+        };
+
+        // Retrieve the register type, which must be assigned.
+        let mut register_type = *register_types.get(&register.locator()).ok_or_else(|| {
+            TypeError::UnassignedRegister(format!("Register '{register}' is used before it is assigned"))
+        })?;
+
+        // Traverse the member path, if the register is a member.
+        if let Register::Member(_, path) = register {
+            for member in path.iter() {
+                // Retrieve the member type.
+                let member_type = match &register_type {
+                    RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                        program.get_struct(struct_name).ok().and_then(|struct_| struct_.members().get(member).copied())
+                    }
+                    RegisterType::Record(..) | RegisterType::ExternalRecord(..) if member.to_string() == "owner" => {
+                        Some(PlaintextType::Literal(LiteralType::Address))
+                    }
+                    RegisterType::Record(..) | RegisterType::ExternalRecord(..) if member.to_string() == "gates" => {
+                        Some(PlaintextType::Literal(LiteralType::U64))
+                    }
+                    RegisterType::Record(record_name) => {
+                        program.get_record(record_name).ok().and_then(|record| match record.entries().get(member) {
+                            Some(EntryType::Constant(plaintext_type))
+                            | Some(EntryType::Public(plaintext_type))
+                            | Some(EntryType::Private(plaintext_type)) => Some(*plaintext_type),
+                            None => None,
+                        })
+                    }
+                    // The entries of an external record are only defined in its own program.
+                    RegisterType::Plaintext(PlaintextType::Literal(..)) | RegisterType::ExternalRecord(..) => None,
+                };
+                // Update the register type.
+                register_type = match member_type {
+                    Some(member_type) => RegisterType::Plaintext(member_type),
+                    None => {
+                        return Err(TypeError::UndefinedType(format!(
+                            "'{member}' is not a member of '{register_type}' in '{register}'"
+                        )));
+                    }
+                };
+            }
+        }
+        Ok(register_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a program that defines the `point` struct and the given function.
+    fn sample_program(function: &str) -> Result<Program<CurrentNetwork>> {
+        Program::from_str(&format!(
+            r"
+program testing.aleo;
+
+struct point:
+    x as field;
+    y as field;
+{function}"
+        ))
+    }
+
+    /// Returns the type-checked outputs of the `run` function in the given program.
+    fn type_check(function: &str) -> Result<Result<Vec<RegisterType<CurrentNetwork>>, TypeError>> {
+        let program = sample_program(function)?;
+        Ok(program.get_function(&Identifier::from_str("run")?)?.type_check(&program))
+    }
+
+    #[test]
+    fn test_type_check() -> Result<()> {
+        // Ensure a valid function returns the inferred output types.
+        let outputs = type_check(
+            r"
+function run:
+    input r0 as point.private;
+    input r1 as field.public;
+    add r0.x r1 into r2;
+    mul r2 r0.y into r3;
+    cast r3 r2 into r4 as point;
+    is.eq r3 r2 into r5;
+    output r4 as point.private;
+    output r3 as field.public;
+    output r5 as boolean.private;",
+        )?;
+        let expected = vec![
+            RegisterType::Plaintext(PlaintextType::from_str("point")?),
+            RegisterType::Plaintext(PlaintextType::from_str("field")?),
+            RegisterType::Plaintext(PlaintextType::from_str("boolean")?),
+        ];
+        assert_eq!(Ok(expected), outputs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_check_fails() -> Result<()> {
+        // Ensure mismatched operand types are rejected.
+        let outputs = type_check(
+            r"
+function run:
+    input r0 as field.private;
+    input r1 as group.private;
+    add r0 r1 into r2;
+    output r2 as field.private;",
+        )?;
+        assert!(matches!(outputs, Err(TypeError::InvalidInstruction(..))), "Unexpected result: {outputs:?}");
+
+        // Ensure a register used before it is assigned is rejected.
+        let outputs = type_check(
+            r"
+function run:
+    input r0 as field.private;
+    add r0 r2 into r1;
+    add r0 r0 into r2;
+    output r2 as field.private;",
+        )?;
+        assert!(matches!(outputs, Err(TypeError::UnassignedRegister(..))), "Unexpected result: {outputs:?}");
+
+        // Ensure an undefined struct is rejected.
+        let outputs = type_check(
+            r"
+function run:
+    input r0 as circle.private;
+    output r0 as circle.private;",
+        )?;
+        assert!(matches!(outputs, Err(TypeError::UndefinedType(..))), "Unexpected result: {outputs:?}");
+
+        // Ensure an undefined member is rejected.
+        let outputs = type_check(
+            r"
+function run:
+    input r0 as point.private;
+    output r0.z as field.private;",
+        )?;
+        assert!(matches!(outputs, Err(TypeError::UndefinedType(..))), "Unexpected result: {outputs:?}");
+
+        // Ensure an output of the wrong type is rejected.
+        let outputs = type_check(
+            r"
+function run:
+    input r0 as point.private;
+    output r0.x as boolean.private;",
+        )?;
+        assert!(matches!(outputs, Err(TypeError::InvalidOutput(..))), "Unexpected result: {outputs:?}");
+        Ok(())
+    }
+}
@@ -95,4 +95,30 @@ mod tests {
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_bitwise() -> Result<()> {
+        for instruction in ["and r0 r1 into r2;", "or r0 r1 into r2;", "xor r0 r1 into r2;"] {
+            let expected = Instruction::<CurrentNetwork>::from_str(instruction)?;
+            let expected_bytes = expected.to_bytes_le()?;
+
+            let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_unary() -> Result<()> {
+        for instruction in ["neg r0 into r1;", "not r0 into r1;"] {
+            let expected = Instruction::<CurrentNetwork>::from_str(instruction)?;
+            let expected_bytes = expected.to_bytes_le()?;
+
+            let candidate = Instruction::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        }
+        Ok(())
+    }
 }
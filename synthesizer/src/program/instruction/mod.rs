@@ -32,10 +32,13 @@ use console::{
         prelude::{
             alt,
             bail,
+            context,
             ensure,
             error,
+            fail,
             fmt,
             map,
+            parser_error_location,
             tag,
             Debug,
             Display,
@@ -54,7 +57,7 @@ use console::{
         },
         Network,
     },
-    program::{Register, RegisterType},
+    program::{LiteralType, Register, RegisterType},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -171,6 +174,30 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Adds the byte arrays `first` and `second` as little-endian integers, storing the outcome in `destination`.
+    AddBytes(AddBytes<N>),
+    /// Performs a bitwise `xor` on the byte arrays `first` and `second`, storing the outcome in `destination`.
+    XorBytes(XorBytes<N>),
+    /// Loads the proof target provided to the process, storing the outcome in `destination`.
+    LoadProofTarget(LoadProofTarget<N>),
+    /// Computes the smaller of `first` and `second`, storing the outcome in `destination`.
+    Min(Min<N>),
+    /// Computes the larger of `first` and `second`, storing the outcome in `destination`.
+    Max(Max<N>),
+    /// Clamps `first` into the range from `second` to `third`, storing the outcome in `destination`.
+    Clamp(Clamp<N>),
+    /// Multiplies the generator `G` by `first`, storing the outcome in `destination`.
+    MulGenerator(MulGenerator<N>),
+    /// Verifies the signature `first` on `third` for the address `second`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
+    /// Packs the operands into a struct of the given name, storing the outcome in `destination`.
+    Pack(Pack<N>),
+    /// Unpacks the struct `first` into its members, storing each member in successive destination registers.
+    Unpack(Unpack<N>),
+    /// Performs a Pedersen hash on the bits of a literal of up to 64 bits.
+    HashPED64Raw(HashPED64Raw<N>),
+    /// Performs a Pedersen hash on the bits of a literal of up to 128 bits.
+    HashPED128Raw(HashPED128Raw<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -258,6 +285,18 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            AddBytes,
+            XorBytes,
+            LoadProofTarget,
+            Min,
+            Max,
+            Clamp,
+            MulGenerator,
+            SignVerify,
+            Pack,
+            Unpack,
+            HashPED64Raw,
+            HashPED128Raw,
         }}
     };
     // A variant **without** curly braces:
@@ -372,6 +411,25 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |instruction| instruction.execute::<A>(stack, registers))
     }
 
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output,
+    /// for operands of the given types and modes.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        instruction!(self, |instruction| instruction.count::<A>(input_types))
+    }
+
+    /// Returns the output of the instruction, synthesized on the given circuit operands.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        instruction!(self, |instruction| instruction.synthesize_output::<A>(inputs))
+    }
+
     /// Returns the output type from the given input types.
     #[inline]
     pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -398,15 +456,83 @@ mod tests {
     use super::*;
     use console::network::Testnet3;
 
+    use indexmap::IndexSet;
+
     type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            68,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
+        // Ensure new instructions are appended, so the opcode indices of existing instructions are unchanged.
+        assert_eq!(Instruction::<CurrentNetwork>::OPCODES[55], Xor::<CurrentNetwork>::opcode());
+    }
+
+    #[test]
+    fn test_operands_and_destinations() -> Result<()> {
+        let instructions = [
+            "add r0 r1 into r2;",
+            "cast r0 r2 into r3 as point;",
+            "hash.bhp256 r3 into r4;",
+            "ternary r5 r2 1u64 into r6;",
+            "assert.eq r4 r6;",
+            "call foo r0 r4 into r7 r8;",
+        ]
+        .iter()
+        .map(|instruction| Instruction::<CurrentNetwork>::from_str(instruction))
+        .collect::<Result<Vec<_>>>()?;
+
+        // Ensure the registers written match the destinations of each instruction.
+        let written = instructions.iter().flat_map(|instruction| instruction.destinations()).collect::<Vec<_>>();
+        let expected = ["r2", "r3", "r4", "r6", "r7", "r8"].map(|register| Register::from_str(register).unwrap());
+        assert_eq!(written, expected);
+
+        // Ensure the operands match the inputs of each instruction.
+        let operands = instructions.iter().map(|instruction| instruction.operands().len()).collect::<Vec<_>>();
+        assert_eq!(operands, [2, 2, 1, 3, 2, 2]);
+
+        // Ensure the registers read, but never written, are the function inputs.
+        let read = instructions
+            .iter()
+            .flat_map(|instruction| instruction.operands())
+            .filter_map(|operand| match operand {
+                Operand::Register(register) if !written.contains(register) => Some(register.clone()),
+                _ => None,
+            })
+            .collect::<IndexSet<_>>();
+        let expected = ["r0", "r1", "r5"].map(|register| Register::from_str(register).unwrap());
+        assert_eq!(read.into_iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_immediate_operands() -> Result<()> {
+        for (string, num_literals) in [
+            ("sub r0 1u8 into r1;", 1),
+            ("sub 1u8 r0 into r1;", 1),
+            ("add r0 2group into r1;", 1),
+            ("neg 2group into r1;", 1),
+            ("ternary r0 1u8 r2 into r3;", 1),
+            ("is.eq 1field 2field into r0;", 2),
+        ] {
+            let instruction = Instruction::<CurrentNetwork>::from_str(string)?;
+
+            // Ensure the immediate operands are parsed as literals.
+            let literals = instruction.operands().iter().filter(|operand| matches!(operand, Operand::Literal(..)));
+            assert_eq!(literals.count(), num_literals, "{string}");
+
+            // Ensure the display form round-trips through the parser.
+            assert_eq!(string, instruction.to_string());
+            assert_eq!(instruction, Instruction::from_str(&instruction.to_string())?);
+
+            // Ensure the byte form round-trips.
+            let bytes = instruction.to_bytes_le()?;
+            assert_eq!(instruction, Instruction::from_bytes_le(&bytes)?);
+        }
+        Ok(())
     }
 }
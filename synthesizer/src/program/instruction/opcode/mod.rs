@@ -21,6 +21,8 @@ use console::network::prelude::*;
 pub enum Opcode {
     /// The opcode is for a assert operation (i.e. `assert`).
     Assert(&'static str),
+    /// The opcode is for a byte array operation (i.e. `add.bytes`).
+    Bytes(&'static str),
     /// The opcode is for a call operation (i.e. `call`).
     Call,
     /// The opcode is for a cast operation (i.e. `cast`).
@@ -37,6 +39,14 @@ pub enum Opcode {
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a load operation (i.e. `load.proof_target`).
+    Load(&'static str),
+    /// The opcode is for a pack operation (i.e. `pack`).
+    Pack,
+    /// The opcode is for a signature operation (i.e. `sign.verify`).
+    Sign(&'static str),
+    /// The opcode is for an unpack operation (i.e. `unpack`).
+    Unpack,
 }
 
 impl Deref for Opcode {
@@ -46,6 +56,7 @@ impl Deref for Opcode {
     fn deref(&self) -> &Self::Target {
         match self {
             Opcode::Assert(opcode) => opcode,
+            Opcode::Bytes(opcode) => opcode,
             Opcode::Call => &"call",
             Opcode::Cast => &"cast",
             Opcode::Command(opcode) => opcode,
@@ -54,6 +65,10 @@ impl Deref for Opcode {
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Load(opcode) => opcode,
+            Opcode::Pack => &"pack",
+            Opcode::Sign(opcode) => opcode,
+            Opcode::Unpack => &"unpack",
         }
     }
 }
@@ -71,6 +86,7 @@ impl Display for Opcode {
         // write!(f, "{}", *self)
         match self {
             Self::Assert(opcode) => write!(f, "{opcode}"),
+            Self::Bytes(opcode) => write!(f, "{opcode}"),
             Self::Call => write!(f, "{}", self.deref()),
             Self::Cast => write!(f, "{}", self.deref()),
             Self::Command(opcode) => write!(f, "{opcode}"),
@@ -79,6 +95,10 @@ impl Display for Opcode {
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Load(opcode) => write!(f, "{opcode}"),
+            Self::Pack => write!(f, "{}", self.deref()),
+            Self::Sign(opcode) => write!(f, "{opcode}"),
+            Self::Unpack => write!(f, "{}", self.deref()),
         }
     }
 }
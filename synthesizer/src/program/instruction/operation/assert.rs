@@ -17,7 +17,7 @@
 use crate::{Opcode, Operand, Registers, Stack};
 use console::{
     network::prelude::*,
-    program::{Register, RegisterType},
+    program::{LiteralType, Register, RegisterType},
 };
 
 /// Asserts two operands are equal to each other.
@@ -123,6 +123,24 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         Ok(())
     }
 
+    /// Returns the circuit cost of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        _input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode())
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -160,11 +178,11 @@ impl<N: Network, const VARIANT: u8> Parser for AssertInstruction<N, VARIANT> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the first operand from the string.
-        let (string, first) = Operand::parse(string)?;
+        let (string, first) = context("operand", Operand::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the second operand from the string.
-        let (string, second) = Operand::parse(string)?;
+        let (string, second) = context("operand", Operand::parse)(string)?;
 
         Ok((string, Self { operands: vec![first, second] }))
     }
@@ -617,4 +635,63 @@ mod tests {
         assert_eq!(assert.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(assert.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
     }
+
+    #[test]
+    fn test_evaluate_message_and_registers() -> Result<()> {
+        let mut cache = Default::default();
+        let mode = circuit::Mode::Public;
+
+        let opcode = AssertEq::<CurrentNetwork>::opcode();
+        let (stack, operands) = sample_stack(opcode, LiteralType::U64, LiteralType::U64, mode, mode, &mut cache)?;
+        let (literal_a, literal_b) = (Literal::from_str("1u64")?, Literal::from_str("2u64")?);
+
+        // Ensure a failing assertion halts with a clear message.
+        let mut registers = sample_registers(&stack, &literal_a, &literal_b, None, None)?;
+        let assert_eq = AssertEq::<CurrentNetwork>::from_str("assert.eq r0 r1")?;
+        let error = assert_eq.evaluate(&stack, &mut registers).unwrap_err();
+        assert_eq!("'assert.eq' failed: '1u64' is not equal to '2u64' (should be equal)", error.to_string());
+        let assert_neq = AssertNeq::<CurrentNetwork>::from_str("assert.neq r0 r0")?;
+        let error = assert_neq.evaluate(&stack, &mut registers).unwrap_err();
+        assert_eq!("'assert.neq' failed: '1u64' is equal to '1u64' (should not be equal)", error.to_string());
+
+        // Ensure a passing assertion leaves the registers untouched.
+        let expected = operands.iter().map(|operand| registers.load(&stack, operand)).collect::<Result<Vec<_>>>()?;
+        AssertNeq::<CurrentNetwork>::from_str("assert.neq r0 r1")?.evaluate(&stack, &mut registers)?;
+        AssertEq::<CurrentNetwork>::from_str("assert.eq r1 r1")?.evaluate(&stack, &mut registers)?;
+        let candidate = operands.iter().map(|operand| registers.load(&stack, operand)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, candidate);
+
+        // Ensure the instructions round-trip through bytes.
+        for string in ["assert.eq r0 r1", "assert.neq r1 r0"] {
+            let expected = crate::Instruction::<CurrentNetwork>::from_str(&format!("{string};"))?;
+            assert_eq!(expected, crate::Instruction::from_bytes_le(&expected.to_bytes_le()?)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal_operands() -> Result<()> {
+        let mut cache = Default::default();
+        let mode = circuit::Mode::Public;
+
+        let opcode = AssertEq::<CurrentNetwork>::opcode();
+        let (stack, _) = sample_stack(opcode, LiteralType::Field, LiteralType::Field, mode, mode, &mut cache)?;
+        let literal = Literal::from_str("5field")?;
+
+        // Ensure an assertion on equal literals passes, in both evaluation and execution.
+        let assert_eq = AssertEq::<CurrentNetwork>::from_str("assert.eq 5field 5field")?;
+        let mut registers = sample_registers(&stack, &literal, &literal, None, None)?;
+        assert_eq.evaluate(&stack, &mut registers)?;
+        let mut registers = sample_registers(&stack, &literal, &literal, Some(mode), Some(mode))?;
+        assert_eq.execute::<CurrentAleo>(&stack, &mut registers)?;
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+
+        // Ensure an assertion on unequal literals halts.
+        let assert_eq = AssertEq::<CurrentNetwork>::from_str("assert.eq 5field 6field")?;
+        let mut registers = sample_registers(&stack, &literal, &literal, None, None)?;
+        let error = assert_eq.evaluate(&stack, &mut registers).unwrap_err();
+        assert_eq!("'assert.eq' failed: '5field' is not equal to '6field' (should be equal)", error.to_string());
+        Ok(())
+    }
 }
@@ -0,0 +1,612 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U8,
+};
+
+use indexmap::IndexMap;
+
+/// Adds the byte arrays `first` and `second` as little-endian integers, wrapping around at the boundary
+/// of the array, and storing the outcome in `destination`.
+pub type AddBytes<N> = BytesInstruction<N, { ByteOperation::Add as u8 }>;
+/// Performs a bitwise `xor` on the byte arrays `first` and `second`, storing the outcome in `destination`.
+pub type XorBytes<N> = BytesInstruction<N, { ByteOperation::Xor as u8 }>;
+
+enum ByteOperation {
+    Add,
+    Xor,
+}
+
+/// Operates element-wise on the byte arrays `first` and `second`, storing the outcome in `destination`.
+///
+/// A byte array is a struct whose members are all `u8`, with the least significant byte as the first member.
+/// Both operands must have the same number of bytes, and the outcome has the struct type of `first`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BytesInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> BytesInstruction<N, VARIANT> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Bytes("add.bytes"),
+            1 => Opcode::Bytes("xor.bytes"),
+            _ => panic!("Invalid 'bytes' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> BytesInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operands.
+        let first = registers.load(stack, &self.operands[0])?;
+        let second = registers.load(stack, &self.operands[1])?;
+        // Compute the byte array.
+        let output = Self::evaluate_bytes(&first, &second)?;
+        // Store the output.
+        registers.store(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Load the operands.
+        let first = registers.load_circuit(stack, &self.operands[0])?;
+        let second = registers.load_circuit(stack, &self.operands[1])?;
+        // Compute the byte array.
+        let output = Self::execute_bytes(&first, &second)?;
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Returns the byte array computed from the given byte arrays.
+    fn evaluate_bytes(first: &Value<N>, second: &Value<N>) -> Result<Value<N>> {
+        // Retrieve the members of the byte arrays.
+        let (first, second) = match (first, second) {
+            (Value::Plaintext(Plaintext::Struct(first, ..)), Value::Plaintext(Plaintext::Struct(second, ..))) => {
+                (first, second)
+            }
+            _ => bail!("Instruction '{}' expects two byte arrays", Self::opcode()),
+        };
+        // Ensure the byte arrays have the same length.
+        Self::ensure_same_length(first.len(), second.len())?;
+
+        // Retrieve the bytes of the given members.
+        let to_bytes = |members: &IndexMap<_, Plaintext<N>>| {
+            members
+                .values()
+                .map(|member| match member {
+                    Plaintext::Literal(Literal::U8(byte), ..) => Ok(**byte),
+                    _ => bail!("Instruction '{}' expects a byte array of 'u8' members", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        // Compute the bytes, in order from the least significant byte.
+        let mut carry = 0u16;
+        let bytes = to_bytes(first)?.into_iter().zip_eq(to_bytes(second)?).map(|(a, b)| match VARIANT {
+            0 => {
+                let sum = a as u16 + b as u16 + carry;
+                carry = sum >> 8;
+                sum as u8
+            }
+            1 => a ^ b,
+            _ => unreachable!("Invalid 'bytes' instruction opcode"),
+        });
+
+        // Output the bytes as members of the struct type of `first`.
+        let members = first
+            .keys()
+            .cloned()
+            .zip_eq(bytes)
+            .map(|(identifier, byte)| (identifier, Plaintext::from(Literal::U8(U8::new(byte)))))
+            .collect();
+        Ok(Value::Plaintext(Plaintext::Struct(members, Default::default())))
+    }
+
+    /// Returns the byte array computed from the given circuit byte arrays.
+    fn execute_bytes<A: circuit::Aleo<Network = N>>(
+        first: &circuit::Value<A>,
+        second: &circuit::Value<A>,
+    ) -> Result<circuit::Value<A>> {
+        use circuit::{FromBits, Inject, ToBits};
+
+        // Retrieve the members of the byte arrays.
+        let (first, second) = match (first, second) {
+            (
+                circuit::Value::Plaintext(circuit::Plaintext::Struct(first, ..)),
+                circuit::Value::Plaintext(circuit::Plaintext::Struct(second, ..)),
+            ) => (first, second),
+            _ => bail!("Instruction '{}' expects two byte arrays", Self::opcode()),
+        };
+        // Ensure the byte arrays have the same length.
+        Self::ensure_same_length(first.len(), second.len())?;
+
+        // Retrieve the bytes of the given members.
+        let to_bytes = |members: &IndexMap<_, circuit::Plaintext<A>>| {
+            members
+                .values()
+                .map(|member| match member {
+                    circuit::Plaintext::Literal(circuit::Literal::U8(byte), ..) => Ok(byte.clone()),
+                    _ => bail!("Instruction '{}' expects a byte array of 'u8' members", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        // Compute the bytes, in order from the least significant byte.
+        let mut carry = circuit::Boolean::constant(false);
+        let bytes = to_bytes(first)?.into_iter().zip_eq(to_bytes(second)?).map(|(a, b)| match VARIANT {
+            // Add the bits of the bytes with a ripple carry.
+            0 => {
+                let bits = a.to_bits_le().into_iter().zip_eq(b.to_bits_le()).map(|(a, b)| {
+                    let half_sum = &a ^ &b;
+                    let sum = &half_sum ^ &carry;
+                    carry = (&a & &b) | (&carry & &half_sum);
+                    sum
+                });
+                circuit::U8::from_bits_le(&bits.collect::<Vec<_>>())
+            }
+            1 => a ^ b,
+            _ => unreachable!("Invalid 'bytes' instruction opcode"),
+        });
+
+        // Output the bytes as members of the struct type of `first`.
+        let members = first
+            .keys()
+            .cloned()
+            .zip_eq(bytes)
+            .map(|(identifier, byte)| (identifier, circuit::Plaintext::from(circuit::Literal::U8(byte))))
+            .collect();
+        Ok(circuit::Value::Plaintext(circuit::Plaintext::Struct(members, Default::default())))
+    }
+
+    /// Ensures the byte arrays of the given lengths have the same length.
+    fn ensure_same_length(first: usize, second: usize) -> Result<()> {
+        ensure!(
+            first == second,
+            "Instruction '{}' expects byte arrays of the same length, found {first} and {second} bytes",
+            Self::opcode()
+        );
+        Ok(())
+    }
+
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output.
+    /// As the operands are byte arrays, the input types are the bytes of `first`, followed by the bytes of `second`,
+    /// so the cost scales with the length of the byte arrays. The output type and mode are those of the last byte.
+    ///
+    /// Note: The cost of constant bytes depends on their values, so only public and private bytes are supported.
+    /// Note: The circuit environment is reset.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        // Reset the circuit environment.
+        A::reset();
+        // Synthesize the operation on the sampled byte arrays.
+        let count = Self::count_bytes::<A>(input_types);
+        // Reset the circuit environment.
+        A::reset();
+        count
+    }
+
+    /// Returns the circuit cost of the operation on byte arrays of the given types and modes.
+    fn count_bytes<A: circuit::Aleo<Network = N>>(
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the input types are the bytes of two byte arrays of the same length.
+        ensure!(!input_types.is_empty(), "Instruction '{}' expects two nonempty byte arrays", Self::opcode());
+        Self::ensure_same_length(input_types.len() / 2, input_types.len() - input_types.len() / 2)?;
+        for (literal_type, mode) in input_types {
+            ensure!(*literal_type == LiteralType::U8, "Expected a byte array of 'u8' members, found '{literal_type}'");
+            ensure!(!mode.is_constant(), "Instruction '{}' does not support counting constant bytes", Self::opcode());
+        }
+
+        // Sample the byte arrays.
+        let (first, second) = input_types.split_at(input_types.len() / 2);
+        let sample = |input_types: &[(LiteralType, circuit::Mode)]| {
+            let members = input_types
+                .iter()
+                .enumerate()
+                .map(|(index, (_, mode))| {
+                    let identifier = console::program::Identifier::from_str(&format!("b{index}"))?;
+                    let byte = circuit::Literal::new(*mode, Literal::U8(U8::new(1)));
+                    Ok((circuit::Identifier::constant(identifier), byte.into()))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?;
+            Ok::<_, Error>(circuit::Value::Plaintext(circuit::Plaintext::Struct(members, Default::default())))
+        };
+        let (first, second) = (sample(first)?, sample(second)?);
+
+        // Synthesize the operation, measuring the number of variables and constraints it introduces.
+        let (num_constants, num_public, num_private, num_constraints, _) = A::count();
+        let output = Self::execute_bytes::<A>(&first, &second)?;
+        let (constants, public, private, constraints, _) = A::count();
+
+        let count = circuit::Count::is(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints,
+        );
+        let mode = match output {
+            circuit::Value::Plaintext(circuit::Plaintext::Struct(members, ..)) => match members.values().last() {
+                Some(circuit::Plaintext::Literal(byte, ..)) => byte.eject_mode(),
+                _ => bail!("Instruction '{}' did not output a byte array", Self::opcode()),
+            },
+            _ => bail!("Instruction '{}' did not output a byte array", Self::opcode()),
+        };
+        Ok((count, LiteralType::U8, mode))
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the length of each byte array, ensuring its struct is defined and has only 'u8' members.
+        let lengths = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                    let struct_ = stack.program().get_struct(struct_name)?;
+                    let is_bytes = struct_
+                        .members()
+                        .values()
+                        .all(|member_type| *member_type == PlaintextType::Literal(LiteralType::U8));
+                    ensure!(
+                        is_bytes,
+                        "Instruction '{}' expects '{struct_name}' to have only 'u8' members",
+                        Self::opcode()
+                    );
+                    Ok(struct_.members().len())
+                }
+                _ => bail!("Instruction '{}' expects a byte array, found '{input_type}'", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the byte arrays have the same length.
+        Self::ensure_same_length(lengths[0], lengths[1])?;
+
+        // Output the struct type of `first`.
+        Ok(vec![input_types[0]])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for BytesInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = context("operand", Operand::parse)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = context("operand", Operand::parse)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = context("'into'", tag("into"))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = context("register", Register::parse)(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for BytesInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for BytesInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for BytesInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            eprintln!("The number of operands must be 2, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for BytesInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for BytesInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::{Eject, Environment, Inject};
+    use console::{
+        network::Testnet3,
+        program::Identifier,
+        types::{Address, Field},
+    };
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a byte array of the given bytes, from the least significant byte.
+    fn to_value(bytes: &[u8]) -> Result<Value<CurrentNetwork>> {
+        let members = bytes.iter().enumerate().map(|(index, byte)| format!("b{index}: {byte}u8")).join(", ");
+        Value::from_str(&format!("{{ {members} }}"))
+    }
+
+    /// Evaluates `opcode` on the given byte arrays, where `r0` and `r1` have the struct types `first` and `second`.
+    fn evaluate(opcode: &str, first: (&str, &[u8]), second: (&str, &[u8])) -> Result<Value<CurrentNetwork>> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = Program::from_str(&format!(
+            r"program testing.aleo;
+struct bytes2:
+    b0 as u8;
+    b1 as u8;
+struct bytes4:
+    b0 as u8;
+    b1 as u8;
+    b2 as u8;
+    b3 as u8;
+closure run:
+    input r0 as {};
+    input r1 as {};
+    {opcode} r0 r1 into r2;
+    output r2 as {};",
+            first.0, second.0, first.0
+        ))?;
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+        let closure = program.get_closure(&Identifier::from_str("run")?)?;
+
+        // Evaluate the closure.
+        let outputs = stack.evaluate_closure::<CurrentAleo>(
+            &closure,
+            &[to_value(first.1)?, to_value(second.1)?],
+            CallStack::evaluate(Authorization::new(&[]))?,
+            Address::new(Uniform::rand(&mut rng)),
+            Field::rand(&mut rng),
+        )?;
+        Ok(outputs[0].clone())
+    }
+
+    /// Checks that the circuit output of the given byte arrays matches the console output in every mode.
+    fn check_execute<const VARIANT: u8>(first: &[u8], second: &[u8]) -> Result<()> {
+        let (first, second) = (to_value(first)?, to_value(second)?);
+        let expected = BytesInstruction::<CurrentNetwork, VARIANT>::evaluate_bytes(&first, &second)?;
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let candidate = BytesInstruction::<CurrentNetwork, VARIANT>::execute_bytes::<CurrentAleo>(
+                &circuit::Value::new(mode, first.clone()),
+                &circuit::Value::new(mode, second.clone()),
+            )?;
+            assert_eq!(expected, candidate.eject_value(), "{first} {second} ({mode})");
+            assert!(CurrentAleo::is_satisfied(), "'{first} {second}' ({mode}) is unsatisfied");
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_bytes() -> Result<()> {
+        // Ensure the carry propagates into the next byte.
+        let output = evaluate("add.bytes", ("bytes4", &[255, 255, 1, 0]), ("bytes4", &[1, 0, 2, 255]))?;
+        assert_eq!(to_value(&[0, 0, 4, 255])?, output);
+        // Ensure the carry out of the last byte wraps around.
+        let output = evaluate("add.bytes", ("bytes2", &[255, 255]), ("bytes2", &[1, 0]))?;
+        assert_eq!(to_value(&[0, 0])?, output);
+
+        // Ensure the circuit matches.
+        check_execute::<0>(&[255, 255, 1, 0], &[1, 0, 2, 255])?;
+        check_execute::<0>(&[255, 255], &[1, 0])?;
+        check_execute::<0>(&[127, 128, 3], &[129, 127, 250])
+    }
+
+    #[test]
+    fn test_xor_bytes() -> Result<()> {
+        // Ensure each byte is xored without a carry.
+        let output = evaluate("xor.bytes", ("bytes4", &[255, 255, 1, 0]), ("bytes4", &[1, 0, 3, 255]))?;
+        assert_eq!(to_value(&[254, 255, 2, 255])?, output);
+
+        // Ensure the circuit matches.
+        check_execute::<1>(&[255, 255, 1, 0], &[1, 0, 3, 255])?;
+        check_execute::<1>(&[127, 128, 3], &[129, 127, 250])
+    }
+
+    #[test]
+    fn test_length_mismatch() -> Result<()> {
+        // Ensure byte arrays of different lengths are rejected at validation.
+        for opcode in ["add.bytes", "xor.bytes"] {
+            let error = evaluate(opcode, ("bytes2", &[1, 2]), ("bytes4", &[1, 2, 3, 4])).unwrap_err();
+            assert!(error.to_string().contains("byte arrays of the same length"), "{error}");
+        }
+
+        // Ensure byte arrays of different lengths halt with an error when evaluated and executed.
+        let (first, second) = (to_value(&[1, 2])?, to_value(&[1, 2, 3])?);
+        assert!(AddBytes::<CurrentNetwork>::evaluate_bytes(&first, &second).is_err());
+        assert!(XorBytes::<CurrentNetwork>::evaluate_bytes(&first, &second).is_err());
+        let first = circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, first);
+        let second = circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, second);
+        assert!(AddBytes::<CurrentNetwork>::execute_bytes(&first, &second).is_err());
+        CurrentAleo::reset();
+
+        // Ensure byte arrays with members other than 'u8' are rejected.
+        let (first, second) = (Value::from_str("{ b0: 1u16 }")?, to_value(&[1])?);
+        assert!(AddBytes::<CurrentNetwork>::evaluate_bytes(&first, &second).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_count() -> Result<()> {
+        let instruction = AddBytes::<CurrentNetwork>::from_str("add.bytes r0 r1 into r2")?;
+
+        // Ensure the count scales with the length of the byte arrays.
+        let mut previous = 0;
+        for length in [1, 2, 4] {
+            let input_types = vec![(LiteralType::U8, circuit::Mode::Private); 2 * length];
+            let (count, output_type, mode) = instruction.count::<CurrentAleo>(&input_types)?;
+            assert_eq!(LiteralType::U8, output_type);
+            assert_eq!(circuit::Mode::Private, mode);
+            let circuit::Measurement::Exact(constraints) = count.3 else { panic!("The count must be exact") };
+            assert!(constraints > previous, "The count must grow with the length ({length} bytes)");
+            previous = constraints;
+        }
+
+        // Ensure mismatched, non-byte, and constant input types are rejected.
+        let input_types = vec![(LiteralType::U8, circuit::Mode::Private); 3];
+        assert!(instruction.count::<CurrentAleo>(&input_types).is_err());
+        let input_types = vec![(LiteralType::U16, circuit::Mode::Private); 2];
+        assert!(instruction.count::<CurrentAleo>(&input_types).is_err());
+        let input_types = vec![(LiteralType::U8, circuit::Mode::Constant); 2];
+        assert!(instruction.count::<CurrentAleo>(&input_types).is_err());
+        CurrentAleo::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_bytes() -> Result<()> {
+        for string in ["add.bytes r0 r1 into r2", "xor.bytes r0 r1 into r2"] {
+            // Check the string representation.
+            let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(string, instruction.to_string().trim_end_matches(';'));
+
+            // Check the byte representation.
+            let bytes = instruction.to_bytes_le()?;
+            assert_eq!(instruction, crate::Instruction::from_bytes_le(&bytes)?);
+        }
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@
 use crate::{CallStack, Opcode, Operand, Registers, Stack};
 use console::{
     network::prelude::*,
-    program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
+    program::{Identifier, LiteralType, Locator, Register, RegisterType, Request, ValueType},
 };
 
 /// The operator references a function name or closure name.
@@ -442,6 +442,24 @@ impl<N: Network> Call<N> {
         Ok(())
     }
 
+    /// Returns the circuit cost of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        _input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode())
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -473,6 +491,10 @@ impl<N: Network> Call<N> {
             if closure.inputs().len() != input_types.len() {
                 bail!("Expected {} input types, found {}", closure.inputs().len(), input_types.len())
             }
+            // Ensure the plaintext input types match the input statements.
+            for (input, input_type) in closure.inputs().iter().zip_eq(input_types) {
+                Self::check_input_type(input.register_type(), input_type)?;
+            }
             // Ensure the number of destinations matches the number of output statements.
             if closure.outputs().len() != self.destinations.len() {
                 bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
@@ -490,6 +512,10 @@ impl<N: Network> Call<N> {
             if function.inputs().len() != input_types.len() {
                 bail!("Expected {} input types, found {}", function.inputs().len(), input_types.len())
             }
+            // Ensure the plaintext input types match the input statements.
+            for (input, input_type) in function.inputs().iter().zip_eq(input_types) {
+                Self::check_input_type(&RegisterType::from(*input.value_type()), input_type)?;
+            }
             // Ensure the number of destinations matches the number of output statements.
             if function.outputs().len() != self.destinations.len() {
                 bail!("Expected {} outputs, found {}", function.outputs().len(), self.destinations.len())
@@ -513,6 +539,17 @@ impl<N: Network> Call<N> {
             bail!("Call operator '{}' is invalid or unsupported.", self.operator)
         }
     }
+
+    /// Ensures the given input type matches the expected input type, if the expected input type is a plaintext.
+    /// Note: Record input types are not compared, as a record of an external program is typed by its locator.
+    fn check_input_type(expected: &RegisterType<N>, input_type: &RegisterType<N>) -> Result<()> {
+        match expected {
+            RegisterType::Plaintext(..) if expected != input_type => {
+                bail!("Expected an input of type '{expected}', found '{input_type}'")
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<N: Network> Parser for Call<N> {
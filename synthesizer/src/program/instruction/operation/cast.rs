@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{sample_operand, Opcode, Operand, Registers, Stack};
 use console::{
     network::prelude::*,
     program::{
@@ -32,7 +32,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::Field,
+    types::{Address, Field, Group},
 };
 
 use indexmap::IndexMap;
@@ -86,13 +86,33 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Cast the literal.
+                let output = Self::cast_literal(literal, literal_type)?;
+                // Store the literal.
+                registers.store_literal(stack, &self.destination, output)
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
 
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
+                // Ensure there is one operand for each struct member.
+                ensure!(
+                    inputs.len() == struct_.members().len(),
+                    "Casting to '{}' requires {} operands, found {} operands",
+                    struct_.name(),
+                    struct_.members().len(),
+                    inputs.len()
+                );
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
@@ -212,13 +232,33 @@ impl<N: Network> Cast<N> {
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Cast the literal.
+                let output = Self::cast_literal_circuit::<A>(literal, literal_type)?;
+                // Store the literal.
+                registers.store_literal_circuit(stack, &self.destination, output)
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
 
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_)?;
+                // Ensure there is one operand for each struct member.
+                ensure!(
+                    inputs.len() == struct_.members().len(),
+                    "Casting to '{}' requires {} operands, found {} operands",
+                    struct_.name(),
+                    struct_.members().len(),
+                    inputs.len()
+                );
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
@@ -341,6 +381,56 @@ impl<N: Network> Cast<N> {
         }
     }
 
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output,
+    /// for operands of the given types and modes. This is only supported for casts into a literal.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the instruction casts into a literal.
+        let literal_type = match self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => literal_type,
+            _ => bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode()),
+        };
+        // Ensure the number of operands is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), input_types.len())
+        }
+
+        // Sample the operand. Note: A field is sampled as zero, as it is a valid x-coordinate when cast into a group.
+        let (input_type, mode) = input_types[0];
+        let literal = match input_type {
+            LiteralType::Field => Literal::Field(Field::zero()),
+            _ => sample_operand::<N>(input_type)?,
+        };
+        let literal = circuit::Literal::new(mode, literal);
+
+        // Synthesize the cast, measuring the number of variables and constraints it introduces.
+        let (num_constants, num_public, num_private, num_constraints, _) = A::count();
+        let output = Self::cast_literal_circuit::<A>(&literal, literal_type)?;
+        let (constants, public, private, constraints, _) = A::count();
+
+        let count = circuit::Count::is(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints,
+        );
+        Ok((count, literal_type, output.eject_mode()))
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -355,7 +445,18 @@ impl<N: Network> Cast<N> {
 
         // Ensure the output type is defined in the program.
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one input type.
+                ensure!(input_types.len() == 1, "Casting to a literal requires exactly one operand");
+                // Ensure the input type is a literal that can be cast into the literal type.
+                match input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Literal(input_type)) => ensure!(
+                        Self::is_literal_cast_supported(input_type, literal_type),
+                        "Casting a '{input_type}' to a '{literal_type}' is unsupported"
+                    ),
+                    input_type => bail!("Casting to a literal requires a literal operand, found '{input_type}'"),
+                }
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
@@ -431,6 +532,140 @@ impl<N: Network> Cast<N> {
     }
 }
 
+impl<N: Network> Cast<N> {
+    /// Returns the number of bits and the signedness of the given literal type, if it is an integer type.
+    fn integer_properties(literal_type: LiteralType) -> Option<(usize, bool)> {
+        match literal_type {
+            LiteralType::I8 => Some((8, true)),
+            LiteralType::I16 => Some((16, true)),
+            LiteralType::I32 => Some((32, true)),
+            LiteralType::I64 => Some((64, true)),
+            LiteralType::I128 => Some((128, true)),
+            LiteralType::U8 => Some((8, false)),
+            LiteralType::U16 => Some((16, false)),
+            LiteralType::U32 => Some((32, false)),
+            LiteralType::U64 => Some((64, false)),
+            LiteralType::U128 => Some((128, false)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a literal of type `from` can be cast into a literal of type `to`.
+    /// The supported casts are between integer types, from integers to fields, from fields to groups,
+    /// and from groups to addresses.
+    fn is_literal_cast_supported(from: LiteralType, to: LiteralType) -> bool {
+        match (Self::integer_properties(from), Self::integer_properties(to)) {
+            (Some(_), Some(_)) => true,
+            (Some(_), None) => to == LiteralType::Field,
+            (None, _) => {
+                from == to
+                    || (from == LiteralType::Field && to == LiteralType::Group)
+                    || (from == LiteralType::Group && to == LiteralType::Address)
+            }
+        }
+    }
+
+    /// Casts the given literal into the given literal type.
+    /// Integer casts halt if the value does not fit in the destination type.
+    fn cast_literal(literal: &Literal<N>, literal_type: LiteralType) -> Result<Literal<N>> {
+        let from = literal.to_type();
+        ensure!(
+            Self::is_literal_cast_supported(from, literal_type),
+            "Casting a '{from}' to a '{literal_type}' is unsupported"
+        );
+
+        match (Self::integer_properties(from), Self::integer_properties(literal_type)) {
+            // Cast between integer types.
+            (Some((from_bits, from_signed)), Some((to_bits, to_signed))) => {
+                // Extend the bits with the sign bit, to cover both the source and the destination.
+                let mut bits_le = literal.to_bits_le();
+                let extension = from_signed && bits_le[from_bits - 1];
+                bits_le.resize(from_bits.max(to_bits) + 1, extension);
+                // Ensure the truncated bits are an extension of the destination sign bit, so the value fits.
+                let fill = to_signed && bits_le[to_bits - 1];
+                ensure!(
+                    bits_le[to_bits..].iter().all(|bit| *bit == fill),
+                    "Failed to cast '{literal}' to a '{literal_type}': the value is out of range"
+                );
+                Literal::from_bits_le(literal_type as u8, &bits_le[..to_bits])
+            }
+            // Cast a non-negative integer to a field element.
+            (Some((from_bits, from_signed)), None) => {
+                let bits_le = literal.to_bits_le();
+                ensure!(
+                    !(from_signed && bits_le[from_bits - 1]),
+                    "Failed to cast '{literal}' to a '{literal_type}': the value is negative"
+                );
+                Ok(Literal::Field(Field::from_bits_le(&bits_le)?))
+            }
+            (None, _) => match (literal, literal_type) {
+                // Cast a field element into the group element with the given x-coordinate.
+                (Literal::Field(field), LiteralType::Group) => Ok(Literal::Group(Group::from_x_coordinate(*field)?)),
+                // Cast a group element into the address it represents.
+                (Literal::Group(group), LiteralType::Address) => Ok(Literal::Address(Address::new(*group))),
+                // Cast a literal into its own type.
+                _ => Ok(literal.clone()),
+            },
+        }
+    }
+
+    /// Casts the given circuit literal into the given literal type.
+    /// Integer casts are constrained to values that fit in the destination type.
+    fn cast_literal_circuit<A: circuit::Aleo<Network = N>>(
+        literal: &circuit::Literal<A>,
+        literal_type: LiteralType,
+    ) -> Result<circuit::Literal<A>> {
+        use circuit::{Equal, FromBits, FromGroup, Inject, ToBits};
+
+        let from = literal.to_type();
+        ensure!(
+            Self::is_literal_cast_supported(from, literal_type),
+            "Casting a '{from}' to a '{literal_type}' is unsupported"
+        );
+
+        match (Self::integer_properties(from), Self::integer_properties(literal_type)) {
+            // Cast between integer types.
+            (Some((from_bits, from_signed)), Some((to_bits, to_signed))) => {
+                // Extend the bits with the sign bit, to cover both the source and the destination.
+                let mut bits_le = literal.to_bits_le();
+                let extension = match from_signed {
+                    true => bits_le[from_bits - 1].clone(),
+                    false => circuit::Boolean::constant(false),
+                };
+                bits_le.resize(from_bits.max(to_bits) + 1, extension);
+                // Ensure the truncated bits are an extension of the destination sign bit, so the value fits.
+                let fill = match to_signed {
+                    true => bits_le[to_bits - 1].clone(),
+                    false => circuit::Boolean::constant(false),
+                };
+                bits_le[to_bits..].iter().for_each(|bit| A::assert(bit.is_equal(&fill)));
+                let variant = circuit::U8::constant(console::types::U8::new(literal_type as u8));
+                Ok(circuit::Literal::from_bits_le(&variant, &bits_le[..to_bits]))
+            }
+            // Cast a non-negative integer to a field element.
+            (Some((from_bits, from_signed)), None) => {
+                let bits_le = literal.to_bits_le();
+                if from_signed {
+                    A::assert(!&bits_le[from_bits - 1]);
+                }
+                Ok(circuit::Literal::Field(circuit::Field::from_bits_le(&bits_le)))
+            }
+            (None, _) => match (literal, literal_type) {
+                // Cast a field element into the group element with the given x-coordinate.
+                (circuit::Literal::Field(field), LiteralType::Group) => {
+                    Ok(circuit::Literal::Group(circuit::Group::from_x_coordinate(field.clone())))
+                }
+                // Cast a group element into the address it represents.
+                (circuit::Literal::Group(group), LiteralType::Address) => {
+                    Ok(circuit::Literal::Address(circuit::Address::from_group(group.clone())))
+                }
+                // Cast a literal into its own type.
+                _ => Ok(literal.clone()),
+            },
+        }
+    }
+}
+
 impl<N: Network> Parser for Cast<N> {
     /// Parses a string into an operation.
     #[inline]
@@ -450,11 +685,11 @@ impl<N: Network> Parser for Cast<N> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "into" from the string.
-        let (string, _) = tag("into")(string)?;
+        let (string, _) = context("'into'", tag("into"))(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        let (string, destination) = context("register", Register::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "as" from the string.
@@ -694,4 +929,134 @@ mod tests {
         string.push_str(&format!("into r{} as foo", CurrentNetwork::MAX_DATA_ENTRIES + 1));
         assert!(Cast::<CurrentNetwork>::parse(&string).is_err(), "Parser did not error");
     }
+
+    /// Checks that casting the literal into the literal type yields the expected output in every mode, or halts.
+    fn check_cast_literal(input: &str, literal_type: LiteralType, expected: Option<&str>) -> Result<()> {
+        use circuit::{Eject, Environment};
+
+        type CurrentAleo = circuit::AleoV0;
+
+        // Ensure the evaluated output matches.
+        let literal = Literal::<CurrentNetwork>::from_str(input)?;
+        let candidate = Cast::cast_literal(&literal, literal_type);
+        match expected {
+            Some(expected) => assert_eq!(Literal::from_str(expected)?, candidate?, "cast {input} as {literal_type}"),
+            None => assert!(candidate.is_err(), "'cast {input} as {literal_type}' did not halt (console)"),
+        }
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            // Ensure the executed output matches, or that the circuit halts or is unsatisfied.
+            let literal = circuit::Literal::<CurrentAleo>::from_str(&format!("{input}.{mode}"))?;
+            let candidate = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                Cast::<CurrentNetwork>::cast_literal_circuit::<CurrentAleo>(&literal, literal_type)
+            }));
+            match (expected, candidate) {
+                (Some(expected), Ok(Ok(candidate))) => {
+                    assert_eq!(Literal::from_str(expected)?, candidate.eject_value(), "cast {input}.{mode}");
+                    assert!(CurrentAleo::is_satisfied(), "'cast {input}.{mode} as {literal_type}' is unsatisfied");
+                }
+                (Some(_), _) => panic!("'cast {input}.{mode} as {literal_type}' failed (circuit)"),
+                (None, Ok(Ok(_))) => {
+                    assert!(!CurrentAleo::is_satisfied(), "'cast {input}.{mode} as {literal_type}' did not halt")
+                }
+                (None, _) => (),
+            }
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_literal() -> Result<()> {
+        let u128_max = format!("{}u128", u128::MAX);
+
+        // Ensure widening casts are lossless.
+        check_cast_literal("255u8", LiteralType::U64, Some("255u64"))?;
+        check_cast_literal("-1i8", LiteralType::I64, Some("-1i64"))?;
+        check_cast_literal("-128i8", LiteralType::I128, Some("-128i128"))?;
+        check_cast_literal("255u8", LiteralType::I16, Some("255i16"))?;
+        check_cast_literal("5u64", LiteralType::Field, Some("5field"))?;
+        check_cast_literal(&u128_max, LiteralType::Field, Some(&format!("{}field", u128::MAX)))?;
+
+        // Ensure narrowing casts succeed when the value fits.
+        check_cast_literal("255u16", LiteralType::U8, Some("255u8"))?;
+        check_cast_literal("127u8", LiteralType::I8, Some("127i8"))?;
+        check_cast_literal("-128i16", LiteralType::I8, Some("-128i8"))?;
+        check_cast_literal("5i64", LiteralType::Field, Some("5field"))?;
+
+        // Ensure narrowing casts halt when the value does not fit.
+        check_cast_literal("256u16", LiteralType::U8, None)?;
+        check_cast_literal("128u8", LiteralType::I8, None)?;
+        check_cast_literal("-1i8", LiteralType::U128, None)?;
+        check_cast_literal("-129i16", LiteralType::I8, None)?;
+        check_cast_literal(&u128_max, LiteralType::I128, None)?;
+        check_cast_literal("-5i64", LiteralType::Field, None)?;
+
+        // Ensure fields cast into groups by x-coordinate, and literals cast into their own type.
+        check_cast_literal("0field", LiteralType::Group, Some("0group"))?;
+        check_cast_literal("1field", LiteralType::Field, Some("1field"))?;
+        check_cast_literal("true", LiteralType::Boolean, Some("true"))?;
+
+        // Ensure unsupported pairs halt.
+        check_cast_literal("1field", LiteralType::U8, None)?;
+        check_cast_literal("true", LiteralType::U8, None)?;
+        check_cast_literal("\"hello\"", LiteralType::Field, None)?;
+        check_cast_literal("1scalar", LiteralType::Field, None)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_group_into_address() -> Result<()> {
+        use circuit::{Environment, Metrics, Mode};
+
+        type CurrentAleo = circuit::AleoV0;
+        type FromGroup = dyn circuit::FromGroup<
+            Group = circuit::Group<CurrentAleo>,
+            Scalar = circuit::Scalar<CurrentAleo>,
+        >;
+
+        // Ensure the group underlying a known address casts back into the address.
+        let mut rng = TestRng::default();
+        let private_key = console::account::PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = console::account::Address::try_from(private_key)?;
+        let group = *address;
+        check_cast_literal(&group.to_string(), LiteralType::Address, Some(&address.to_string()))?;
+        let zero_address = Address::<CurrentNetwork>::new(Group::zero());
+        check_cast_literal("0group", LiteralType::Address, Some(&zero_address.to_string()))?;
+
+        // Ensure non-group literals do not cast into an address.
+        check_cast_literal("0field", LiteralType::Address, None)?;
+        check_cast_literal("1scalar", LiteralType::Address, None)?;
+        check_cast_literal("1u64", LiteralType::Address, None)?;
+
+        // Ensure the instruction cost matches the circuit metrics, and the output mode is the input mode.
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("cast r0 into r1 as address;")?;
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let (candidate, output_type, output_mode) =
+                instruction.count::<CurrentAleo>(&[(LiteralType::Group, mode)])?;
+            let expected = <circuit::Address<CurrentAleo> as Metrics<FromGroup>>::count(&());
+            assert_eq!(LiteralType::Address, output_type);
+            assert_eq!(mode, output_mode);
+            assert_eq!(expected.0, candidate.0);
+            assert_eq!(expected.1, candidate.1);
+            assert_eq!(expected.2, candidate.2);
+            assert_eq!(expected.3, candidate.3);
+            CurrentAleo::reset();
+        }
+        assert!(instruction.count::<CurrentAleo>(&[(LiteralType::Field, Mode::Private)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_cast_into_literal() -> Result<()> {
+        let cast = Cast::<CurrentNetwork>::from_str("cast r0 into r1 as u64")?;
+        assert_eq!(cast.operands(), &[Operand::Register(Register::Locator(0))]);
+        assert_eq!(cast.destinations(), vec![Register::Locator(1)]);
+        assert_eq!(cast.register_type(), &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U64)));
+        assert_eq!("cast r0 into r1 as u64", cast.to_string());
+
+        // Ensure the literal type round-trips through bytes.
+        assert_eq!(cast, Cast::from_bytes_le(&cast.to_bytes_le()?)?);
+        Ok(())
+    }
 }
@@ -99,22 +99,8 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         // Retrieve the input and randomizer.
         let input = registers.load(stack, &self.operands[0])?;
         let randomizer = registers.load(stack, &self.operands[1])?;
-        // Retrieve the randomizer.
-        let randomizer = match randomizer {
-            Value::Plaintext(Plaintext::Literal(Literal::Scalar(randomizer), ..)) => randomizer,
-            _ => bail!("Invalid randomizer type for the commit evaluation, expected a scalar"),
-        };
-
         // Commit the input.
-        let output = match VARIANT {
-            0 => Literal::Field(N::commit_bhp256(&input.to_bits_le(), &randomizer)?),
-            1 => Literal::Field(N::commit_bhp512(&input.to_bits_le(), &randomizer)?),
-            2 => Literal::Field(N::commit_bhp768(&input.to_bits_le(), &randomizer)?),
-            3 => Literal::Field(N::commit_bhp1024(&input.to_bits_le(), &randomizer)?),
-            4 => Literal::Group(N::commit_ped64(&input.to_bits_le(), &randomizer)?),
-            5 => Literal::Group(N::commit_ped128(&input.to_bits_le(), &randomizer)?),
-            _ => bail!("Invalid 'commit' variant: {VARIANT}"),
-        };
+        let output = Self::commit(&input, &randomizer)?;
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
@@ -126,8 +112,6 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        use circuit::ToBits;
-
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
             bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
@@ -136,30 +120,32 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         // Retrieve the input and randomizer.
         let input = registers.load_circuit(stack, &self.operands[0])?;
         let randomizer = registers.load_circuit(stack, &self.operands[1])?;
-        // Retrieve the randomizer.
-        let randomizer = match randomizer {
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(randomizer), ..)) => {
-                randomizer
-            }
-            _ => bail!("Invalid randomizer type for the commit execution, expected a scalar"),
-        };
-
-        // Commits the input.
-        let output = match VARIANT {
-            0 => circuit::Literal::Field(A::commit_bhp256(&input.to_bits_le(), &randomizer)),
-            1 => circuit::Literal::Field(A::commit_bhp512(&input.to_bits_le(), &randomizer)),
-            2 => circuit::Literal::Field(A::commit_bhp768(&input.to_bits_le(), &randomizer)),
-            3 => circuit::Literal::Field(A::commit_bhp1024(&input.to_bits_le(), &randomizer)),
-            4 => circuit::Literal::Group(A::commit_ped64(&input.to_bits_le(), &randomizer)),
-            5 => circuit::Literal::Group(A::commit_ped128(&input.to_bits_le(), &randomizer)),
-            _ => bail!("Invalid 'commit' variant: {VARIANT}"),
-        };
+        // Commit the input.
+        let output = Self::commit_circuit(&input, &randomizer)?;
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
 
+    /// Returns the circuit cost of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        _input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode())
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -172,6 +158,11 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
         }
 
+        // Ensure the randomizer is a scalar.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) {
+            bail!("Instruction '{}' expects the randomizer to be a scalar, found '{}'", Self::opcode(), input_types[1])
+        }
+
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
@@ -180,6 +171,76 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         }
     }
+
+    /// Returns the maximum number of input bits for the committer, if it is bounded.
+    const fn capacity_in_bits() -> Option<usize> {
+        match VARIANT {
+            4 => Some(64),
+            5 => Some(128),
+            _ => None,
+        }
+    }
+
+    /// Ensures the given number of input bits is within the capacity of the committer.
+    fn check_capacity(num_bits: usize) -> Result<()> {
+        match Self::capacity_in_bits() {
+            Some(capacity) if num_bits > capacity => {
+                bail!("Instruction '{}' supports up to {capacity} input bits, found {num_bits} bits", Self::opcode())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the commitment of the given input and randomizer.
+    fn commit(input: &Value<N>, randomizer: &Value<N>) -> Result<Literal<N>> {
+        // Retrieve the randomizer.
+        let randomizer = match randomizer {
+            Value::Plaintext(Plaintext::Literal(Literal::Scalar(randomizer), ..)) => randomizer,
+            _ => bail!("Invalid randomizer type for the commit evaluation, expected a scalar"),
+        };
+        // Retrieve the input bits, ensuring they are within the capacity of the committer.
+        let input = input.to_bits_le();
+        Self::check_capacity(input.len())?;
+
+        match VARIANT {
+            0 => Ok(Literal::Field(N::commit_bhp256(&input, randomizer)?)),
+            1 => Ok(Literal::Field(N::commit_bhp512(&input, randomizer)?)),
+            2 => Ok(Literal::Field(N::commit_bhp768(&input, randomizer)?)),
+            3 => Ok(Literal::Field(N::commit_bhp1024(&input, randomizer)?)),
+            4 => Ok(Literal::Group(N::commit_ped64(&input, randomizer)?)),
+            5 => Ok(Literal::Group(N::commit_ped128(&input, randomizer)?)),
+            _ => bail!("Invalid 'commit' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the commitment of the given circuit input and randomizer.
+    fn commit_circuit<A: circuit::Aleo<Network = N>>(
+        input: &circuit::Value<A>,
+        randomizer: &circuit::Value<A>,
+    ) -> Result<circuit::Literal<A>> {
+        use circuit::ToBits;
+
+        // Retrieve the randomizer.
+        let randomizer = match randomizer {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(randomizer), ..)) => {
+                randomizer
+            }
+            _ => bail!("Invalid randomizer type for the commit execution, expected a scalar"),
+        };
+        // Retrieve the input bits, ensuring they are within the capacity of the committer.
+        let input = input.to_bits_le();
+        Self::check_capacity(input.len())?;
+
+        match VARIANT {
+            0 => Ok(circuit::Literal::Field(A::commit_bhp256(&input, randomizer))),
+            1 => Ok(circuit::Literal::Field(A::commit_bhp512(&input, randomizer))),
+            2 => Ok(circuit::Literal::Field(A::commit_bhp768(&input, randomizer))),
+            3 => Ok(circuit::Literal::Field(A::commit_bhp1024(&input, randomizer))),
+            4 => Ok(circuit::Literal::Group(A::commit_ped64(&input, randomizer))),
+            5 => Ok(circuit::Literal::Group(A::commit_ped128(&input, randomizer))),
+            _ => bail!("Invalid 'commit' variant: {VARIANT}"),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
@@ -191,19 +252,19 @@ impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the first operand from the string.
-        let (string, first) = Operand::parse(string)?;
+        let (string, first) = context("operand", Operand::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the second operand from the string.
-        let (string, second) = Operand::parse(string)?;
+        let (string, second) = context("operand", Operand::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "into" from the string.
-        let (string, _) = tag("into")(string)?;
+        let (string, _) = context("'into'", tag("into"))(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        let (string, destination) = context("register", Register::parse)(string)?;
 
         Ok((string, Self { operands: vec![first, second], destination }))
     }
@@ -296,4 +357,117 @@ mod tests {
         assert_eq!(commit.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
     }
+
+    /// Checks that the console and circuit commitments of the given input match in every mode.
+    fn check_commit<const VARIANT: u8>(input: &str, randomizer: &str) -> Result<()> {
+        use circuit::{Eject, Environment, Inject};
+
+        type CurrentAleo = circuit::AleoV0;
+
+        let opcode = CommitInstruction::<CurrentNetwork, VARIANT>::opcode();
+
+        // Compute the expected output.
+        let input = Value::<CurrentNetwork>::from_str(input)?;
+        let randomizer = Value::<CurrentNetwork>::from_str(randomizer)?;
+        let expected = CommitInstruction::<CurrentNetwork, VARIANT>::commit(&input, &randomizer)?;
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            // Ensure the circuit output matches, and the circuit is satisfied.
+            let circuit_input = circuit::Value::<CurrentAleo>::new(mode, input.clone());
+            let circuit_randomizer = circuit::Value::<CurrentAleo>::new(mode, randomizer.clone());
+            let candidate =
+                CommitInstruction::<CurrentNetwork, VARIANT>::commit_circuit(&circuit_input, &circuit_randomizer)?;
+            assert_eq!(expected, candidate.eject_value(), "{opcode} {input} {randomizer}.{mode}");
+            assert!(CurrentAleo::is_satisfied(), "'{opcode} {input} {randomizer}.{mode}' is unsatisfied");
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..5 {
+            let randomizer = console::types::Scalar::<CurrentNetwork>::rand(&mut rng).to_string();
+            for input in ["true", "1u8", "-1i32", "1u32"] {
+                check_commit::<{ Committer::BHP256 as u8 }>(input, &randomizer)?;
+                check_commit::<{ Committer::PED64 as u8 }>(input, &randomizer)?;
+                check_commit::<{ Committer::PED128 as u8 }>(input, &randomizer)?;
+            }
+            for input in ["1u64", "-1i64"] {
+                check_commit::<{ Committer::BHP256 as u8 }>(input, &randomizer)?;
+                check_commit::<{ Committer::PED128 as u8 }>(input, &randomizer)?;
+            }
+            check_commit::<{ Committer::BHP256 as u8 }>("1field", &randomizer)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_fails() -> Result<()> {
+        use circuit::Inject;
+
+        type CurrentAleo = circuit::AleoV0;
+
+        let input = Value::<CurrentNetwork>::from_str("1u8")?;
+
+        // Ensure a non-scalar randomizer is rejected.
+        for randomizer in ["1field", "1u8", "0group"] {
+            let randomizer = Value::<CurrentNetwork>::from_str(randomizer)?;
+            assert!(CommitBHP256::<CurrentNetwork>::commit(&input, &randomizer).is_err());
+            assert!(CommitPED64::<CurrentNetwork>::commit(&input, &randomizer).is_err());
+            assert!(CommitPED128::<CurrentNetwork>::commit(&input, &randomizer).is_err());
+
+            let input = circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, input.clone());
+            let randomizer = circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, randomizer);
+            assert!(CommitPED64::<CurrentNetwork>::commit_circuit(&input, &randomizer).is_err());
+        }
+
+        // Ensure an input exceeding the capacity of the committer is rejected.
+        let randomizer = Value::<CurrentNetwork>::from_str("1scalar")?;
+        for input in ["1u64", "1u128", "1field"] {
+            let input = Value::<CurrentNetwork>::from_str(input)?;
+            assert!(CommitPED64::<CurrentNetwork>::commit(&input, &randomizer).is_err());
+        }
+        for input in ["1u128", "1field"] {
+            let input = Value::<CurrentNetwork>::from_str(input)?;
+            assert!(CommitPED128::<CurrentNetwork>::commit(&input, &randomizer).is_err());
+        }
+
+        // Ensure a non-scalar randomizer is rejected when type checking.
+        let process = crate::Process::<CurrentNetwork>::load_with_cache(&mut std::collections::HashMap::new())?;
+        for (randomizer_type, is_ok) in [("scalar", true), ("field", false), ("group", false)] {
+            let program = crate::Program::from_str(&format!(
+                "program testing.aleo;
+                function run:
+                    input r0 as u8.private;
+                    input r1 as {randomizer_type}.private;
+                    commit.ped64 r0 r1 into r2;"
+            ))?;
+            assert_eq!(crate::Stack::new(&process, &program).is_ok(), is_ok, "{randomizer_type}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_bytes() -> Result<()> {
+        for (opcode, string) in [
+            (CommitBHP256::<CurrentNetwork>::opcode(), "commit.bhp256 r0 r1 into r2"),
+            (CommitPED64::<CurrentNetwork>::opcode(), "commit.ped64 r0 r1 into r2"),
+            (CommitPED128::<CurrentNetwork>::opcode(), "commit.ped128 r0 1scalar into r2"),
+        ] {
+            assert!(string.starts_with(*opcode));
+
+            // Check the string representation.
+            let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(instruction.opcode(), opcode);
+            assert_eq!(string, instruction.to_string());
+
+            // Check the byte representation.
+            let bytes = instruction.to_bytes_le()?;
+            assert_eq!(instruction, crate::Instruction::from_bytes_le(&bytes)?);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+use std::any::Any;
+
+/// An error from evaluating an operation, returned in place of a halt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvaluationError {
+    /// The operation overflowed the range of the integer type of its operands.
+    IntegerOverflow(String),
+    /// The operation would halt for another reason, such as a division by zero.
+    Halt(String),
+    /// The operation returned an error, such as on operands of an invalid type.
+    InvalidOperation(String),
+    /// An operand of the operation is a register that has not been assigned.
+    MissingRegister(String),
+}
+
+impl EvaluationError {
+    /// Returns the output of the given evaluation, with an error in place of a halt.
+    pub fn catch_halt<T>(evaluate: impl FnOnce() -> Result<T>) -> Result<T, Self> {
+        // Note: The state of the evaluation is discarded on a halt, so it is not observed after unwinding.
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(evaluate)) {
+            Ok(output) => output.map_err(|error| Self::InvalidOperation(error.to_string())),
+            Err(halt) => Err(Self::from_halt(halt)),
+        }
+    }
+
+    /// Returns the error for the given halt, distinguishing the integer overflows by their message.
+    fn from_halt(halt: Box<dyn Any + Send>) -> Self {
+        let message = match halt.downcast::<String>() {
+            Ok(message) => *message,
+            Err(halt) => match halt.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "The operation halted".to_string(),
+            },
+        };
+        // Note: The checked integer operations halt with "Integer {operation} failed on: {operands}",
+        // and the checked shifts halt with "Failed to shift {operand} ...".
+        let is_overflow = message.starts_with("Integer ") && message.contains(" failed on: ");
+        // Note: The checked divisions also halt with this message on a zero divisor, which is not an overflow.
+        let is_division = message.starts_with("Integer division ") || message.starts_with("Integer remainder ");
+        let is_zero_divisor =
+            is_division && message.rsplit_once(" and ").map_or(false, |(_, divisor)| divisor.starts_with('0'));
+        match (is_overflow && !is_zero_divisor) || message.starts_with("Failed to shift ") {
+            true => Self::IntegerOverflow(message),
+            false => Self::Halt(message),
+        }
+    }
+}
+
+impl Display for EvaluationError {
+    /// Prints the evaluation error as its message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::IntegerOverflow(message)
+            | Self::Halt(message)
+            | Self::InvalidOperation(message)
+            | Self::MissingRegister(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for EvaluationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AbsOperation,
+        AddOperation,
+        ClampOperation,
+        DivOperation,
+        InvOperation,
+        Operation,
+        PowOperation,
+        RemWrappedOperation,
+        ShlOperation,
+    };
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the output of `try_evaluate` for the given operation on the given operands.
+    fn try_evaluate<O, const N: usize>(operands: [&str; N]) -> Result<Literal<CurrentNetwork>, EvaluationError>
+    where
+        O: Operation<CurrentNetwork, Literal<CurrentNetwork>, console::program::LiteralType, N>,
+    {
+        O::try_evaluate(&operands.map(|operand| Literal::from_str(operand).unwrap()))
+    }
+
+    #[test]
+    fn test_try_evaluate() -> Result<()> {
+        // Ensure a valid evaluation returns its output.
+        assert_eq!(try_evaluate::<AddOperation<_>, 2>(["200u8", "55u8"]), Ok(Literal::from_str("255u8")?));
+
+        // Ensure an overflow returns an error, instead of halting.
+        let error = try_evaluate::<AddOperation<_>, 2>(["200u8", "100u8"]).unwrap_err();
+        assert_eq!(error, EvaluationError::IntegerOverflow("Integer addition failed on: 200u8 and 100u8".to_string()));
+        let output = try_evaluate::<AddOperation<_>, 2>(["-100i8", "-100i8"]);
+        assert!(matches!(output, Err(EvaluationError::IntegerOverflow(..))));
+        assert!(matches!(try_evaluate::<AbsOperation<_>, 1>(["-128i8"]), Err(EvaluationError::IntegerOverflow(..))));
+
+        // Ensure invalid operand types return an error.
+        let output = try_evaluate::<AddOperation<_>, 2>(["1u8", "1field"]);
+        assert!(matches!(output, Err(EvaluationError::InvalidOperation(..))));
+        let output = try_evaluate::<DivOperation<_>, 2>(["1u8", "0field"]);
+        assert!(matches!(output, Err(EvaluationError::InvalidOperation(..))));
+
+        // Ensure a division by zero returns an error, instead of halting.
+        assert!(matches!(try_evaluate::<DivOperation<_>, 2>(["7u8", "0u8"]), Err(EvaluationError::Halt(..))));
+        assert!(matches!(try_evaluate::<DivOperation<_>, 2>(["7field", "0field"]), Err(EvaluationError::Halt(..))));
+        assert!(matches!(try_evaluate::<RemWrappedOperation<_>, 2>(["7i8", "0i8"]), Err(EvaluationError::Halt(..))));
+        // Ensure a signed division overflow returns an error.
+        let output = try_evaluate::<DivOperation<_>, 2>(["-128i8", "-1i8"]);
+        assert!(matches!(output, Err(EvaluationError::IntegerOverflow(..))));
+
+        // Ensure an overflow on a magnitude operand returns an error.
+        let output = try_evaluate::<ShlOperation<_>, 2>(["1u8", "8u8"]);
+        assert!(matches!(output, Err(EvaluationError::IntegerOverflow(..))));
+        let output = try_evaluate::<PowOperation<_>, 2>(["2u8", "8u32"]);
+        assert!(matches!(output, Err(EvaluationError::IntegerOverflow(..))));
+
+        // Ensure inverted bounds return an error, instead of halting.
+        assert_eq!(try_evaluate::<ClampOperation<_>, 3>(["9u8", "1u8", "5u8"]), Ok(Literal::from_str("5u8")?));
+        assert!(matches!(try_evaluate::<ClampOperation<_>, 3>(["9u8", "5u8", "1u8"]), Err(EvaluationError::Halt(..))));
+
+        // Ensure the inverse of zero returns the error of the operation.
+        let output = try_evaluate::<InvOperation<_>, 1>(["0field"]);
+        assert!(matches!(output, Err(EvaluationError::InvalidOperation(..))));
+        Ok(())
+    }
+}
@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{sample_operand, Opcode, Operand, Registers, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Field,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -34,6 +35,11 @@ pub type HashPED64<N> = HashInstruction<N, { Hasher::PED64 as u8 }>;
 /// Pedersen128 is a collision-resistant hash function that processes inputs in 128-bit chunks.
 pub type HashPED128<N> = HashInstruction<N, { Hasher::PED128 as u8 }>;
 
+/// Pedersen64Raw is a Pedersen64 hash on the bits of a literal, without the plaintext encoding.
+pub type HashPED64Raw<N> = HashInstruction<N, { Hasher::PED64Raw as u8 }>;
+/// Pedersen128Raw is a Pedersen128 hash on the bits of a literal, without the plaintext encoding.
+pub type HashPED128Raw<N> = HashInstruction<N, { Hasher::PED128Raw as u8 }>;
+
 /// Poseidon2 is a cryptographic hash function that processes inputs in 2-field chunks.
 pub type HashPSD2<N> = HashInstruction<N, { Hasher::PSD2 as u8 }>;
 /// Poseidon4 is a cryptographic hash function that processes inputs in 4-field chunks.
@@ -51,6 +57,8 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    PED64Raw,
+    PED128Raw,
 }
 
 /// Hashes the operand into the declared type.
@@ -76,6 +84,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.ped64.raw"),
+            10 => Opcode::Hash("hash.ped128.raw"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -94,6 +104,15 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the maximum number of input bits, if the variant is a Pedersen hash on the bits of a literal.
+    const fn raw_pedersen_num_bits() -> Option<u16> {
+        match VARIANT {
+            9 => Some(64),
+            10 => Some(128),
+            _ => None,
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -111,18 +130,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
-            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
-        };
+        let output = Self::hash(&input)?;
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
     }
@@ -134,8 +142,6 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        use circuit::{ToBits, ToFields};
-
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
@@ -143,18 +149,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
-            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
-        };
+        let output = Self::hash_circuit::<A>(&input)?;
         // Convert the output to a stack value.
         let output =
             circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
@@ -162,6 +157,126 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         registers.store_circuit(stack, &self.destination, output)
     }
 
+    /// Returns the hash of the given input.
+    fn hash(input: &Value<N>) -> Result<Field<N>> {
+        match VARIANT {
+            0 => N::hash_bhp256(&input.to_bits_le()),
+            1 => N::hash_bhp512(&input.to_bits_le()),
+            2 => N::hash_bhp768(&input.to_bits_le()),
+            3 => N::hash_bhp1024(&input.to_bits_le()),
+            4 => N::hash_ped64(&input.to_bits_le()),
+            5 => N::hash_ped128(&input.to_bits_le()),
+            6 => N::hash_psd2(&input.to_fields()?),
+            7 => N::hash_psd4(&input.to_fields()?),
+            8 => N::hash_psd8(&input.to_fields()?),
+            9 => N::hash_ped64(&Self::literal_bits_le(input)?),
+            10 => N::hash_ped128(&Self::literal_bits_le(input)?),
+            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the hash of the given circuit input.
+    fn hash_circuit<A: circuit::Aleo<Network = N>>(input: &circuit::Value<A>) -> Result<circuit::Field<A>> {
+        use circuit::{ToBits, ToFields};
+
+        match VARIANT {
+            0 => Ok(A::hash_bhp256(&input.to_bits_le())),
+            1 => Ok(A::hash_bhp512(&input.to_bits_le())),
+            2 => Ok(A::hash_bhp768(&input.to_bits_le())),
+            3 => Ok(A::hash_bhp1024(&input.to_bits_le())),
+            4 => Ok(A::hash_ped64(&input.to_bits_le())),
+            5 => Ok(A::hash_ped128(&input.to_bits_le())),
+            6 => Ok(A::hash_psd2(&input.to_fields())),
+            7 => Ok(A::hash_psd4(&input.to_fields())),
+            8 => Ok(A::hash_psd8(&input.to_fields())),
+            9 => Ok(A::hash_ped64(&Self::literal_bits_le_circuit(input)?)),
+            10 => Ok(A::hash_ped128(&Self::literal_bits_le_circuit(input)?)),
+            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        }
+    }
+
+    /// Returns the bits of the given input to a raw Pedersen hash, which hashes the bits of a literal directly.
+    fn literal_bits_le(input: &Value<N>) -> Result<Vec<bool>> {
+        match input {
+            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal.to_bits_le()),
+            _ => bail!("Instruction '{}' expects a literal operand", Self::opcode()),
+        }
+    }
+
+    /// Returns the bits of the given circuit input to a raw Pedersen hash, which hashes the bits of a literal directly.
+    fn literal_bits_le_circuit<A: circuit::Aleo<Network = N>>(
+        input: &circuit::Value<A>,
+    ) -> Result<Vec<circuit::Boolean<A>>> {
+        use circuit::ToBits;
+
+        match input {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal.to_bits_le()),
+            _ => bail!("Instruction '{}' expects a literal operand", Self::opcode()),
+        }
+    }
+
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output,
+    /// for an operand of the given type and mode. This is only supported for the raw Pedersen hashes.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the instruction is a raw Pedersen hash.
+        let num_bits = match Self::raw_pedersen_num_bits() {
+            Some(num_bits) => num_bits,
+            None => bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode()),
+        };
+        // Ensure the number of operands is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), input_types.len())
+        }
+
+        // Sample the operand, ensuring it fits within the number of bits, as the circuit halts otherwise.
+        let (literal_type, mode) = input_types[0];
+        let literal = sample_operand::<N>(literal_type)?;
+        if literal.size_in_bits() > num_bits {
+            bail!("Instruction '{}' expects at most {num_bits} bits, found a '{literal_type}' operand", Self::opcode())
+        }
+        let input = circuit::Value::Plaintext(circuit::Plaintext::Literal(
+            circuit::Literal::new(mode, literal),
+            Default::default(),
+        ));
+
+        // Synthesize the hash, measuring the number of variables and constraints it introduces.
+        let (num_constants, num_public, num_private, num_constraints, _) = A::count();
+        let output = Self::hash_circuit::<A>(&input)?;
+        let (constants, public, private, constraints, _) = A::count();
+
+        let count = circuit::Count::is(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints,
+        );
+        Ok((count, LiteralType::Field, output.eject_mode()))
+    }
+
+    /// Returns the output of the instruction on the given operands. This is only supported for the raw Pedersen hashes.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        // Ensure the instruction is a raw Pedersen hash.
+        if Self::raw_pedersen_num_bits().is_none() {
+            bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+        }
+        // Ensure the number of operands is correct.
+        if inputs.len() != 1 {
+            bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), inputs.len())
+        }
+        let input = circuit::Value::Plaintext(circuit::Plaintext::Literal(inputs[0].clone(), Default::default()));
+        Ok(circuit::Literal::Field(Self::hash_circuit::<A>(&input)?))
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -176,8 +291,23 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // If the operation is raw Pedersen, ensure the input is a literal that is within the number of bits.
+        // Note: The size of a string depends on its value, so it is checked when hashing.
+        if let Some(num_bits) = Self::raw_pedersen_num_bits() {
+            match &input_types[0] {
+                RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String)) => (),
+                RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                    let size_in_bits = sample_operand::<N>(*literal_type)?.size_in_bits();
+                    if size_in_bits > num_bits {
+                        bail!("Instruction '{}' expects at most {num_bits} bits, found {size_in_bits}", Self::opcode())
+                    }
+                }
+                input_type => bail!("Instruction '{}' expects a literal, found '{input_type}'", Self::opcode()),
+            }
+        }
+
         match VARIANT {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 => {
                 Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
             }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
@@ -194,15 +324,15 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the operand from the string.
-        let (string, operand) = Operand::parse(string)?;
+        let (string, operand) = context("operand", Operand::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "into" from the string.
-        let (string, _) = tag("into")(string)?;
+        let (string, _) = context("'into'", tag("into"))(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        let (string, destination) = context("register", Register::parse)(string)?;
 
         Ok((string, Self { operands: vec![operand], destination }))
     }
@@ -289,4 +419,168 @@ mod tests {
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
     }
+
+    /// Checks that the console and circuit hashes of the given input match in every mode.
+    fn check_hash<const VARIANT: u8>(input: &str) -> Result<()> {
+        use circuit::{Eject, Environment, Inject};
+
+        type CurrentAleo = circuit::AleoV0;
+
+        let opcode = HashInstruction::<CurrentNetwork, VARIANT>::opcode();
+
+        // Compute the expected output.
+        let value = Value::<CurrentNetwork>::from_str(input)?;
+        let expected = HashInstruction::<CurrentNetwork, VARIANT>::hash(&value)?;
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            // Ensure the circuit output matches, and the circuit is satisfied.
+            let value = circuit::Value::<CurrentAleo>::new(mode, value.clone());
+            let candidate = HashInstruction::<CurrentNetwork, VARIANT>::hash_circuit::<CurrentAleo>(&value)?;
+            assert_eq!(expected, candidate.eject_value(), "{opcode} {input}.{mode}");
+            assert!(CurrentAleo::is_satisfied(), "'{opcode} {input}.{mode}' is unsatisfied");
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_bhp() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = console::account::PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = console::account::Address::try_from(private_key)?;
+
+        // A string spanning several iterations of every BHP variant.
+        let long_string = format!("\"{}\"", "a".repeat(200));
+
+        let inputs = [
+            "true".to_string(),
+            "1u8".to_string(),
+            "-1i64".to_string(),
+            format!("{}u128", u128::MAX),
+            "1field".to_string(),
+            "0group".to_string(),
+            "1scalar".to_string(),
+            address.to_string(),
+            "\"hello\"".to_string(),
+            long_string,
+        ];
+        for input in &inputs {
+            check_hash::<{ Hasher::BHP256 as u8 }>(input)?;
+            check_hash::<{ Hasher::BHP512 as u8 }>(input)?;
+            check_hash::<{ Hasher::BHP768 as u8 }>(input)?;
+            check_hash::<{ Hasher::BHP1024 as u8 }>(input)?;
+        }
+
+        // Ensure the variants are domain-separated.
+        let value = Value::from_str("1field")?;
+        assert_ne!(HashBHP256::<CurrentNetwork>::hash(&value)?, HashBHP512::<CurrentNetwork>::hash(&value)?);
+        assert_ne!(HashBHP768::<CurrentNetwork>::hash(&value)?, HashBHP1024::<CurrentNetwork>::hash(&value)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_ped() -> Result<()> {
+        use circuit::{Environment, Inject, Metrics, Mode};
+
+        type CurrentAleo = circuit::AleoV0;
+        type PedersenHash =
+            dyn circuit::Hash<Input = circuit::Boolean<CurrentAleo>, Output = circuit::Field<CurrentAleo>>;
+
+        // Ensure the Pedersen variants hash the plaintext encoding of the value.
+        let value = Value::<CurrentNetwork>::from_str("5u8")?;
+        assert_eq!(CurrentNetwork::hash_ped64(&value.to_bits_le())?, HashPED64::<CurrentNetwork>::hash(&value)?);
+        assert_eq!(CurrentNetwork::hash_ped128(&value.to_bits_le())?, HashPED128::<CurrentNetwork>::hash(&value)?);
+        check_hash::<{ Hasher::PED64 as u8 }>("5u8")?;
+        check_hash::<{ Hasher::PED128 as u8 }>("5u64")?;
+
+        // Ensure the raw Pedersen variants hash the bits of the literal, without the plaintext encoding.
+        let value = Value::<CurrentNetwork>::from_str("5u64")?;
+        let bits = Literal::<CurrentNetwork>::from_str("5u64")?.to_bits_le();
+        assert_eq!(CurrentNetwork::hash_ped64(&bits)?, HashPED64Raw::<CurrentNetwork>::hash(&value)?);
+        assert_eq!(CurrentNetwork::hash_ped128(&bits)?, HashPED128Raw::<CurrentNetwork>::hash(&value)?);
+
+        let inputs = ["true".to_string(), "1u8".to_string(), "-1i32".to_string(), format!("{}u64", u64::MAX)];
+        for input in &inputs {
+            check_hash::<{ Hasher::PED64Raw as u8 }>(input)?;
+            check_hash::<{ Hasher::PED128Raw as u8 }>(input)?;
+        }
+        check_hash::<{ Hasher::PED128Raw as u8 }>(&format!("{}u128", u128::MAX))?;
+        check_hash::<{ Hasher::PED128Raw as u8 }>("-1i128")?;
+
+        // Ensure an input that exceeds the number of bits halts.
+        let value = Value::<CurrentNetwork>::from_str("1u128")?;
+        assert!(HashPED64Raw::<CurrentNetwork>::hash(&value).is_err());
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let value = circuit::Value::<CurrentAleo>::new(mode, value.clone());
+            let result =
+                std::panic::catch_unwind(|| HashPED64Raw::<CurrentNetwork>::hash_circuit::<CurrentAleo>(&value));
+            assert!(result.is_err());
+            CurrentAleo::reset();
+        }
+
+        // Ensure the instruction cost matches the Pedersen gadget on the bits of the literal.
+        // Note: The Pedersen gadgets were used above, so their constants are not counted again here.
+        for (string, num_bits) in [("hash.ped64.raw r0 into r1;", 64), ("hash.ped128.raw r0 into r1;", 128)] {
+            let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(string, instruction.to_string());
+            assert_eq!(instruction, crate::Instruction::from_bytes_le(&instruction.to_bytes_le()?)?);
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let (candidate, output_type, output_mode) =
+                    instruction.count::<CurrentAleo>(&[(LiteralType::U64, mode)])?;
+
+                let modes = vec![mode; 64];
+                let expected = match num_bits {
+                    64 => <circuit::Pedersen64<CurrentAleo> as Metrics<PedersenHash>>::count(&modes),
+                    _ => <circuit::Pedersen128<CurrentAleo> as Metrics<PedersenHash>>::count(&modes),
+                };
+                assert_eq!(LiteralType::Field, output_type);
+                let exact = |measurement| match measurement {
+                    circuit::Measurement::Exact(value) => value,
+                    _ => panic!("The count must be exact"),
+                };
+                let (constants, public, private, constraints) =
+                    (exact(candidate.0), exact(candidate.1), exact(candidate.2), exact(candidate.3));
+                assert!(expected.matches(constants, public, private, constraints));
+
+                // Ensure the output is a constant only if the input is a constant.
+                match mode.is_constant() {
+                    true => assert_eq!(Mode::Constant, output_mode),
+                    false => assert_eq!(Mode::Private, output_mode),
+                }
+                CurrentAleo::reset();
+            }
+        }
+
+        // Ensure the instruction cost is not estimated for an input that exceeds the number of bits.
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("hash.ped64.raw r0 into r1;")?;
+        assert!(instruction.count::<CurrentAleo>(&[(LiteralType::U128, Mode::Private)]).is_err());
+        assert!(instruction.count::<CurrentAleo>(&[(LiteralType::Field, Mode::Private)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_bytes_bhp() -> Result<()> {
+        for (opcode, string) in [
+            (HashBHP256::<CurrentNetwork>::opcode(), "hash.bhp256 r0 into r1;"),
+            (HashBHP512::<CurrentNetwork>::opcode(), "hash.bhp512 r0 into r1;"),
+            (HashBHP768::<CurrentNetwork>::opcode(), "hash.bhp768 r0 into r1;"),
+            (HashBHP1024::<CurrentNetwork>::opcode(), "hash.bhp1024 r0 into r1;"),
+        ] {
+            assert!(string.starts_with(*opcode));
+
+            // Check the string representation.
+            let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(instruction.opcode(), opcode);
+            assert_eq!(string, instruction.to_string());
+
+            // Check the byte representation.
+            let bytes = instruction.to_bytes_le()?;
+            assert_eq!(instruction, crate::Instruction::from_bytes_le(&bytes)?);
+        }
+
+        // Ensure a mismatched suffix does not parse as the instruction.
+        assert!(HashBHP256::<CurrentNetwork>::from_str("hash.bhp512 r0 into r1").is_err());
+        Ok(())
+    }
 }
@@ -122,6 +122,24 @@ impl<N: Network, const VARIANT: u8> IsInstruction<N, VARIANT> {
         registers.store_circuit(stack, &self.destination, output)
     }
 
+    /// Returns the circuit cost of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        _input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode())
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -159,19 +177,19 @@ impl<N: Network, const VARIANT: u8> Parser for IsInstruction<N, VARIANT> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the first operand from the string.
-        let (string, first) = Operand::parse(string)?;
+        let (string, first) = context("operand", Operand::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the second operand from the string.
-        let (string, second) = Operand::parse(string)?;
+        let (string, second) = context("operand", Operand::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the "into" from the string.
-        let (string, _) = tag("into")(string)?;
+        let (string, _) = context("'into'", tag("into"))(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
+        let (string, destination) = context("register", Register::parse)(string)?;
 
         Ok((string, Self { operands: vec![first, second], destination }))
     }
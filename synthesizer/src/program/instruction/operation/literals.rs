@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Operation, Registers, Stack};
+use crate::{Opcode, Operand, Operation, OverflowPolicy, Registers, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::{Address, Group},
 };
 
 use core::marker::PhantomData;
@@ -42,6 +43,19 @@ pub struct Literals<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPE
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
     Literals<N, O, NUM_OPERANDS>
 {
+    /// Initializes a new instruction with the given operands and destination register.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Ensure the number of operands is correct.
+        ensure!(
+            operands.len() == NUM_OPERANDS,
+            "Instruction '{}' expects {NUM_OPERANDS} operands, found {} operands",
+            O::OPCODE,
+            operands.len()
+        );
+        Ok(Self { operands, destination, _phantom: PhantomData })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -59,6 +73,30 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns `true` if the operands may be reordered without changing the output.
+    /// Note: Only `add` and `mul` (and their wrapped variants) are normalized; all other operations keep their order.
+    fn is_commutative() -> bool {
+        NUM_OPERANDS == 2 && matches!(O::OPCODE, Opcode::Literal("add" | "add.w" | "mul" | "mul.w"))
+    }
+
+    /// Returns the given loaded operands, with a literal operand moved into the second position
+    /// for commutative operations, so that special cases only need to check the second operand.
+    /// Note: The instruction itself is unchanged, so its string and byte representations keep the written order.
+    fn normalize<T>(&self, mut inputs: Vec<T>) -> Vec<T> {
+        if Self::is_commutative()
+            && matches!(self.operands[0], Operand::Literal(..))
+            && !matches!(self.operands[1], Operand::Literal(..))
+        {
+            inputs.swap(0, 1);
+        }
+        inputs
+    }
+
+    /// Returns the location of the instruction, i.e. `at r2 (operands r0, r1)`, for use in error messages.
+    fn location(&self) -> String {
+        format!("at {} (operands {})", self.destination, self.operands.iter().join(", "))
+    }
 }
 
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
@@ -77,14 +115,31 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         }
 
         // Load the operands literals.
-        let inputs: Vec<_> =
-            self.operands.iter().map(|operand| registers.load_literal(stack, operand)).try_collect()?;
+        let inputs: Vec<_> = self
+            .operands
+            .iter()
+            .map(|operand| registers.load_literal(stack, operand))
+            .try_collect()
+            .map_err(|error| anyhow!("Invalid '{}' instruction {}: {error}", O::OPCODE, self.location()))?;
+        let inputs = self.normalize(inputs);
         // Compute the operands register types.
         let input_types: Vec<_> =
             inputs.iter().map(|input| RegisterType::Plaintext(PlaintextType::from(input.to_type()))).collect();
 
         // Compute the operation.
-        let output = O::evaluate(&inputs.try_into().map_err(|_| anyhow!("Failed to prepare operands in evaluate"))?)?;
+        let evaluate = || -> Result<Literal<N>> {
+            let operands = inputs.clone().try_into().map_err(|_| anyhow!("Failed to prepare operands in evaluate"))?;
+            // Note: A halt in the operation is returned as an error, so that the evaluation does not unwind.
+            Ok(O::try_evaluate(&operands)?)
+        };
+        let output = match (stack.replay_cache(), stack.overflow_policy()) {
+            // If the replay cache is enabled, replay the output of an identical computation.
+            // Note: The cache is only used under the `Halt` policy, as other policies may compute different outputs.
+            (Some(replay_cache), OverflowPolicy::Halt) => replay_cache.evaluate(O::OPCODE, &inputs, evaluate),
+            // Otherwise, compute the output under the overflow policy.
+            (_, overflow_policy) => overflow_policy.evaluate(O::OPCODE, &inputs, evaluate),
+        }
+        .map_err(|error| anyhow!("Invalid '{}' instruction {}: {error}", O::OPCODE, self.location()))?;
         // Compute the output type.
         let output_type = RegisterType::Plaintext(PlaintextType::from(output.to_type()));
 
@@ -112,14 +167,20 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         }
 
         // Load the operands literals.
-        let inputs: Vec<_> =
-            self.operands.iter().map(|operand| registers.load_literal_circuit(stack, operand)).try_collect()?;
+        let inputs: Vec<_> = self
+            .operands
+            .iter()
+            .map(|operand| registers.load_literal_circuit(stack, operand))
+            .try_collect()
+            .map_err(|error| anyhow!("Invalid '{}' instruction {}: {error}", O::OPCODE, self.location()))?;
+        let inputs = self.normalize(inputs);
         // Compute the operands register types.
         let input_types: Vec<_> =
             inputs.iter().map(|input| RegisterType::Plaintext(PlaintextType::from(input.to_type()))).collect();
 
         // Compute the operation.
-        let output = O::execute(&inputs.try_into().map_err(|_| anyhow!("Failed to prepare operands in evaluate"))?)?;
+        let output = O::execute(&inputs.try_into().map_err(|_| anyhow!("Failed to prepare operands in evaluate"))?)
+            .map_err(|error| anyhow!("Invalid '{}' instruction {}: {error}", O::OPCODE, self.location()))?;
         // Compute the output type.
         let output_type = RegisterType::Plaintext(PlaintextType::from(output.to_type()));
 
@@ -134,6 +195,94 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         registers.store_literal_circuit(stack, &self.destination, output)
     }
 
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output,
+    /// for operands of the given types and modes.
+    ///
+    /// The cost of an operation on a constant may depend on its value, as in multiplying by `1u64`.
+    /// So the literal operands are synthesized with their values, while the other operands are synthesized
+    /// on sample operands that do not halt, and take no special case for a constant of a given value.
+    /// Note: The circuit environment is reset.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        // Reset the circuit environment.
+        A::reset();
+        // Synthesize the operation on the operands.
+        let count = self.count_operation::<A>(input_types);
+        // Reset the circuit environment.
+        A::reset();
+        count
+    }
+
+    /// Returns the circuit cost of the operation, along with the type and mode of its output.
+    fn count_operation<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the number of operands is correct.
+        if input_types.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects {NUM_OPERANDS} operands, found {} operands", O::OPCODE, input_types.len())
+        }
+
+        // Compute the output type, ensuring the operand types are supported.
+        let literal_types: Vec<_> = input_types.iter().map(|(literal_type, _)| *literal_type).collect();
+        let output_type = O::output_type(
+            &literal_types.try_into().map_err(|_| anyhow!("Failed to prepare operand types in count"))?,
+        )?;
+
+        // Inject the literal operands as given, and sample the other operands.
+        let inputs: Vec<_> = input_types
+            .iter()
+            .zip_eq(&self.operands)
+            .map(|((literal_type, mode), operand)| {
+                let literal = match (mode, operand) {
+                    (circuit::Mode::Constant, Operand::Literal(literal)) => {
+                        ensure!(literal.to_type() == *literal_type, "Expected a '{literal_type}', found '{literal}'");
+                        literal.clone()
+                    }
+                    (circuit::Mode::Constant, _) => sample_constant_operand(*literal_type)?,
+                    _ => sample_operand(*literal_type)?,
+                };
+                Ok(circuit::Literal::new(*mode, literal))
+            })
+            .collect::<Result<_>>()?;
+
+        // Synthesize the operation, measuring the number of variables and constraints it introduces.
+        let (num_constants, num_public, num_private, num_constraints, _) = A::count();
+        let output =
+            O::execute::<A>(&inputs.try_into().map_err(|_| anyhow!("Failed to prepare operands in count"))?)?;
+        let (constants, public, private, constraints, _) = A::count();
+
+        let count = circuit::Count::is(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints,
+        );
+        Ok((count, output_type, output.eject_mode()))
+    }
+
+    /// Returns the output of the instruction, synthesized on the given circuit operands.
+    ///
+    /// Unlike `count`, the operands are used as given, so the mode of the output accounts for
+    /// the values of the constant operands, without resetting the circuit environment.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        // Ensure the number of operands is correct.
+        if inputs.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects {NUM_OPERANDS} operands, found {} operands", O::OPCODE, inputs.len())
+        }
+        // Synthesize the operation.
+        O::execute::<A>(&inputs.to_vec().try_into().map_err(|_| anyhow!("Failed to prepare operands in synthesis"))?)
+    }
+
     /// Returns the output type from the given program and input types.
     #[inline]
     pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
@@ -161,7 +310,20 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
             .collect::<Result<Vec<_>>>()?;
 
         // Compute the output type.
-        let output = O::output_type(&input_types.try_into().map_err(|_| anyhow!("Failed to prepare operand types"))?)?;
+        let inputs = <[LiteralType; NUM_OPERANDS]>::try_from(input_types.as_slice())
+            .map_err(|_| anyhow!("Failed to prepare operand types"))?;
+        let output = O::output_type(&inputs).map_err(|error| match (*O::OPCODE, &input_types[..]) {
+            // Guide the user to scalar-multiply the group first, a common mistake.
+            // Note: 'add.w' only supports integers, so it keeps the generic error.
+            ("add", [LiteralType::Group, LiteralType::Scalar]) | ("add", [LiteralType::Scalar, LiteralType::Group]) => {
+                anyhow!(
+                    "Instruction '{}' cannot add a group and a scalar. Use 'mul' to multiply a group by the scalar \
+                     first, then 'add' the resulting groups",
+                    O::OPCODE
+                )
+            }
+            _ => error,
+        })?;
 
         // Return the output type.
         Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(output))])
@@ -191,7 +353,7 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         // Parse the operands from the string.
         for _ in 0..NUM_OPERANDS {
             // Parse the operand from the string.
-            let (string, operand) = Operand::parse(string_tracker)?;
+            let (string, operand) = context("operand", Operand::parse)(string_tracker)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Add the operand to the vector.
@@ -203,13 +365,24 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         let string = string_tracker;
 
         // Parse the "into " from the string.
-        let (string, _) = tag("into")(string)?;
+        let (string, _) = context("'into'", tag("into"))(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
-        let (string, destination) = Register::parse(string)?;
-
-        Ok((string, Self { operands, destination, _phantom: PhantomData }))
+        let (string, destination) = context("register", Register::parse)(string)?;
+
+        // Ensure the destination register is not also read as an operand.
+        // Note: Registers are single-assignment, so any overlap would read the destination before it is written.
+        let is_overlapping = operands.iter().any(|operand| match operand {
+            Operand::Register(register) => register.locator() == destination.locator(),
+            _ => false,
+        });
+        match is_overlapping {
+            false => Ok((string, Self { operands, destination, _phantom: PhantomData })),
+            true => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse '{}': destination overlaps an operand", O::OPCODE)))
+            })(string),
+        }
     }
 }
 
@@ -307,3 +480,375 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
         self.destination.write_le(&mut writer)
     }
 }
+
+/// Returns a sample operand of the given literal type, on which every operation succeeds.
+pub(crate) fn sample_operand<N: Network>(literal_type: LiteralType) -> Result<Literal<N>> {
+    match literal_type {
+        LiteralType::Address => Ok(Literal::Address(Address::new(Group::zero()))),
+        LiteralType::Boolean => Literal::from_str("true"),
+        LiteralType::Group => Literal::from_str("0group"),
+        LiteralType::String => Literal::from_str("\"\""),
+        _ => Literal::from_str(&format!("1{literal_type}")),
+    }
+}
+
+/// Returns a sample constant of the given literal type, on which every operation succeeds, and which takes
+/// no special case for a constant of a given value, such as multiplying by zero or one, or adding `0group`.
+/// Note: Every boolean constant takes a special case, so a boolean is sampled as `true`.
+pub(crate) fn sample_constant_operand<N: Network>(literal_type: LiteralType) -> Result<Literal<N>> {
+    match literal_type {
+        LiteralType::Address => Ok(Literal::Address(Address::new(Group::generator()))),
+        LiteralType::Boolean => Literal::from_str("true"),
+        // Note: A field is sampled as a square, so that taking its square root succeeds.
+        LiteralType::Field => Literal::from_str("4field"),
+        LiteralType::Group => Ok(Literal::Group(Group::generator())),
+        LiteralType::String => Literal::from_str("\"\""),
+        _ => Literal::from_str(&format!("2{literal_type}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::AleoV0;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, Value},
+        types::Field,
+    };
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_parse_destination_overlap() {
+        type Add = BinaryLiteral<CurrentNetwork, crate::AddOperation<CurrentNetwork>>;
+        type Ternary = TernaryLiteral<CurrentNetwork, crate::TernaryOperation<CurrentNetwork>>;
+
+        // Ensure operands that are distinct from the destination are permitted.
+        assert!(Add::from_str("add r0 r1 into r2").is_ok());
+        assert!(Add::from_str("add r0 r0 into r1").is_ok());
+        assert!(Add::from_str("add r0.x 1u8 into r1").is_ok());
+        assert!(Add::from_str("add 1u8 2u8 into r0").is_ok());
+        assert!(Ternary::from_str("ternary r0 r1 r2 into r3").is_ok());
+
+        // Ensure operands that overlap the destination are rejected.
+        assert!(Add::from_str("add r0 r1 into r0").is_err());
+        assert!(Add::from_str("add r0 r1 into r1").is_err());
+        assert!(Add::from_str("add r0.x r1 into r0").is_err());
+        assert!(Ternary::from_str("ternary r0 r1 r2 into r2").is_err());
+    }
+
+    #[test]
+    fn test_parse_commutative_order() -> Result<()> {
+        type Add = BinaryLiteral<CurrentNetwork, crate::AddOperation<CurrentNetwork>>;
+        type Mul = BinaryLiteral<CurrentNetwork, crate::MulOperation<CurrentNetwork>>;
+
+        for expected in ["add 2field r0 into r1", "add r0 2field into r1", "mul 2u8 r0 into r1"] {
+            // Ensure the written operand order is kept.
+            let candidate = match expected.starts_with("add") {
+                true => Add::from_str(expected)?.to_string(),
+                false => Mul::from_str(expected)?.to_string(),
+            };
+            assert_eq!(expected, candidate);
+        }
+
+        // Ensure the string and byte representations agree, i.e. bytes -> string -> bytes is lossless.
+        let expected = Add::from_str("add 2field r0 into r1")?.to_bytes_le()?;
+        let candidate = Add::from_str(&Add::from_bytes_le(&expected)?.to_string())?;
+        assert_eq!(expected, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_commutative_normalization() -> Result<()> {
+        // Initialize a program that adds a constant on either side.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    add 2field r0 into r1;
+    add r0 2field into r2;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` set to one.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let one = Value::Plaintext(Plaintext::from(Literal::Field(Field::one())));
+        registers.store(&stack, &Register::Locator(0), one)?;
+
+        // Ensure both instructions keep their order, and evaluate identically.
+        let function = program.get_function(&function_name)?;
+        assert_eq!(function.instructions()[0].to_string(), "add 2field r0 into r1;");
+        for instruction in function.instructions() {
+            instruction.evaluate(&stack, &mut registers)?;
+        }
+        let first = registers.load(&stack, &Operand::Register(Register::Locator(1)))?;
+        let second = registers.load(&stack, &Operand::Register(Register::Locator(2)))?;
+        assert_eq!(first, second);
+        assert_eq!(Value::Plaintext(Plaintext::from(Literal::from_str("3field")?)), first);
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_immediate_operands() -> Result<()> {
+        // Initialize a program that mixes register and immediate operands.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.private;
+    sub r0 1u8 into r1;
+    sub 10u8 r1 into r2;
+    lt 3u8 4u8 into r3;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` set to five.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        registers.store(&stack, &Register::Locator(0), Value::from_str("5u8")?)?;
+
+        // Ensure the immediate operands are loaded without a register lookup.
+        let function = program.get_function(&function_name)?;
+        for instruction in function.instructions() {
+            instruction.evaluate(&stack, &mut registers)?;
+        }
+        for (locator, expected) in [(1, "4u8"), (2, "6u8"), (3, "true")] {
+            let candidate = registers.load(&stack, &Operand::Register(Register::Locator(locator)))?;
+            assert_eq!(Value::from_str(expected)?, candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_group_and_scalar_guidance() -> Result<()> {
+        let process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+
+        for instruction in ["add 2group 1scalar into r1", "add 1scalar r0 into r1"] {
+            // Initialize a program that adds a group and a scalar.
+            let program = Program::<CurrentNetwork>::from_str(&format!(
+                "program testing.aleo; function run: input r0 as group.private; {instruction};"
+            ))?;
+
+            // Ensure the program is rejected at validation, with guidance to use `mul`.
+            let error = Stack::new(&process, &program).err().unwrap().to_string();
+            assert!(error.contains("cannot add a group and a scalar. Use 'mul'"), "Unexpected error: {error}");
+        }
+
+        // Ensure other invalid operand types keep the generic error.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program testing.aleo; function run: input r0 as group.private; add r0 1field into r1;",
+        )?;
+        let error = Stack::new(&process, &program).err().unwrap().to_string();
+        assert!(error.contains("Invalid operand types for the 'add' instruction"), "Unexpected error: {error}");
+
+        // Ensure 'add.w' keeps the generic error, as it does not support groups.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program testing.aleo; function run: input r0 as group.private; add.w r0 1scalar into r1;",
+        )?;
+        let error = Stack::new(&process, &program).err().unwrap().to_string();
+        assert!(error.contains("Invalid operand types for the 'add.w' instruction"), "Unexpected error: {error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_record_members() -> Result<()> {
+        // Initialize a program that adds the amounts of two records.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function run:
+    input r0 as token.record;
+    input r1 as token.record;
+    add r0.amount r1.amount into r2;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` and `r1` set to tokens.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let owner = "aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw";
+        for (locator, amount) in [(0, 3), (1, 4)] {
+            let token = Value::from_str(&format!(
+                "{{ owner: {owner}.private, gates: 0u64.private, amount: {amount}u64.private, _nonce: 0group.public }}"
+            ))?;
+            registers.store(&stack, &Register::Locator(locator), token)?;
+        }
+
+        // Ensure the record members are added.
+        let function = program.get_function(&function_name)?;
+        function.instructions()[0].evaluate(&stack, &mut registers)?;
+        let expected = Value::Plaintext(Plaintext::from(Literal::from_str("7u64")?));
+        assert_eq!(expected, registers.load(&stack, &Operand::Register(Register::Locator(2)))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_struct_members() -> Result<()> {
+        // Initialize a program that adds and multiplies the members of a struct.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+function run:
+    input r0 as point.private;
+    add r0.x r0.y into r1;
+    mul r0.x r0.y into r2;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let process = Process::load_with_cache(&mut HashMap::new())?;
+        let stack = Stack::new(&process, &program)?;
+
+        // Initialize the registers, with `r0` set to a point.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        registers.store(&stack, &Register::Locator(0), Value::from_str("{ x: 3u64, y: 4u64 }")?)?;
+
+        // Ensure the struct members are resolved, and the instructions operate on the literals.
+        let function = program.get_function(&function_name)?;
+        for instruction in function.instructions() {
+            instruction.evaluate(&stack, &mut registers)?;
+        }
+        for (locator, expected) in [(1, "7u64"), (2, "12u64")] {
+            let candidate = registers.load(&stack, &Operand::Register(Register::Locator(locator)))?;
+            assert_eq!(Value::from_str(expected)?, candidate);
+        }
+
+        // Ensure loading an unknown member, or a member of a literal, fails.
+        for register in ["r0.z", "r1.x"] {
+            assert!(registers.load(&stack, &Operand::Register(Register::from_str(register)?)).is_err());
+        }
+
+        // Ensure an unknown member, or a member of a literal, is rejected at validation.
+        for instruction in ["add r0.x r0.z into r2", "add r0.x r1.x into r2"] {
+            let program = Program::<CurrentNetwork>::from_str(&format!(
+                "program testing.aleo; struct point: x as u64; y as u64;
+                function run: input r0 as point.private; add r0.x r0.y into r1; {instruction};"
+            ))?;
+            assert!(Stack::new(&process, &program).is_err(), "{instruction}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_execute_mul_by_constant_one_and_zero() -> Result<()> {
+        use circuit::{Eject, Environment, Inject};
+
+        // Initialize a program that multiplies by a constant one and a constant zero.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as field.private;
+    mul r0 1u64 into r2;
+    mul r1 0field into r3;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` and `r1` set to private circuit values.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        for (locator, value) in [(0, "5u64"), (1, "5field")] {
+            let value = Value::from_str(value)?;
+            registers.store(&stack, &Register::Locator(locator), value.clone())?;
+            registers.store_circuit(&stack, &Register::Locator(locator), circuit::Value::new(circuit::Mode::Private, value))?;
+        }
+
+        // Execute the instructions.
+        let num_constraints = CurrentAleo::num_constraints();
+        let function = program.get_function(&function_name)?;
+        for instruction in function.instructions() {
+            instruction.execute(&stack, &mut registers)?;
+        }
+        // Ensure the multiplications did not add any constraints.
+        assert_eq!(num_constraints, CurrentAleo::num_constraints());
+
+        // Ensure `mul r0 1u64` preserves the mode and value of `r0`.
+        let candidate = registers.load_literal_circuit(&stack, &Operand::Register(Register::Locator(2)))?;
+        assert_eq!(circuit::Mode::Private, candidate.eject_mode());
+        assert_eq!(Literal::from_str("5u64")?, candidate.eject_value());
+
+        // Ensure `mul r1 0field` yields a constant zero.
+        let candidate = registers.load_literal_circuit(&stack, &Operand::Register(Register::Locator(3)))?;
+        assert_eq!(circuit::Mode::Constant, candidate.eject_mode());
+        assert_eq!(Literal::Field(Field::zero()), candidate.eject_value());
+
+        CurrentAleo::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_error_includes_location() -> Result<()> {
+        // Initialize a program that inverts its input.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as field.private;
+    inv r0 into r1;",
+        )?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+
+        // Initialize the registers, with `r0` set to zero.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&function_name)?.clone(),
+        );
+        let zero = Value::Plaintext(Plaintext::from(Literal::Field(Field::zero())));
+        registers.store(&stack, &Register::Locator(0), zero)?;
+
+        // Ensure the error names the instruction, the destination register, and the operands.
+        let function = program.get_function(&function_name)?;
+        let error = function.instructions()[0].evaluate(&stack, &mut registers).unwrap_err().to_string();
+        assert!(error.contains("Invalid 'inv' instruction at r1 (operands r0)"), "Unexpected error: {error}");
+        Ok(())
+    }
+}
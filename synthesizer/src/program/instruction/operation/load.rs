@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::U64,
+};
+
+/// Loads the proof target provided to the process into the destination register, as a `u64`.
+///
+/// In a circuit, the proof target is injected as a constant, so it is fixed by the circuit keys.
+/// A proof for one proof target does not verify under the keys synthesized for another.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LoadProofTarget<N: Network> {
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> LoadProofTarget<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Load("load.proof_target")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &[]
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> LoadProofTarget<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Retrieve the proof target.
+        let proof_target = Self::proof_target(stack)?;
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::U64(U64::new(proof_target)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Retrieve the proof target.
+        let proof_target = Self::proof_target(stack)?;
+        // Inject the proof target as a constant, so the prover cannot choose its value.
+        let output = circuit::U64::new(circuit::Mode::Constant, U64::new(proof_target));
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::U64(output))
+    }
+
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        ensure!(input_types.is_empty(), "Instruction '{}' expects 0 operands", Self::opcode());
+
+        // Inject a sample proof target, measuring the number of variables and constraints it introduces.
+        let (num_constants, num_public, num_private, num_constraints, _) = A::count();
+        let _output = circuit::U64::<A>::new(circuit::Mode::Constant, U64::new(0));
+        let (constants, public, private, constraints, _) = A::count();
+
+        let count = circuit::Count::is(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints,
+        );
+        Ok((count, LiteralType::U64, circuit::Mode::Constant))
+    }
+
+    /// Returns the output of the instruction, synthesized on a sample proof target.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        ensure!(inputs.is_empty(), "Instruction '{}' expects 0 operands", Self::opcode());
+        Ok(circuit::Literal::U64(circuit::U64::new(circuit::Mode::Constant, U64::new(0))))
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if !input_types.is_empty() {
+            bail!("Instruction '{}' expects 0 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U64))])
+    }
+
+    /// Returns the proof target provided to the process.
+    ///
+    /// As the proof target is a constant of the circuit, it is required in every mode,
+    /// including when authorizing, synthesizing the circuit keys, and checking a deployment.
+    fn proof_target(stack: &Stack<N>) -> Result<u64> {
+        match stack.proof_target() {
+            Some(proof_target) => Ok(proof_target),
+            None => bail!("Instruction '{}' requires a proof target to be provided", Self::opcode()),
+        }
+    }
+}
+
+impl<N: Network> Parser for LoadProofTarget<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = context("'into'", tag("into"))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = context("register", Register::parse)(string)?;
+
+        Ok((string, Self { destination }))
+    }
+}
+
+impl<N: Network> FromStr for LoadProofTarget<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for LoadProofTarget<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for LoadProofTarget<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} into {}", Self::opcode(), self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for LoadProofTarget<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the operation.
+        Ok(Self { destination })
+    }
+}
+
+impl<N: Network> ToBytes for LoadProofTarget<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, load) = LoadProofTarget::<CurrentNetwork>::parse("load.proof_target into r0").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(load.destination, Register::Locator(0), "The destination register is incorrect");
+        assert_eq!("load.proof_target into r0", load.to_string());
+
+        // Ensure the instruction does not accept operands.
+        assert!(LoadProofTarget::<CurrentNetwork>::from_str("load.proof_target r0 into r1").is_err());
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = LoadProofTarget::<CurrentNetwork>::from_str("load.proof_target into r3")?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, LoadProofTarget::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
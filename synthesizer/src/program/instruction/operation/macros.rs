@@ -362,6 +362,38 @@ mod tests {
         };
     }
 
+    /// Executes the binary operation in every mode combination, returning the output mode and value of each.
+    /// The combinations are ordered `(Constant, Public, Private) x (Constant, Public, Private)`.
+    ///
+    /// ## Example
+    /// ```ignore
+    /// ```text
+    ///     let outputs = run_binary!(AddOperation<CurrentNetwork>, "1u8", "2u8");
+    /// ```
+    #[macro_export]
+    macro_rules! run_binary {
+        ($operation:ty, $first:expr, $second:expr) => {{
+            use circuit::Eject;
+
+            let mut outputs = Vec::with_capacity(9);
+            for mode_a in &[circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+                for mode_b in &[circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+                    // Initialize the circuit operands.
+                    let a = circuit::program::Literal::<circuit::network::AleoV0>::from_str(&format!("{}.{mode_a}", $first))?;
+                    let b = circuit::program::Literal::<circuit::network::AleoV0>::from_str(&format!("{}.{mode_b}", $second))?;
+
+                    // Record the executed output.
+                    let candidate = <$operation as $crate::Operation<_, _, _, 2>>::execute::<circuit::network::AleoV0>(&[a, b])?;
+                    outputs.push((candidate.eject_mode(), candidate.eject_value()));
+
+                    // Reset the circuit.
+                    <circuit::network::AleoV0 as circuit::Environment>::reset();
+                }
+            }
+            outputs
+        }};
+    }
+
     ///
     /// Creates a test of the given operation for each declared case.
     ///
@@ -609,9 +641,14 @@ mod tests {
                         #[allow(unused_macros)]
                         macro_rules! check_condition {
                             ("ensure overflows halt") => {
+                                // Note: This import provides `checked_abs` for unsigned integers, which do not have it inherently.
+                                #[allow(unused_imports)]
+                                use console::prelude::integers::CheckedAbs;
                                 match *<$operation as $crate::Operation<_, _, _, 1>>::OPCODE {
                                     "abs" => should_succeed &= (*a).checked_abs().is_some(),
                                     "neg" => should_succeed &= (*a).checked_neg().is_some(),
+                                    "double" => should_succeed &= (*a).checked_add(*a).is_some(),
+                                    "square" => should_succeed &= (*a).checked_mul(*a).is_some(),
                                     _ => panic!("Unsupported test enforcement for '{}'", <$operation as $crate::Operation<_, _, _, 1>>::OPCODE),
                                 }
                             };
@@ -640,6 +677,8 @@ mod tests {
 
                             // If this iteration should succeed, ensure the evaluated and executed outputs match the expected output.
                             if should_succeed {
+                                // Ensure the checked evaluation returns the expected output.
+                                assert_eq!(expected, <$operation as $crate::Operation<_, _, _, 1>>::try_evaluate(&[a.clone()]).ok());
                                 // Compute the evaluated output.
                                 let candidate_a = <$operation as $crate::Operation<_, _, _, 1>>::evaluate(&[a])?;
                                 // Compute the executed output.
@@ -658,6 +697,10 @@ mod tests {
                                 // Ensure the evaluation halted.
                                 assert!(result_a.is_err(), "Failure case (on iteration {i}) did not halt (console): {a}");
 
+                                // Ensure the checked evaluation returns an error, instead of halting.
+                                let result_c = <$operation as $crate::Operation<_, _, _, 1>>::try_evaluate(&[a.clone()]);
+                                assert!(result_c.is_err(), "Failure case (on iteration {i}) did not return an error (console): {a}");
+
                                 // Halt the execution.
                                 if mode_a.is_constant() {
                                     // Attempt to execute a failure case.
@@ -814,6 +857,8 @@ mod tests {
 
                                 // If this iteration should succeed, ensure the evaluated and executed outputs match the expected output.
                                 if should_succeed {
+                                    // Ensure the checked evaluation returns the expected output.
+                                    assert_eq!(expected, <$operation as $crate::Operation<_, _, _, 2>>::try_evaluate(&[a.clone(), b.clone()]).ok());
                                     // Compute the evaluated output.
                                     let candidate_a = <$operation as $crate::Operation<_, _, _, 2>>::evaluate(&[a, b])?;
                                     // Compute the executed output.
@@ -832,6 +877,10 @@ mod tests {
                                     // Ensure the evaluation halted.
                                     assert!(result_a.is_err(), "Failure case (on iteration {i}) did not halt (console): {a} {b}");
 
+                                    // Ensure the checked evaluation returns an error, instead of halting.
+                                    let result_c = <$operation as $crate::Operation<_, _, _, 2>>::try_evaluate(&[a.clone(), b.clone()]);
+                                    assert!(result_c.is_err(), "Failure case (on iteration {i}) did not return an error (console): {a} {b}");
+
                                     // Halt the execution.
                                     if (mode_a.is_constant() && mode_b.is_constant()) || should_panic_on_halt {
                                         // Attempt to execute a failure case.
@@ -864,6 +913,9 @@ mod tests {
         //   2. "ensure divide by zero halts"
         //     - If the sampled divisor is zero, ensure it halts.
         //     - If the sampled divisor is **not** zero, ensure it succeeds.
+        //   3. "ensure inverted bounds halt"
+        //     - If the sampled lower bound (second) exceeds the upper bound (third), ensure it halts.
+        //     - If the sampled bounds are ordered, ensure it succeeds.
         ($operator:tt::$operate:tt == $operation:tt::$execute:tt for ($input_a:ident, $input_b:ident, $input_c:ident) => $output:ident $( ($($condition:tt),+) )?) => {
             paste::paste! {
                 #[test]
@@ -900,6 +952,19 @@ mod tests {
                         // Initialize an indicator whether the operation should succeed or not.
                         #[allow(unused_mut)]
                         let mut should_succeed = true;
+                        #[allow(unused_mut)]
+                        let mut halts_on_constant_bounds = false;
+                        /// A helper macro to check the conditions.
+                        #[allow(unused_macros)]
+                        macro_rules! check_condition {
+                            ("ensure inverted bounds halt") => {
+                                should_succeed &= (*b) <= (*c);
+                                // This indicator is later used in the for-loops below.
+                                halts_on_constant_bounds |= true;
+                            };
+                        }
+                        // Check the conditions.
+                        $( $( check_condition!($condition); )+ )?
 
                         // If `should_succeed` is `true`, compute the expected output.
                         let expected = match should_succeed {
@@ -922,6 +987,8 @@ mod tests {
 
                                     // If this iteration should succeed, ensure the evaluated and executed outputs match the expected output.
                                     if should_succeed {
+                                        // Ensure the checked evaluation returns the expected output.
+                                        assert_eq!(expected, <$operation as $crate::Operation<_, _, _, 3>>::try_evaluate(&[a.clone(), b.clone(), c.clone()]).ok());
                                         // Compute the evaluated output.
                                         let candidate_a = <$operation as $crate::Operation<_, _, _, 3>>::evaluate(&[a, b, c])?;
                                         // Compute the executed output.
@@ -939,8 +1006,15 @@ mod tests {
                                         // Ensure the evaluation halted.
                                         assert!(result_a.is_err(), "Failure case (on iteration {i}) did not halt (console): {a} {b} {c}");
 
+                                        // Ensure the checked evaluation returns an error, instead of halting.
+                                        let result_c = <$operation as $crate::Operation<_, _, _, 3>>::try_evaluate(&[a.clone(), b.clone(), c.clone()]);
+                                        assert!(result_c.is_err(), "Failure case (on iteration {i}) did not return an error (console): {a} {b} {c}");
+
                                         // Halt the execution.
-                                        if (mode_a.is_constant() && mode_b.is_constant() && mode_c.is_constant()) {
+                                        // Note: Inverted bounds halt as soon as both bounds are constant, regardless of the value.
+                                        if (mode_a.is_constant() && mode_b.is_constant() && mode_c.is_constant())
+                                            || (halts_on_constant_bounds && mode_b.is_constant() && mode_c.is_constant())
+                                        {
                                             // Attempt to execute a failure case.
                                             let result_b = std::panic::catch_unwind(|| <$operation as $crate::Operation<_, _, _, 3>>::$execute::<CurrentAleo>(&[first, second, third]).unwrap());
                                             // Ensure the execution halted.
@@ -17,6 +17,9 @@
 mod assert;
 pub use assert::*;
 
+mod bytes;
+pub use bytes::*;
+
 mod call;
 pub use call::*;
 
@@ -26,6 +29,9 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod evaluation_error;
+pub use evaluation_error::*;
+
 mod hash;
 pub use hash::*;
 
@@ -35,6 +41,18 @@ pub use is::*;
 mod literals;
 pub use literals::*;
 
+mod load;
+pub use load::*;
+
+mod sign;
+pub use sign::*;
+
+mod pack;
+pub use pack::*;
+
+mod unpack;
+pub use unpack::*;
+
 mod macros;
 
 use crate::Opcode;
@@ -47,6 +65,12 @@ pub trait Operation<N: Network, Value: Parser + ToBits, ValueType: Parser, const
     /// Returns the result of evaluating the operation on the given inputs.
     fn evaluate(inputs: &[Value; NUM_OPERANDS]) -> Result<Value>;
 
+    /// Returns the result of evaluating the operation on the given inputs, with an error in place of a halt.
+    #[inline]
+    fn try_evaluate(inputs: &[Value; NUM_OPERANDS]) -> Result<Value, EvaluationError> {
+        EvaluationError::catch_halt(|| Self::evaluate(inputs))
+    }
+
     /// Returns the result of executing the operation on the given circuit inputs.
     fn execute<A: circuit::Aleo<Network = N>>(
         inputs: &[circuit::Literal<A>; NUM_OPERANDS],
@@ -87,7 +111,9 @@ pub type Add<N> = BinaryLiteral<N, AddOperation<N>>;
 
 crate::operation!(
     pub struct AddOperation<core::ops::Add, core::ops::Add, add, "add"> {
+        (Address, Group) => Group,
         (Field, Field) => Field,
+        (Group, Address) => Group,
         (Group, Group) => Group,
         (I8, I8) => I8 ("ensure overflows halt"),
         (I16, I16) => I16 ("ensure overflows halt"),
@@ -103,6 +129,34 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_add {
+    use super::*;
+    use circuit::Mode;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_run_binary() -> Result<()> {
+        // Ensure the harness covers every mode combination, with `constant + constant => constant`.
+        let outputs = crate::run_binary!(AddOperation<CurrentNetwork>, "1u8", "2u8");
+        assert_eq!(9, outputs.len());
+        assert_eq!((Mode::Constant, Literal::from_str("3u8")?), outputs[0]);
+        assert!(outputs[1..].iter().all(|(mode, _)| *mode == Mode::Private));
+        assert!(outputs.iter().all(|(_, output)| *output == Literal::from_str("3u8").unwrap()));
+
+        // Ensure the harness reports the modes of the special cases, e.g. `2group + 0group`.
+        let generator = console::types::Group::<CurrentNetwork>::generator();
+        let first = (generator + generator).to_string();
+        let outputs = crate::run_binary!(AddOperation<CurrentNetwork>, first, "0group");
+        assert_eq!(Mode::Constant, outputs[0].0);
+        assert!(outputs.iter().all(|(_, output)| output.to_string() == first));
+        Ok(())
+    }
+}
+
+
 /// Adds `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
 pub type AddWrapped<N> = BinaryLiteral<N, AddWrappedOperation<N>>;
 
@@ -140,6 +194,139 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_and {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_and_known_answers() -> Result<()> {
+        let evaluate = |first: &str, second: &str| -> Result<Literal<CurrentNetwork>> {
+            AndOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(first)?, Literal::from_str(second)?])
+        };
+
+        // Ensure the truth table holds.
+        for (first, second, expected) in [
+            ("false", "false", "false"),
+            ("false", "true", "false"),
+            ("true", "false", "false"),
+            ("true", "true", "true"),
+        ] {
+            assert_eq!(Literal::from_str(expected)?, evaluate(first, second)?, "'and {first} {second}'");
+        }
+
+        // Ensure integers are masked bitwise.
+        assert_eq!(Literal::from_str("0u8")?, evaluate("240u8", "15u8")?);
+        assert_eq!(Literal::from_str("255i16")?, evaluate("-1i16", "255i16")?);
+        Ok(())
+    }
+}
+
+
+/// Clamps `first` into the range from `second` to `third`, storing the outcome in `destination`.
+pub type Clamp<N> = TernaryLiteral<N, ClampOperation<N>>;
+
+crate::operation!(
+    pub struct ClampOperation<console::prelude::Clamp, circuit::prelude::Clamp, clamp, "clamp"> {
+        (I8, I8, I8) => I8 ("ensure inverted bounds halt"),
+        (I16, I16, I16) => I16 ("ensure inverted bounds halt"),
+        (I32, I32, I32) => I32 ("ensure inverted bounds halt"),
+        (I64, I64, I64) => I64 ("ensure inverted bounds halt"),
+        (I128, I128, I128) => I128 ("ensure inverted bounds halt"),
+        (U8, U8, U8) => U8 ("ensure inverted bounds halt"),
+        (U16, U16, U16) => U16 ("ensure inverted bounds halt"),
+        (U32, U32, U32) => U32 ("ensure inverted bounds halt"),
+        (U64, U64, U64) => U64 ("ensure inverted bounds halt"),
+        (U128, U128, U128) => U128 ("ensure inverted bounds halt"),
+    }
+);
+
+#[cfg(test)]
+mod tests_clamp {
+    use super::*;
+    use circuit::prelude::{Clamp, Metrics, Mode};
+    use console::{
+        network::Testnet3,
+        program::{Literal, LiteralType},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    type I8Circuit = circuit::I8<CurrentAleo>;
+    type I8Clamp = dyn Clamp<Output = I8Circuit>;
+
+    #[test]
+    fn test_clamp_known_answers() -> Result<()> {
+        // Check values below, inside, and above the range.
+        for (first, second, third, expected) in [
+            ("-5i8", "1i8", "3i8", "1i8"),
+            ("2i8", "1i8", "3i8", "2i8"),
+            ("127i8", "1i8", "3i8", "3i8"),
+            ("0u64", "7u64", "7u64", "7u64"),
+        ] {
+            let inputs = [Literal::from_str(first)?, Literal::from_str(second)?, Literal::from_str(third)?];
+            let candidate = ClampOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'clamp {first} {second} {third}'");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_clamp_inverted_bounds() -> Result<()> {
+        // Ensure inverted bounds halt when they are constant, and are unsatisfiable otherwise.
+        let inputs = ["2i8", "3i8", "1i8"].map(|input| Literal::<CurrentNetwork>::from_str(input).unwrap());
+        let result = std::panic::catch_unwind(|| ClampOperation::<CurrentNetwork>::evaluate(&inputs));
+        assert!(result.is_err());
+        for mode in [Mode::Constant, Mode::Private] {
+            let operands = [
+                circuit::program::Literal::<CurrentAleo>::from_str("2i8.private")?,
+                circuit::program::Literal::<CurrentAleo>::from_str(&format!("3i8.{mode}"))?,
+                circuit::program::Literal::<CurrentAleo>::from_str(&format!("1i8.{mode}"))?,
+            ];
+            match mode.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(|| {
+                        ClampOperation::<CurrentNetwork>::execute::<CurrentAleo>(&operands)
+                    });
+                    assert!(result.is_err());
+                }
+                false => {
+                    ClampOperation::<CurrentNetwork>::execute::<CurrentAleo>(&operands)?;
+                    assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+                }
+            }
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_clamp_count() -> Result<()> {
+        // Ensure the instruction cost matches the circuit metrics.
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("clamp r0 r1 r2 into r3;")?;
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_c in [Mode::Constant, Mode::Public, Mode::Private] {
+                    let input_types = [(LiteralType::I8, mode_a), (LiteralType::I8, mode_b), (LiteralType::I8, mode_c)];
+                    let (candidate, output_type, _) = instruction.count::<CurrentAleo>(&input_types)?;
+                    let expected = <I8Circuit as Metrics<I8Clamp>>::count(&(mode_a, mode_b, mode_c));
+                    assert_eq!(LiteralType::I8, output_type);
+                    assert_eq!(expected.0, candidate.0);
+                    assert_eq!(expected.1, candidate.1);
+                    assert_eq!(expected.2, candidate.2);
+                    assert_eq!(expected.3, candidate.3);
+                    <CurrentAleo as circuit::Environment>::reset();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+
 /// Divides `first` by `second`, storing the outcome in `destination`.
 pub type Div<N> = BinaryLiteral<N, DivOperation<N>>;
 
@@ -185,9 +372,85 @@ crate::operation!(
     pub struct DoubleOperation<console::prelude::Double, circuit::prelude::Double, double, "double"> {
         Field => Field,
         Group => Group,
+        I8 => I8 ("ensure overflows halt"),
+        I16 => I16 ("ensure overflows halt"),
+        I32 => I32 ("ensure overflows halt"),
+        I64 => I64 ("ensure overflows halt"),
+        I128 => I128 ("ensure overflows halt"),
+        U8 => U8 ("ensure overflows halt"),
+        U16 => U16 ("ensure overflows halt"),
+        U32 => U32 ("ensure overflows halt"),
+        U64 => U64 ("ensure overflows halt"),
+        U128 => U128 ("ensure overflows halt"),
     }
 );
 
+#[cfg(test)]
+mod tests_double {
+    use super::*;
+    use circuit::Environment;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_double_matches_self_addition() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let inputs = [
+                Literal::Field(console::types::Field::<CurrentNetwork>::rand(&mut rng)),
+                Literal::Group(console::types::Group::<CurrentNetwork>::rand(&mut rng)),
+            ];
+            for input in inputs {
+                // Ensure the double matches the self-addition.
+                let expected = AddOperation::<CurrentNetwork>::evaluate(&[input.clone(), input.clone()])?;
+                assert_eq!(expected, DoubleOperation::<CurrentNetwork>::evaluate(&[input.clone()])?);
+
+                for mode in &[circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+                    let a = || circuit::program::Literal::<CurrentAleo>::from_str(&format!("{input}.{mode}"));
+
+                    // Ensure the double does not synthesize more constraints than the self-addition.
+                    DoubleOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?])?;
+                    let double = CurrentAleo::num_constraints();
+                    CurrentAleo::reset();
+
+                    AddOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?, a()?])?;
+                    let add = CurrentAleo::num_constraints();
+                    CurrentAleo::reset();
+
+                    assert!(double <= add, "'double' ({double}) exceeds 'add' ({add}) for {input}.{mode}");
+                }
+            }
+        }
+
+        // Ensure unsupported operands are rejected.
+        for input in ["1scalar", "true"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            assert!(DoubleOperation::<CurrentNetwork>::evaluate(&[literal]).is_err(), "'double {input}' should fail");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_known_answers() -> Result<()> {
+        for (input, expected) in [("5field", "10field"), ("-64i8", "-128i8"), ("127u8", "254u8")] {
+            let candidate = DoubleOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(input)?])?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'double {input}'");
+        }
+
+        // Ensure integer overflows halt.
+        for input in ["128u8", "-65i8"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            let result = std::panic::catch_unwind(|| DoubleOperation::<CurrentNetwork>::evaluate(&[literal]));
+            assert!(!matches!(result, Ok(Ok(_))), "'double {input}' did not halt");
+        }
+        Ok(())
+    }
+}
+
+
 /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
 pub type GreaterThan<N> = BinaryLiteral<N, GreaterThanOperation<N>>;
 
@@ -209,6 +472,46 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_gt {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_gt_boundaries() -> Result<()> {
+        // The boundary pairs, and the expected outcome of `gt`.
+        let u128_max = format!("{}u128", u128::MAX);
+        let i128_min = format!("{}i128", i128::MIN);
+        for (first, second, expected) in [
+            ("255u8", "255u8", "false"),
+            ("254u8", "255u8", "false"),
+            (u128_max.as_str(), u128_max.as_str(), "false"),
+            ("0u128", u128_max.as_str(), "false"),
+            ("127i8", "127i8", "false"),
+            ("-1i8", "1i8", "false"),
+            ("-128i8", "127i8", "false"),
+            ("1i64", "-1i64", "true"),
+            (i128_min.as_str(), "0i128", "false"),
+            ("0field", "1field", "false"),
+            ("1scalar", "1scalar", "false"),
+        ] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            let candidate = GreaterThanOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'gt {first} {second}'");
+        }
+
+        // Ensure mismatched and unsupported operand types halt.
+        for (first, second) in [("1u8", "1u16"), ("1u8", "1i8"), ("1field", "1scalar"), ("true", "false")] {
+            let operands = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            assert!(GreaterThanOperation::<CurrentNetwork>::evaluate(&operands).is_err(), "'gt {first} {second}'");
+        }
+        Ok(())
+    }
+}
+
+
 /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
 pub type GreaterThanOrEqual<N> = BinaryLiteral<N, GreaterThanOrEqualOperation<N>>;
 
@@ -230,6 +533,40 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_gte {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_gte_boundaries() -> Result<()> {
+        // The boundary pairs, and the expected outcome of `gte`.
+        let u128_max = format!("{}u128", u128::MAX);
+        let i128_min = format!("{}i128", i128::MIN);
+        for (first, second, expected) in [
+            ("255u8", "255u8", "true"),
+            ("254u8", "255u8", "false"),
+            (u128_max.as_str(), u128_max.as_str(), "true"),
+            ("0u128", u128_max.as_str(), "false"),
+            ("127i8", "127i8", "true"),
+            ("-1i8", "1i8", "false"),
+            ("-128i8", "127i8", "false"),
+            ("1i64", "-1i64", "true"),
+            (i128_min.as_str(), "0i128", "false"),
+            ("0field", "1field", "false"),
+            ("1scalar", "1scalar", "true"),
+        ] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            let candidate = GreaterThanOrEqualOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'gte {first} {second}'");
+        }
+        Ok(())
+    }
+}
+
+
 /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
 pub type Inv<N> = UnaryLiteral<N, InvOperation<N>>;
 
@@ -239,6 +576,97 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_inv {
+    use super::*;
+    use circuit::Eject;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_inv() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Ensure the inverse of the inverse is the original element.
+            let input = Literal::Field(console::types::Field::<CurrentNetwork>::rand(&mut rng));
+            let inverse = InvOperation::<CurrentNetwork>::evaluate(&[input.clone()])?;
+            assert_eq!(input, InvOperation::<CurrentNetwork>::evaluate(&[inverse.clone()])?);
+
+            // Ensure the output mode is constant only for a constant operand.
+            for (mode, expected) in [("constant", "constant"), ("public", "private"), ("private", "private")] {
+                let a = circuit::program::Literal::<CurrentAleo>::from_str(&format!("{input}.{mode}"))?;
+                let candidate = InvOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a])?;
+                assert_eq!(inverse, candidate.eject_value(), "'inv {input}.{mode}'");
+                assert!(candidate.to_string().ends_with(&format!(".{expected}")), "'inv {input}.{mode}'");
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+
+        // Ensure the inverse of zero fails with a descriptive error.
+        let error = InvOperation::<CurrentNetwork>::evaluate(&[Literal::from_str("0field")?]).unwrap_err();
+        assert_eq!("Failed to invert a field element: 0field", error.to_string());
+
+        // Ensure non-field operands are rejected.
+        for input in ["1u8", "1scalar", "true"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            assert!(InvOperation::<CurrentNetwork>::evaluate(&[literal]).is_err(), "'inv {input}' should fail");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_inv_known_answers() -> Result<()> {
+        use circuit::prelude::{Inverse, Metrics};
+
+        type FieldCircuit = circuit::Field<CurrentAleo>;
+
+        // Ensure `inv 2field` times `2field` equals `1field`.
+        let inverse = InvOperation::<CurrentNetwork>::evaluate(&[Literal::from_str("2field")?])?;
+        let candidate = MulOperation::<CurrentNetwork>::evaluate(&[inverse, Literal::from_str("2field")?])?;
+        assert_eq!(Literal::from_str("1field")?, candidate);
+
+        // Ensure the circuit cost matches the metrics of the inverse gadget.
+        let counts = || {
+            (
+                <CurrentAleo as circuit::Environment>::num_constants(),
+                <CurrentAleo as circuit::Environment>::num_public(),
+                <CurrentAleo as circuit::Environment>::num_private(),
+                <CurrentAleo as circuit::Environment>::num_constraints(),
+            )
+        };
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            let a = circuit::program::Literal::<CurrentAleo>::from_str(&format!("2field.{mode}"))?;
+            let (constants, public, private, constraints) = counts();
+            InvOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a])?;
+            let (num_constants, num_public, num_private, num_constraints) = counts();
+            let expected = <FieldCircuit as Metrics<dyn Inverse<Output = FieldCircuit>>>::count(&mode);
+            assert!(
+                expected.matches(
+                    num_constants - constants,
+                    num_public - public,
+                    num_private - private,
+                    num_constraints - constraints
+                ),
+                "'inv 2field.{mode}'"
+            );
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+
+        // Ensure the inverse of a constant zero halts.
+        let a = circuit::program::Literal::<CurrentAleo>::from_str("0field.constant")?;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            InvOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a])
+        }));
+        assert!(!matches!(result, Ok(Ok(_))), "'inv 0field.constant' did not halt");
+        <CurrentAleo as circuit::Environment>::reset();
+        Ok(())
+    }
+}
+
+
 /// Computes whether `first` is less than `second` as a boolean, storing the outcome in `destination`.
 pub type LessThan<N> = BinaryLiteral<N, LessThanOperation<N>>;
 
@@ -260,6 +688,65 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_lt {
+    use super::*;
+    use console::{
+        network::Testnet3,
+        program::{Literal, LiteralType},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_lt_boundaries() -> Result<()> {
+        // The boundary pairs, and the expected outcome of `lt`.
+        let u128_max = format!("{}u128", u128::MAX);
+        let i128_min = format!("{}i128", i128::MIN);
+        for (first, second, expected) in [
+            ("255u8", "255u8", "false"),
+            ("254u8", "255u8", "true"),
+            (u128_max.as_str(), u128_max.as_str(), "false"),
+            ("0u128", u128_max.as_str(), "true"),
+            ("127i8", "127i8", "false"),
+            ("-1i8", "1i8", "true"),
+            ("-128i8", "127i8", "true"),
+            ("1i64", "-1i64", "false"),
+            (i128_min.as_str(), "0i128", "true"),
+            ("0field", "1field", "true"),
+            ("1scalar", "1scalar", "false"),
+        ] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            let candidate = LessThanOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'lt {first} {second}'");
+
+            // Ensure the output type is a boolean.
+            let input_types = [inputs[0].to_type(), inputs[1].to_type()];
+            assert_eq!(LiteralType::Boolean, LessThanOperation::<CurrentNetwork>::output_type(&input_types)?);
+        }
+
+        // Ensure mismatched and unsupported operand types halt.
+        for (first, second) in [("1u8", "1u16"), ("1u8", "1i8"), ("1field", "1scalar"), ("true", "false")] {
+            let operands = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            assert!(LessThanOperation::<CurrentNetwork>::evaluate(&operands).is_err(), "'lt {first} {second}'");
+        }
+
+        // Ensure a private field comparison costs more constraints than a private integer comparison.
+        let mut num_constraints = Vec::new();
+        for (first, second) in [("1u64", "2u64"), ("1field", "2field")] {
+            let a = circuit::program::Literal::from_str(&format!("{first}.private"))?;
+            let b = circuit::program::Literal::from_str(&format!("{second}.private"))?;
+            LessThanOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a, b])?;
+            num_constraints.push(<CurrentAleo as circuit::Environment>::num_constraints());
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+        assert!(num_constraints[0] < num_constraints[1]);
+        Ok(())
+    }
+}
+
+
 /// Computes whether `first` is less than or equal to `second` as a boolean, storing the outcome in `destination`.
 pub type LessThanOrEqual<N> = BinaryLiteral<N, LessThanOrEqualOperation<N>>;
 
@@ -281,6 +768,174 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_lte {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lte_boundaries() -> Result<()> {
+        // The boundary pairs, and the expected outcome of `lte`.
+        let u128_max = format!("{}u128", u128::MAX);
+        let i128_min = format!("{}i128", i128::MIN);
+        for (first, second, expected) in [
+            ("255u8", "255u8", "true"),
+            ("254u8", "255u8", "true"),
+            (u128_max.as_str(), u128_max.as_str(), "true"),
+            ("0u128", u128_max.as_str(), "true"),
+            ("127i8", "127i8", "true"),
+            ("-1i8", "1i8", "true"),
+            ("-128i8", "127i8", "true"),
+            ("1i64", "-1i64", "false"),
+            (i128_min.as_str(), "0i128", "true"),
+            ("0field", "1field", "true"),
+            ("1scalar", "1scalar", "true"),
+        ] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            let candidate = LessThanOrEqualOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'lte {first} {second}'");
+        }
+        Ok(())
+    }
+}
+
+
+/// Computes the larger of `first` and `second`, storing the outcome in `destination`.
+pub type Max<N> = BinaryLiteral<N, MaxOperation<N>>;
+
+crate::operation!(
+    pub struct MaxOperation<console::prelude::MinMax, circuit::prelude::MinMax, maximum, "max"> {
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
+#[cfg(test)]
+mod tests_max {
+    use super::*;
+    use circuit::Mode;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_max() -> Result<()> {
+        for (first, second, expected) in [
+            ("3i8", "7i8", "7i8"),
+            ("-1i64", "5i64", "5i64"),
+            ("0u128", "1u128", "1u128"),
+        ] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            let candidate = MaxOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'max {first} {second}'");
+        }
+
+        // Ensure the output is a constant only if both operands are constants.
+        let outputs = crate::run_binary!(MaxOperation<CurrentNetwork>, "3i8", "7i8");
+        assert_eq!(Mode::Constant, outputs[0].0);
+        assert!(outputs[1..].iter().all(|(mode, _)| *mode == Mode::Private));
+
+        // Ensure non-integer and mismatched operands are rejected.
+        for (first, second) in [("1field", "2field"), ("1scalar", "2scalar"), ("true", "false"), ("1u8", "1i8")] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            assert!(MaxOperation::<CurrentNetwork>::evaluate(&inputs).is_err());
+        }
+        Ok(())
+    }
+}
+
+
+/// Computes the smaller of `first` and `second`, storing the outcome in `destination`.
+pub type Min<N> = BinaryLiteral<N, MinOperation<N>>;
+
+crate::operation!(
+    pub struct MinOperation<console::prelude::MinMax, circuit::prelude::MinMax, minimum, "min"> {
+        (I8, I8) => I8,
+        (I16, I16) => I16,
+        (I32, I32) => I32,
+        (I64, I64) => I64,
+        (I128, I128) => I128,
+        (U8, U8) => U8,
+        (U16, U16) => U16,
+        (U32, U32) => U32,
+        (U64, U64) => U64,
+        (U128, U128) => U128,
+    }
+);
+
+#[cfg(test)]
+mod tests_min {
+    use super::*;
+    use circuit::prelude::{Metrics, MinMax, Mode};
+    use console::{
+        network::Testnet3,
+        program::{Literal, LiteralType},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    type I8Circuit = circuit::I8<CurrentAleo>;
+    type I8MinMax = dyn MinMax<I8Circuit, Output = I8Circuit>;
+
+    #[test]
+    fn test_min() -> Result<()> {
+        for (first, second, expected) in [
+            ("3i8", "7i8", "3i8"),
+            ("-1i64", "5i64", "-1i64"),
+            ("255u8", "255u8", "255u8"),
+        ] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            let candidate = MinOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'min {first} {second}'");
+        }
+
+        // Ensure the output is a constant only if both operands are constants.
+        let outputs = crate::run_binary!(MinOperation<CurrentNetwork>, "3i8", "7i8");
+        assert_eq!(Mode::Constant, outputs[0].0);
+        assert!(outputs[1..].iter().all(|(mode, _)| *mode == Mode::Private));
+
+        // Ensure non-integer and mismatched operands are rejected.
+        for (first, second) in [("1field", "2field"), ("1scalar", "2scalar"), ("true", "false"), ("1u8", "1i8")] {
+            let inputs = [Literal::<CurrentNetwork>::from_str(first)?, Literal::from_str(second)?];
+            assert!(MinOperation::<CurrentNetwork>::evaluate(&inputs).is_err());
+            assert!(MinOperation::<CurrentNetwork>::output_type(&[inputs[0].to_type(), inputs[1].to_type()]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_count() -> Result<()> {
+        // Ensure the instruction cost matches the comparator and select gadgets in the circuit.
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("min r0 r1 into r2;")?;
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let input_types = [(LiteralType::I8, mode_a), (LiteralType::I8, mode_b)];
+                let (candidate, output_type, _) = instruction.count::<CurrentAleo>(&input_types)?;
+                let expected = <I8Circuit as Metrics<I8MinMax>>::count(&(mode_a, mode_b));
+                assert_eq!(LiteralType::I8, output_type);
+                assert_eq!(expected.0, candidate.0);
+                assert_eq!(expected.1, candidate.1);
+                assert_eq!(expected.2, candidate.2);
+                assert_eq!(expected.3, candidate.3);
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+}
+
+
 /// Computes the result of `first` mod `second`, storing the outcome in the destination.
 pub type Modulo<N> = BinaryLiteral<N, ModuloOperation<N>>;
 
@@ -294,6 +949,52 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_mod {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_mod() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let evaluate = |first: &str, second: &str| -> Result<Literal<CurrentNetwork>> {
+            ModuloOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(first)?, Literal::from_str(second)?])
+        };
+
+        // Ensure the known answers hold.
+        assert_eq!(Literal::from_str("1u8")?, evaluate("7u8", "3u8")?);
+        assert_eq!(Literal::from_str("3u8")?, evaluate("3u8", "7u8")?);
+        assert_eq!(Literal::from_str("0u8")?, evaluate("255u8", "255u8")?);
+
+        // Ensure a power-of-two modulus masks the low bits.
+        for k in 0..64 {
+            let (value, modulus) = (u64::rand(&mut rng), 1u64 << k);
+            let expected = Literal::from_str(&format!("{}u64", value & (modulus - 1)))?;
+            let candidate = evaluate(&format!("{value}u64"), &format!("{modulus}u64"))?;
+            assert_eq!(expected, candidate, "'mod {value}u64 {modulus}u64'");
+        }
+
+        // Ensure a zero modulus halts.
+        let result = std::panic::catch_unwind(|| evaluate("7u8", "0u8"));
+        assert!(!matches!(result, Ok(Ok(_))), "'mod 7u8 0u8' did not halt");
+
+        // Ensure signed integers and fields are rejected.
+        for (first, second) in [("7i8", "3i8"), ("7field", "3field")] {
+            assert!(evaluate(first, second).is_err(), "'mod {first} {second}'");
+        }
+
+        // Ensure the instruction parses and serializes.
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("mod r0 r1 into r2;")?;
+        assert_eq!("mod r0 r1 into r2;", instruction.to_string());
+        assert_eq!(instruction, crate::Instruction::from_bytes_le(&instruction.to_bytes_le()?)?);
+        Ok(())
+    }
+}
+
+
 /// Multiplies `first` and `second`, storing the outcome in `destination`.
 pub type Mul<N> = BinaryLiteral<N, MulOperation<N>>;
 
@@ -316,6 +1017,222 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_mul {
+    use super::*;
+    use circuit::{
+        prelude::{CircuitType, Metrics, Mode, OutputMode},
+        Eject,
+    };
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    type GroupCircuit = circuit::Group<CurrentAleo>;
+    type ScalarCircuit = circuit::Scalar<CurrentAleo>;
+    type GroupMul = dyn core::ops::Mul<ScalarCircuit, Output = GroupCircuit>;
+
+    #[test]
+    fn test_mul_group_scalar() -> Result<()> {
+        let generator = console::types::Group::<CurrentNetwork>::generator();
+        let first = (generator + generator).to_string();
+
+        // Ensure `2group * 1scalar` and `1scalar * 2group` compute `2group`.
+        for (a, b) in [(first.as_str(), "1scalar"), ("1scalar", first.as_str())] {
+            let candidate = MulOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(a)?, Literal::from_str(b)?])?;
+            assert_eq!(first, candidate.to_string());
+        }
+
+        // Ensure `2group * 1scalar` preserves the mode of `2group` when `1scalar` is a constant, as with `add`.
+        let outputs = crate::run_binary!(MulOperation<CurrentNetwork>, first, "1scalar");
+        let modes = outputs.iter().map(|(mode, _)| *mode).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Mode::Constant,
+                Mode::Private,
+                Mode::Private,
+                Mode::Public,
+                Mode::Private,
+                Mode::Private,
+                Mode::Private,
+                Mode::Private,
+                Mode::Private
+            ],
+            modes
+        );
+        assert!(outputs.iter().all(|(_, output)| output.to_string() == first));
+
+        // Ensure the circuit count and output mode of `2group * 1scalar` match the synthesized operation.
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = circuit::program::Literal::<CurrentAleo>::from_str(&format!("{first}.{mode_a}"))?;
+                let b = circuit::program::Literal::<CurrentAleo>::from_str(&format!("1scalar.{mode_b}"))?;
+                let case = match (&a, &b) {
+                    (circuit::program::Literal::Group(a), circuit::program::Literal::Scalar(b)) => {
+                        (CircuitType::from(a), CircuitType::from(b))
+                    }
+                    _ => unreachable!("The operands are a group and a scalar"),
+                };
+
+                let (num_constants, num_public, num_private, num_constraints, _) =
+                    <CurrentAleo as circuit::Environment>::count();
+                let candidate = MulOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a, b])?;
+                let (constants, public, private, constraints, _) = <CurrentAleo as circuit::Environment>::count();
+                let (constants, public, private, constraints) = (
+                    constants - num_constants,
+                    public - num_public,
+                    private - num_private,
+                    constraints - num_constraints,
+                );
+
+                // Ensure the operation is not free, and the count and output mode match.
+                assert!(constants + public + private + constraints > 0, "{mode_a} * {mode_b}");
+                let count = <GroupCircuit as Metrics<GroupMul>>::count(&case);
+                assert!(count.matches(constants, public, private, constraints), "{mode_a} * {mode_b}");
+                let output_mode = <GroupCircuit as OutputMode<GroupMul>>::output_mode(&case);
+                assert_eq!(output_mode, candidate.eject_mode(), "{mode_a} * {mode_b}");
+
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+        Ok(())
+    }
+}
+
+
+/// Multiplies the generator `G` by `first`, storing the outcome in `destination`.
+pub type MulGenerator<N> = UnaryLiteral<N, MulGeneratorOperation<N>>;
+
+/// The implementation of the generator multiplication, which uses the fixed-base bases of the network.
+/// Note: This is not defined with `operation!`, as the bases are provided by the network, not the scalar.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MulGeneratorOperation<N: Network>(core::marker::PhantomData<N>);
+
+impl<N: Network> Operation<N, console::program::Literal<N>, console::program::LiteralType, 1>
+    for MulGeneratorOperation<N>
+{
+    /// The opcode of the operation.
+    const OPCODE: Opcode = Opcode::Literal("mul.gen");
+
+    /// Returns the result of evaluating the operation on the given inputs.
+    #[inline]
+    fn evaluate(inputs: &[console::program::Literal<N>; 1]) -> Result<console::program::Literal<N>> {
+        match inputs {
+            [console::program::Literal::Scalar(scalar)] => {
+                Ok(console::program::Literal::Group(N::g_scalar_multiply(scalar)))
+            }
+            _ => bail!("Invalid operand for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the result of executing the operation on the given circuit inputs.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(inputs: &[circuit::Literal<A>; 1]) -> Result<circuit::Literal<A>> {
+        match inputs {
+            [circuit::Literal::Scalar(scalar)] => Ok(circuit::Literal::Group(A::g_scalar_multiply(scalar))),
+            _ => bail!("Invalid operand for the '{}' instruction", Self::OPCODE),
+        }
+    }
+
+    /// Returns the output type from the given input types.
+    #[inline]
+    fn output_type(inputs: &[console::program::LiteralType; 1]) -> Result<console::program::LiteralType> {
+        match inputs {
+            [console::program::LiteralType::Scalar] => Ok(console::program::LiteralType::Group),
+            _ => bail!("Invalid operand types for the '{}' instruction", Self::OPCODE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_mul_gen {
+    use super::*;
+    use circuit::{Aleo, Eject, Environment, Inject, Measurement, Mode};
+    use console::{
+        network::Testnet3,
+        program::{Literal, LiteralType},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_mul_generator() -> Result<()> {
+        let mut rng = TestRng::default();
+        let generator = Literal::Group(CurrentNetwork::g_powers()[0]);
+
+        // Ensure the output matches the variable-base multiplication of the generator, in every mode.
+        let scalar = console::types::Scalar::<CurrentNetwork>::rand(&mut rng);
+        for input in ["0scalar".to_string(), "1scalar".to_string(), "5scalar".to_string(), format!("{scalar}")] {
+            let literal = Literal::<CurrentNetwork>::from_str(&input)?;
+            let expected = MulOperation::<CurrentNetwork>::evaluate(&[generator.clone(), literal.clone()])?;
+            assert_eq!(expected, MulGeneratorOperation::<CurrentNetwork>::evaluate(&[literal])?, "'mul.gen {input}'");
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = circuit::program::Literal::<CurrentAleo>::from_str(&format!("{input}.{mode}"))?;
+                let candidate = MulGeneratorOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a])?;
+                assert_eq!(expected, candidate.eject_value(), "'mul.gen {input}.{mode}'");
+                assert!(CurrentAleo::is_satisfied());
+                CurrentAleo::reset();
+            }
+        }
+        let candidate = MulGeneratorOperation::<CurrentNetwork>::evaluate(&[Literal::from_str("1scalar")?])?;
+        assert_eq!(generator, candidate);
+
+        // Ensure non-scalar operands are rejected.
+        for input in ["1field", "0group", "1u8", "true"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            assert!(MulGeneratorOperation::<CurrentNetwork>::output_type(&[literal.to_type()]).is_err());
+            assert!(MulGeneratorOperation::<CurrentNetwork>::evaluate(&[literal]).is_err(), "'mul.gen {input}'");
+        }
+
+        // Ensure the instruction parses, and does not parse as the variable-base multiplication.
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("mul.gen r0 into r1;")?;
+        assert_eq!("mul.gen r0 into r1;", instruction.to_string());
+        assert_eq!(instruction, crate::Instruction::from_bytes_le(&instruction.to_bytes_le()?)?);
+        assert!(Mul::<CurrentNetwork>::from_str("mul.gen r0 into r1").is_err());
+
+        // Ensure the cost matches the fixed-base gadget, and is lower than the variable-base multiplication.
+        // Note: The bases of the gadget were injected above, so they are not counted again here.
+        let mul = crate::Instruction::<CurrentNetwork>::from_str("mul r0 r1 into r2;")?;
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let (candidate, output_type, output_mode) =
+                instruction.count::<CurrentAleo>(&[(LiteralType::Scalar, mode)])?;
+            assert_eq!(LiteralType::Group, output_type);
+            // Note: The number of constants depends on the value of a constant scalar, so only the variables are checked.
+            if mode.is_constant() {
+                assert_eq!(Mode::Constant, output_mode);
+                assert!(candidate.1.matches(0) && candidate.2.matches(0) && candidate.3.matches(0));
+                continue;
+            }
+            assert_eq!(Mode::Private, output_mode);
+
+            let scalar = circuit::Scalar::<CurrentAleo>::new(mode, console::types::Scalar::one());
+            let (num_constants, num_public, num_private, num_constraints, _) = CurrentAleo::count();
+            CurrentAleo::g_scalar_multiply(&scalar);
+            let (constants, public, private, constraints, _) = CurrentAleo::count();
+            assert!(candidate.matches(
+                constants - num_constants,
+                public - num_public,
+                private - num_private,
+                constraints - num_constraints
+            ));
+            CurrentAleo::reset();
+
+            let input_types = [(LiteralType::Group, Mode::Constant), (LiteralType::Scalar, mode)];
+            let (variable_base, ..) = mul.count::<CurrentAleo>(&input_types)?;
+            match (candidate.3, variable_base.3) {
+                (Measurement::Exact(fixed), Measurement::Exact(variable)) => assert!(fixed < variable),
+                _ => panic!("Expected exact constraint counts"),
+            }
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+}
+
+
 /// Multiplies `first` and `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
 pub type MulWrapped<N> = BinaryLiteral<N, MulWrappedOperation<N>>;
 
@@ -343,6 +1260,59 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_nand {
+    use super::*;
+    use circuit::Environment;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_nand_truth_table() -> Result<()> {
+        let evaluate = |first: &str, second: &str| -> Result<Literal<CurrentNetwork>> {
+            NandOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(first)?, Literal::from_str(second)?])
+        };
+
+        for (first, second, expected) in [
+            ("false", "false", "true"),
+            ("false", "true", "true"),
+            ("true", "false", "true"),
+            ("true", "true", "false"),
+        ] {
+            assert_eq!(Literal::from_str(expected)?, evaluate(first, second)?, "'nand {first} {second}'");
+        }
+
+        // Ensure non-boolean operands are rejected.
+        assert!(evaluate("1u8", "2u8").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_nand_constraints_do_not_exceed_composition() -> Result<()> {
+        let a = || circuit::program::Literal::<CurrentAleo>::from_str("true.private");
+        let b = || circuit::program::Literal::<CurrentAleo>::from_str("false.private");
+
+        // Counts the constraints synthesized by the given closure.
+        let count = |f: &dyn Fn() -> Result<()>| -> Result<u64> {
+            f()?;
+            let num_constraints = CurrentAleo::num_constraints();
+            CurrentAleo::reset();
+            Ok(num_constraints)
+        };
+
+        let nand = count(&|| NandOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?, b()?]).map(|_| ()))?;
+        let not_and = count(&|| {
+            let output = AndOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?, b()?])?;
+            NotOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[output]).map(|_| ())
+        })?;
+        assert!(nand <= not_and, "'nand' ({nand}) exceeds 'not' + 'and' ({not_and})");
+        Ok(())
+    }
+}
+
+
 /// Negates `first`, storing the outcome in `destination`.
 pub type Neg<N> = UnaryLiteral<N, NegOperation<N>>;
 
@@ -358,6 +1328,55 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_neg {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_neg_matches_literal_neg_checked() -> Result<()> {
+        for input in ["5i8", "-5i8", "0i8", "-128i8", "-170141183460469231731687303715884105728i128", "1field"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            match literal.neg_checked() {
+                // Ensure the instruction matches the host negation.
+                Ok(expected) => assert_eq!(expected, NegOperation::<CurrentNetwork>::evaluate(&[literal])?),
+                // Ensure the instruction halts when the host negation overflows.
+                Err(_) => {
+                    let result = std::panic::catch_unwind(|| NegOperation::<CurrentNetwork>::evaluate(&[literal]));
+                    assert!(!matches!(result, Ok(Ok(_))), "'neg {input}' did not halt");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_neg_group_and_unsupported_types() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure negating a group element returns its inverse.
+        let group = console::types::Group::<CurrentNetwork>::rand(&mut rng);
+        match NegOperation::<CurrentNetwork>::evaluate(&[Literal::Group(group)])? {
+            Literal::Group(candidate) => {
+                assert_eq!(-group, candidate);
+                assert_eq!(console::types::Group::zero(), group + candidate);
+            }
+            candidate => panic!("Expected a group element, found '{candidate}'"),
+        }
+
+        // Ensure unsupported types are rejected.
+        let address = "aleo1wfyyj2uvwuqw0c0dqa5x70wrawnlkkvuepn4y08xyaqfqqwweqys39jayw";
+        for input in ["1u8", "1u128", "true", address] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            assert!(NegOperation::<CurrentNetwork>::evaluate(&[literal]).is_err(), "'neg {input}' should fail");
+        }
+        Ok(())
+    }
+}
+
+
 /// Returns `true` if neither `first` nor `second` is `true`, storing the outcome in `destination`.
 pub type Nor<N> = BinaryLiteral<N, NorOperation<N>>;
 
@@ -367,6 +1386,59 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_nor {
+    use super::*;
+    use circuit::Environment;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_nor_truth_table() -> Result<()> {
+        let evaluate = |first: &str, second: &str| -> Result<Literal<CurrentNetwork>> {
+            NorOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(first)?, Literal::from_str(second)?])
+        };
+
+        for (first, second, expected) in [
+            ("false", "false", "true"),
+            ("false", "true", "false"),
+            ("true", "false", "false"),
+            ("true", "true", "false"),
+        ] {
+            assert_eq!(Literal::from_str(expected)?, evaluate(first, second)?, "'nor {first} {second}'");
+        }
+
+        // Ensure non-boolean operands are rejected.
+        assert!(evaluate("1u8", "2u8").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_nor_constraints_do_not_exceed_composition() -> Result<()> {
+        let a = || circuit::program::Literal::<CurrentAleo>::from_str("true.private");
+        let b = || circuit::program::Literal::<CurrentAleo>::from_str("false.private");
+
+        // Counts the constraints synthesized by the given closure.
+        let count = |f: &dyn Fn() -> Result<()>| -> Result<u64> {
+            f()?;
+            let num_constraints = CurrentAleo::num_constraints();
+            CurrentAleo::reset();
+            Ok(num_constraints)
+        };
+
+        let nor = count(&|| NorOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?, b()?]).map(|_| ()))?;
+        let not_or = count(&|| {
+            let output = OrOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?, b()?])?;
+            NotOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[output]).map(|_| ())
+        })?;
+        assert!(nor <= not_or, "'nor' ({nor}) exceeds 'not' + 'or' ({not_or})");
+        Ok(())
+    }
+}
+
+
 /// Flips each bit in the representation of `first`, storing the outcome in `destination`.
 pub type Not<N> = UnaryLiteral<N, NotOperation<N>>;
 
@@ -386,6 +1458,31 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_not {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_not() -> Result<()> {
+        for (input, expected) in [("true", "false"), ("false", "true"), ("0u8", "255u8"), ("0i8", "-1i8")] {
+            let candidate = NotOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(input)?])?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'not {input}'");
+        }
+
+        // Ensure a constant operand yields a constant output.
+        let a = circuit::program::Literal::from_str("true.constant")?;
+        let candidate = NotOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a])?;
+        assert_eq!("false.constant", candidate.to_string());
+        <CurrentAleo as circuit::Environment>::reset();
+        Ok(())
+    }
+}
+
+
 /// Performs a bitwise `or` on `first` and `second`, storing the outcome in `destination`.
 pub type Or<N> = BinaryLiteral<N, OrOperation<N>>;
 
@@ -405,6 +1502,36 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_or {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_or_known_answers() -> Result<()> {
+        let evaluate = |first: &str, second: &str| -> Result<Literal<CurrentNetwork>> {
+            OrOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(first)?, Literal::from_str(second)?])
+        };
+
+        // Ensure the truth table holds.
+        for (first, second, expected) in [
+            ("false", "false", "false"),
+            ("false", "true", "true"),
+            ("true", "false", "true"),
+            ("true", "true", "true"),
+        ] {
+            assert_eq!(Literal::from_str(expected)?, evaluate(first, second)?, "'or {first} {second}'");
+        }
+
+        // Ensure integers are combined bitwise.
+        assert_eq!(Literal::from_str("255u8")?, evaluate("240u8", "15u8")?);
+        Ok(())
+    }
+}
+
+
 /// Raises `first` to the power of `second`, storing the outcome in `destination`.
 pub type Pow<N> = BinaryLiteral<N, PowOperation<N>>;
 
@@ -676,9 +1803,77 @@ pub type Square<N> = UnaryLiteral<N, SquareOperation<N>>;
 crate::operation!(
     pub struct SquareOperation<console::prelude::Square, circuit::prelude::Square, square, "square"> {
         Field => Field,
+        I8 => I8 ("ensure overflows halt"),
+        I16 => I16 ("ensure overflows halt"),
+        I32 => I32 ("ensure overflows halt"),
+        I64 => I64 ("ensure overflows halt"),
+        I128 => I128 ("ensure overflows halt"),
+        U8 => U8 ("ensure overflows halt"),
+        U16 => U16 ("ensure overflows halt"),
+        U32 => U32 ("ensure overflows halt"),
+        U64 => U64 ("ensure overflows halt"),
+        U128 => U128 ("ensure overflows halt"),
     }
 );
 
+#[cfg(test)]
+mod tests_square {
+    use super::*;
+    use circuit::Environment;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_square_matches_self_multiplication() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Ensure the square matches the self-multiplication.
+            let input = Literal::Field(console::types::Field::<CurrentNetwork>::rand(&mut rng));
+            let expected = MulOperation::<CurrentNetwork>::evaluate(&[input.clone(), input.clone()])?;
+            assert_eq!(expected, SquareOperation::<CurrentNetwork>::evaluate(&[input.clone()])?);
+
+            for mode in &[circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+                let a = || circuit::program::Literal::<CurrentAleo>::from_str(&format!("{input}.{mode}"));
+
+                // Ensure the square does not synthesize more constraints than the self-multiplication.
+                SquareOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?])?;
+                let square = CurrentAleo::num_constraints();
+                CurrentAleo::reset();
+
+                MulOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a()?, a()?])?;
+                let mul = CurrentAleo::num_constraints();
+                CurrentAleo::reset();
+
+                assert!(square <= mul, "'square' ({square}) exceeds 'mul' ({mul}) in {mode} mode");
+            }
+        }
+
+        // Ensure unsupported operands are rejected.
+        assert!(SquareOperation::<CurrentNetwork>::evaluate(&[Literal::from_str("true")?]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_square_known_answers() -> Result<()> {
+        for (input, expected) in [("3i64", "9i64"), ("-3i64", "9i64"), ("15u8", "225u8")] {
+            let candidate = SquareOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(input)?])?;
+            assert_eq!(Literal::from_str(expected)?, candidate, "'square {input}'");
+        }
+
+        // Ensure integer overflows halt.
+        for input in ["16u8", "12i8"] {
+            let literal = Literal::<CurrentNetwork>::from_str(input)?;
+            let result = std::panic::catch_unwind(|| SquareOperation::<CurrentNetwork>::evaluate(&[literal]));
+            assert!(!matches!(result, Ok(Ok(_))), "'square {input}' did not halt");
+        }
+        Ok(())
+    }
+}
+
+
 /// Computes the square root of `first`, storing the outcome in `destination`.
 pub type SquareRoot<N> = UnaryLiteral<N, SquareRootOperation<N>>;
 
@@ -688,6 +1883,58 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_sqrt {
+    use super::*;
+    use circuit::Eject;
+    use console::{
+        network::Testnet3,
+        prelude::{SquareRoot, Uniform},
+        program::Literal,
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_sqrt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure zero is its own square root.
+        let zero = Literal::<CurrentNetwork>::from_str("0field")?;
+        assert_eq!(zero, SquareRootOperation::<CurrentNetwork>::evaluate(&[zero.clone()])?);
+
+        for _ in 0..10 {
+            // Ensure the square root of a perfect square is a root.
+            let root: console::types::Field<CurrentNetwork> = Uniform::rand(&mut rng);
+            let input = Literal::Field(root * root);
+            let candidate = SquareRootOperation::<CurrentNetwork>::evaluate(&[input.clone()])?;
+            assert!(candidate == Literal::Field(root) || candidate == Literal::Field(-root));
+
+            // Ensure the output mode is propagated from the operand.
+            for (mode, expected) in [("constant", "constant"), ("public", "private"), ("private", "private")] {
+                let a = circuit::program::Literal::<CurrentAleo>::from_str(&format!("{input}.{mode}"))?;
+                let output = SquareRootOperation::<CurrentNetwork>::execute::<CurrentAleo>(&[a])?;
+                assert_eq!(candidate, output.eject_value(), "'sqrt {input}.{mode}'");
+                assert!(output.to_string().ends_with(&format!(".{expected}")), "'sqrt {input}.{mode}'");
+                <CurrentAleo as circuit::Environment>::reset();
+            }
+        }
+
+        // Ensure a quadratic nonresidue halts.
+        let nonresidue = loop {
+            let candidate: console::types::Field<CurrentNetwork> = Uniform::rand(&mut rng);
+            if candidate.square_root().is_err() {
+                break Literal::Field(candidate);
+            }
+        };
+        let result = std::panic::catch_unwind(|| SquareRootOperation::<CurrentNetwork>::evaluate(&[nonresidue]));
+        assert!(!matches!(result, Ok(Ok(_))), "'sqrt' of a nonresidue did not halt");
+        Ok(())
+    }
+}
+
+
 /// Computes `first - second`, storing the outcome in `destination`.
 pub type Sub<N> = BinaryLiteral<N, SubOperation<N>>;
 
@@ -709,6 +1956,51 @@ crate::operation!(
     }
 );
 
+#[cfg(test)]
+mod tests_sub {
+    use super::*;
+    use circuit::Mode;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sub_group() -> Result<()> {
+        let generator = console::types::Group::<CurrentNetwork>::generator();
+        let first = (generator + generator).to_string();
+
+        // Ensure group subtraction is computed as `a + (-b)`, rather than halting.
+        let cases = [(generator.to_string(), generator.to_string()), ("0group".to_string(), first.clone())];
+        for (second, expected) in cases {
+            let inputs = [Literal::<CurrentNetwork>::from_str(&first)?, Literal::from_str(&second)?];
+            let candidate = SubOperation::<CurrentNetwork>::evaluate(&inputs)?;
+            assert_eq!(expected, candidate.to_string());
+            let outputs = crate::run_binary!(SubOperation<CurrentNetwork>, first, second);
+            assert!(outputs.iter().all(|(_, output)| output.to_string() == expected));
+        }
+
+        // Ensure `2group - 0group` preserves the mode of `2group` when `0group` is a constant, as with `add`.
+        let outputs = crate::run_binary!(SubOperation<CurrentNetwork>, first, "0group");
+        let modes = outputs.iter().map(|(mode, _)| *mode).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Mode::Constant,
+                Mode::Private,
+                Mode::Private,
+                Mode::Public,
+                Mode::Private,
+                Mode::Private,
+                Mode::Private,
+                Mode::Private,
+                Mode::Private
+            ],
+            modes
+        );
+        Ok(())
+    }
+}
+
+
 /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
 pub type SubWrapped<N> = BinaryLiteral<N, SubWrappedOperation<N>>;
 
@@ -769,3 +2061,33 @@ crate::operation!(
         (U128, U128) => U128,
     }
 );
+
+#[cfg(test)]
+mod tests_xor {
+    use super::*;
+    use console::{network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_xor_known_answers() -> Result<()> {
+        let evaluate = |first: &str, second: &str| -> Result<Literal<CurrentNetwork>> {
+            XorOperation::<CurrentNetwork>::evaluate(&[Literal::from_str(first)?, Literal::from_str(second)?])
+        };
+
+        // Ensure the truth table holds.
+        for (first, second, expected) in [
+            ("false", "false", "false"),
+            ("false", "true", "true"),
+            ("true", "false", "true"),
+            ("true", "true", "false"),
+        ] {
+            assert_eq!(Literal::from_str(expected)?, evaluate(first, second)?, "'xor {first} {second}'");
+        }
+
+        // Ensure integers are flipped bitwise.
+        assert_eq!(Literal::from_str("240u8")?, evaluate("255u8", "15u8")?);
+        assert_eq!(Literal::from_str("-256i16")?, evaluate("-1i16", "255i16")?);
+        Ok(())
+    }
+}
@@ -0,0 +1,494 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    network::prelude::*,
+    program::{Identifier, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+use indexmap::IndexMap;
+
+/// Packs the operands into a struct of the given name, storing the outcome in `destination`.
+/// i.e. `pack message r0 r1 into r2;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Pack<N: Network> {
+    /// The name of the struct.
+    struct_name: Identifier<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Pack<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Pack
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the name of the struct.
+    #[inline]
+    pub const fn struct_name(&self) -> &Identifier<N> {
+        &self.struct_name
+    }
+}
+
+impl<N: Network> Pack<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Load the operands values.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Retrieve the struct and ensure it is defined in the program.
+        let struct_ = stack.program().get_struct(&self.struct_name)?;
+        // Ensure there is one operand for each struct member.
+        ensure!(
+            inputs.len() == struct_.members().len(),
+            "Instruction '{}' expects {} operands for '{}', found {} operands",
+            Self::opcode(),
+            struct_.members().len(),
+            struct_.name(),
+            inputs.len()
+        );
+
+        // Initialize the struct members.
+        let mut members = IndexMap::new();
+        for (member, (member_name, member_type)) in inputs.into_iter().zip_eq(struct_.members()) {
+            // Retrieve the plaintext value from the entry.
+            let plaintext = match member {
+                Value::Plaintext(plaintext) => plaintext,
+                // Ensure the struct member is not a record.
+                Value::Record(..) => bail!("Packing a record into a struct member is illegal"),
+            };
+            // Ensure the member matches the member type.
+            stack.matches_register_type(
+                &Value::Plaintext(plaintext.clone()),
+                &RegisterType::Plaintext(*member_type),
+            )?;
+            // Append the member to the struct members.
+            members.insert(*member_name, plaintext);
+        }
+
+        // Store the struct.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::Struct(members, Default::default())))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        use circuit::{Eject, Inject};
+
+        // Load the operands values.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+
+        // Retrieve the struct and ensure it is defined in the program.
+        let struct_ = stack.program().get_struct(&self.struct_name)?;
+        // Ensure there is one operand for each struct member.
+        ensure!(
+            inputs.len() == struct_.members().len(),
+            "Instruction '{}' expects {} operands for '{}', found {} operands",
+            Self::opcode(),
+            struct_.members().len(),
+            struct_.name(),
+            inputs.len()
+        );
+
+        // Initialize the struct members.
+        let mut members = IndexMap::new();
+        for (member, (member_name, member_type)) in inputs.into_iter().zip_eq(struct_.members()) {
+            // Retrieve the plaintext value from the entry.
+            let plaintext = match member {
+                circuit::Value::Plaintext(plaintext) => plaintext,
+                // Ensure the struct member is not a record.
+                circuit::Value::Record(..) => bail!("Packing a record into a struct member is illegal"),
+            };
+            // Ensure the member matches the member type.
+            stack.matches_register_type(
+                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                &RegisterType::Plaintext(*member_type),
+            )?;
+            // Append the member to the struct members.
+            members.insert(circuit::Identifier::constant(*member_name), plaintext);
+        }
+
+        // Store the struct.
+        let struct_ = circuit::Plaintext::Struct(members, Default::default());
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
+    }
+
+    /// Returns the circuit cost of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        _input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode())
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != self.operands.len() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                self.operands.len(),
+                input_types.len()
+            )
+        }
+
+        // Retrieve the struct and ensure it is defined in the program.
+        let struct_ = stack.program().get_struct(&self.struct_name)?;
+        // Ensure there is one operand for each struct member.
+        if struct_.members().len() != input_types.len() {
+            bail!(
+                "Instruction '{}' expects {} operands for '{}', found {} operands",
+                Self::opcode(),
+                struct_.members().len(),
+                struct_.name(),
+                input_types.len()
+            )
+        }
+
+        // Ensure the input types match the member types.
+        for ((member_name, member_type), input_type) in struct_.members().iter().zip_eq(input_types) {
+            match input_type {
+                RegisterType::Plaintext(plaintext_type) => ensure!(
+                    member_type == plaintext_type,
+                    "Struct '{}' member '{member_name}' expects '{member_type}', found '{plaintext_type}'",
+                    struct_.name()
+                ),
+                input_type => bail!(
+                    "Struct '{}' member '{member_name}' expects '{member_type}', found '{input_type}'",
+                    struct_.name()
+                ),
+            }
+        }
+
+        // Output the struct type.
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Struct(self.struct_name))])
+    }
+}
+
+impl<N: Network> Parser for Pack<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the struct name from the string.
+        let (string, struct_name) = context("struct name", Identifier::parse)(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many1(parse_operand), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_DATA_ENTRIES {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'pack' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = context("'into'", tag("into"))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = context("register", Register::parse)(string)?;
+
+        Ok((string, Self { struct_name, operands, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Pack<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Pack<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Pack<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_DATA_ENTRIES {
+            eprintln!("The number of operands must be nonzero and <= {}", N::MAX_DATA_ENTRIES);
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {}", Self::opcode(), self.struct_name)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, " into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Pack<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the struct name.
+        let struct_name = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands.is_zero() || num_operands > N::MAX_DATA_ENTRIES {
+            return Err(error(format!("The number of operands must be nonzero and <= {}", N::MAX_DATA_ENTRIES)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { struct_name, operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Pack<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_DATA_ENTRIES {
+            return Err(error(format!("The number of operands must be nonzero and <= {}", N::MAX_DATA_ENTRIES)));
+        }
+
+        // Write the struct name.
+        self.struct_name.write_le(&mut writer)?;
+        // Write the number of operands.
+        (self.operands.len() as u8).write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use console::{
+        network::Testnet3,
+        types::{Address, Field},
+    };
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Evaluates the closure `run` in the given program on the given inputs.
+    fn evaluate(program: &str, inputs: &[&str]) -> Result<Vec<Value<CurrentNetwork>>> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = Program::from_str(program)?;
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+        let closure = program.get_closure(&Identifier::from_str("run")?)?;
+
+        // Evaluate the closure.
+        let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>()?;
+        stack.evaluate_closure::<CurrentAleo>(
+            &closure,
+            &inputs,
+            CallStack::evaluate(Authorization::new(&[]))?,
+            Address::new(Uniform::rand(&mut rng)),
+            Field::rand(&mut rng),
+        )
+    }
+
+    #[test]
+    fn test_pack() -> Result<()> {
+        let program = r"program testing.aleo;
+struct message:
+    first as field;
+    second as u8;
+closure run:
+    input r0 as field;
+    input r1 as u8;
+    pack message r0 r1 into r2;
+    unpack r2 into r3 r4;
+    output r2 as message;
+    output r3 as field;
+    output r4 as u8;";
+
+        // Ensure packing and unpacking a struct round-trips its members.
+        let outputs = evaluate(program, &["1field", "2u8"])?;
+        let expected = ["{ first: 1field, second: 2u8 }", "1field", "2u8"]
+            .into_iter()
+            .map(Value::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(outputs, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pack_nested() -> Result<()> {
+        let program = r"program testing.aleo;
+struct message:
+    first as field;
+    second as u8;
+struct envelope:
+    inner as message;
+    flag as boolean;
+closure run:
+    input r0 as field;
+    input r1 as u8;
+    input r2 as boolean;
+    pack message r0 r1 into r3;
+    pack envelope r3 r2 into r4;
+    unpack r4 into r5 r6;
+    output r4 as envelope;
+    output r5 as message;
+    output r6 as boolean;";
+
+        // Ensure packing a nested struct round-trips through unpacking.
+        let outputs = evaluate(program, &["1field", "2u8", "true"])?;
+        let expected =
+            ["{ inner: { first: 1field, second: 2u8 }, flag: true }", "{ first: 1field, second: 2u8 }", "true"]
+                .into_iter()
+                .map(Value::from_str)
+                .collect::<Result<Vec<_>>>()?;
+        assert_eq!(outputs, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_mismatch() -> Result<()> {
+        let process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+
+        for (instructions, is_ok) in [
+            ("pack message r0 r1 into r2;", true),
+            // Ensure the number of operands must match the number of members.
+            ("pack message r0 into r2;", false),
+            ("pack message r0 r1 r1 into r2;", false),
+            // Ensure the operand types must match the member types.
+            ("pack message r1 r0 into r2;", false),
+            ("pack message r0 r0 into r2;", false),
+            // Ensure the struct must be defined in the program.
+            ("pack envelope r0 r1 into r2;", false),
+        ] {
+            let program = Program::from_str(&format!(
+                "program testing.aleo;
+                struct message:
+                    first as field;
+                    second as u8;
+                closure run:
+                    input r0 as field;
+                    input r1 as u8;
+                    {instructions}
+                    output r0 as field;"
+            ))?;
+            assert_eq!(Stack::new(&process, &program).is_ok(), is_ok, "{instructions}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_bytes() -> Result<()> {
+        let (string, pack) = Pack::<CurrentNetwork>::parse("pack message r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(pack.struct_name, Identifier::from_str("message")?, "The struct name is incorrect");
+        assert_eq!(
+            pack.operands,
+            vec![Operand::Register(Register::Locator(0)), Operand::Register(Register::Locator(1))],
+            "The operands are incorrect"
+        );
+        assert_eq!(pack.destination, Register::Locator(2), "The destination register is incorrect");
+
+        for string in ["pack message r0 into r1", "pack message r0 r1 into r2", "pack envelope r0.inner true into r3"] {
+            // Check the string representation.
+            let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(instruction.opcode(), Pack::<CurrentNetwork>::opcode());
+            assert_eq!(string, instruction.to_string());
+
+            // Check the byte representation.
+            let bytes = instruction.to_bytes_le()?;
+            assert_eq!(instruction, crate::Instruction::from_bytes_le(&bytes)?);
+        }
+
+        // Ensure a pack without operands is rejected.
+        assert!(Pack::<CurrentNetwork>::from_str("pack message into r0").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,571 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    account::{ComputeKey, Signature},
+    network::prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+use indexmap::IndexMap;
+
+/// The members of a signature struct, in order, with their literal types.
+const SIGNATURE_MEMBERS: [(&str, LiteralType); 4] = [
+    ("challenge", LiteralType::Scalar),
+    ("response", LiteralType::Scalar),
+    ("pk_sig", LiteralType::Group),
+    ("pr_sig", LiteralType::Group),
+];
+
+/// Verifies the account signature `first` on the message `third` for the address `second`,
+/// storing the outcome as a boolean in `destination`.
+///
+/// The signature is a struct with the members `challenge: scalar`, `response: scalar`,
+/// `pk_sig: group`, and `pr_sig: group`, and the message is a literal that is signed as its field elements.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerify<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign("sign.verify")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = registers.load(stack, &self.operands[0])?;
+        let address = registers.load(stack, &self.operands[1])?;
+        let message = registers.load(stack, &self.operands[2])?;
+
+        // Verify the signature.
+        let output = Literal::Boolean(Boolean::new(Self::verify(&signature, &address, &message)?));
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = registers.load_circuit(stack, &self.operands[0])?;
+        let address = registers.load_circuit(stack, &self.operands[1])?;
+        let message = registers.load_circuit(stack, &self.operands[2])?;
+
+        // Verify the signature.
+        let output = circuit::Literal::Boolean(Self::verify_circuit::<A>(&signature, &address, &message)?);
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Returns `true` if the given signature is valid for the given address and message.
+    fn verify(signature: &Value<N>, address: &Value<N>, message: &Value<N>) -> Result<bool> {
+        // Retrieve the members of the signature.
+        let members = match signature {
+            Value::Plaintext(Plaintext::Struct(members, ..)) => members,
+            _ => bail!("Instruction '{}' expects a signature struct as the first operand", Self::opcode()),
+        };
+        let literals = SIGNATURE_MEMBERS
+            .iter()
+            .map(|(name, _)| match members.get(&Identifier::from_str(name)?) {
+                Some(Plaintext::Literal(literal, ..)) => Ok(literal),
+                _ => bail!("Instruction '{}' expects a signature with a '{name}' member", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the signature.
+        let signature = match literals[..] {
+            [Literal::Scalar(challenge), Literal::Scalar(response), Literal::Group(pk_sig), Literal::Group(pr_sig)] => {
+                Signature::from((*challenge, *response, ComputeKey::try_from((*pk_sig, *pr_sig))?))
+            }
+            _ => bail!("Instruction '{}' expects a signature with scalar and group members", Self::opcode()),
+        };
+
+        // Retrieve the address.
+        let address = match address {
+            Value::Plaintext(Plaintext::Literal(Literal::Address(address), ..)) => address,
+            _ => bail!("Instruction '{}' expects an address as the second operand", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = match message {
+            Value::Plaintext(Plaintext::Literal(..)) => message.to_fields()?,
+            _ => bail!("Instruction '{}' expects a literal as the third operand", Self::opcode()),
+        };
+
+        Ok(signature.verify(address, &message))
+    }
+
+    /// Returns `true` if the given circuit signature is valid for the given address and message.
+    fn verify_circuit<A: circuit::Aleo<Network = N>>(
+        signature: &circuit::Value<A>,
+        address: &circuit::Value<A>,
+        message: &circuit::Value<A>,
+    ) -> Result<circuit::Boolean<A>> {
+        use circuit::{Eject, ToFields};
+
+        // Retrieve the members of the signature, in order.
+        let members = match signature {
+            circuit::Value::Plaintext(circuit::Plaintext::Struct(members, ..)) => members,
+            _ => bail!("Instruction '{}' expects a signature struct as the first operand", Self::opcode()),
+        };
+        if members.len() != SIGNATURE_MEMBERS.len() {
+            bail!("Instruction '{}' expects a signature with 4 members", Self::opcode())
+        }
+        let literals = members
+            .iter()
+            .zip_eq(SIGNATURE_MEMBERS.iter())
+            .map(|((identifier, member), (name, _))| match member {
+                circuit::Plaintext::Literal(literal, ..) if identifier.eject_value().to_string() == *name => {
+                    Ok(literal)
+                }
+                _ => bail!("Instruction '{}' expects a signature with a '{name}' member", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the signature.
+        let signature = match literals[..] {
+            [
+                circuit::Literal::Scalar(challenge),
+                circuit::Literal::Scalar(response),
+                circuit::Literal::Group(pk_sig),
+                circuit::Literal::Group(pr_sig),
+            ] => circuit::Signature::from((
+                challenge.clone(),
+                response.clone(),
+                circuit::ComputeKey::from((pk_sig.clone(), pr_sig.clone())),
+            )),
+            _ => bail!("Instruction '{}' expects a signature with scalar and group members", Self::opcode()),
+        };
+
+        // Retrieve the address.
+        let address = match address {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Address(address), ..)) => address,
+            _ => bail!("Instruction '{}' expects an address as the second operand", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = match message {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(..)) => message.to_fields(),
+            _ => bail!("Instruction '{}' expects a literal as the third operand", Self::opcode()),
+        };
+
+        Ok(signature.verify(address, &message))
+    }
+
+    /// Returns the circuit cost of the instruction, along with the type and mode of its output.
+    /// As the signature is a struct, the input types are the signature members in order, the address, and the message.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        use circuit::{Eject, Inject};
+
+        // Ensure the number of input types is correct.
+        let num_inputs = SIGNATURE_MEMBERS.len() + 2;
+        if input_types.len() != num_inputs {
+            bail!("Instruction '{}' expects {num_inputs} inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the input types are correct.
+        for ((name, expected), (literal_type, _)) in SIGNATURE_MEMBERS.iter().zip_eq(&input_types[..4]) {
+            ensure!(expected == literal_type, "Expected '{name}' to be a '{expected}', found '{literal_type}'");
+        }
+        ensure!(input_types[4].0 == LiteralType::Address, "Expected an address, found '{}'", input_types[4].0);
+
+        // Sample the signature, address, and message.
+        let members = SIGNATURE_MEMBERS
+            .iter()
+            .zip_eq(&input_types[..4])
+            .map(|((name, literal_type), (_, mode))| {
+                let literal = circuit::Literal::new(*mode, crate::sample_operand::<N>(*literal_type)?);
+                Ok((circuit::Identifier::constant(Identifier::from_str(name)?), literal.into()))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let signature = circuit::Value::Plaintext(circuit::Plaintext::Struct(members, Default::default()));
+        let [address, message] = [input_types[4], input_types[5]].map(|(literal_type, mode)| {
+            Ok::<_, Error>(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::new(
+                mode,
+                crate::sample_operand::<N>(literal_type)?,
+            ))))
+        });
+        let (address, message) = (address?, message?);
+
+        // Synthesize the verification, measuring the number of variables and constraints it introduces.
+        let (num_constants, num_public, num_private, num_constraints, _) = A::count();
+        let output = Self::verify_circuit::<A>(&signature, &address, &message)?;
+        let (constants, public, private, constraints, _) = A::count();
+
+        let count = circuit::Count::is(
+            constants - num_constants,
+            public - num_public,
+            private - num_private,
+            constraints - num_constraints,
+        );
+        Ok((count, LiteralType::Boolean, output.eject_mode()))
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first operand is a signature struct.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                let struct_ = stack.program().get_struct(struct_name)?;
+                let is_signature = struct_.members().len() == SIGNATURE_MEMBERS.len()
+                    && struct_.members().iter().zip_eq(SIGNATURE_MEMBERS.iter()).all(
+                        |((name, member_type), (expected_name, expected_type))| {
+                            name.to_string() == *expected_name
+                                && *member_type == PlaintextType::Literal(*expected_type)
+                        },
+                    );
+                ensure!(
+                    is_signature,
+                    "Instruction '{}' expects '{struct_name}' to have the members \
+                    'challenge: scalar', 'response: scalar', 'pk_sig: group', and 'pr_sig: group'",
+                    Self::opcode()
+                );
+            }
+            input_type => bail!("Instruction '{}' expects a signature struct, found '{input_type}'", Self::opcode()),
+        }
+        // Ensure the second operand is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!("Instruction '{}' expects an address, found '{}'", Self::opcode(), input_types[1])
+        }
+        // Ensure the third operand is a literal.
+        if !matches!(input_types[2], RegisterType::Plaintext(PlaintextType::Literal(..))) {
+            bail!("Instruction '{}' expects a literal message, found '{}'", Self::opcode(), input_types[2])
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for SignVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = context("operand", Operand::parse)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = context("operand", Operand::parse)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = context("operand", Operand::parse)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = context("'into'", tag("into"))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = context("register", Register::parse)(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for SignVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for SignVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for SignVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for SignVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for SignVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the given signature as a signature struct.
+    fn to_value(signature: &Signature<CurrentNetwork>) -> Result<Value<CurrentNetwork>> {
+        let compute_key = signature.compute_key();
+        Value::from_str(&format!(
+            "{{ challenge: {}, response: {}, pk_sig: {}, pr_sig: {} }}",
+            signature.challenge(),
+            signature.response(),
+            compute_key.pk_sig(),
+            compute_key.pr_sig()
+        ))
+    }
+
+    /// Checks that the console and circuit verifications of the given inputs match the expected outcome in every mode.
+    fn check_verify(
+        signature: &Value<CurrentNetwork>,
+        address: &Value<CurrentNetwork>,
+        message: &Value<CurrentNetwork>,
+        expected: bool,
+    ) -> Result<()> {
+        use circuit::{Eject, Environment, Inject};
+
+        type CurrentAleo = circuit::AleoV0;
+
+        assert_eq!(expected, SignVerify::<CurrentNetwork>::verify(signature, address, message)?);
+
+        for mode in [circuit::Mode::Constant, circuit::Mode::Public, circuit::Mode::Private] {
+            // Ensure the circuit output matches, and the circuit is satisfied.
+            let candidate = SignVerify::<CurrentNetwork>::verify_circuit::<CurrentAleo>(
+                &circuit::Value::new(mode, signature.clone()),
+                &circuit::Value::new(mode, address.clone()),
+                &circuit::Value::new(mode, message.clone()),
+            )?;
+            assert_eq!(expected, candidate.eject_value(), "sign.verify {message}.{mode}");
+            assert!(CurrentAleo::is_satisfied(), "'sign.verify {message}.{mode}' is unsatisfied");
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_verify() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample two accounts.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Value::from_str(&Address::try_from(&private_key)?.to_string())?;
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let other_address = Value::<CurrentNetwork>::from_str(&Address::try_from(other_private_key)?.to_string())?;
+
+        for message in ["true", "1u8", "-1i64", "1field", "1scalar"] {
+            let message = Value::<CurrentNetwork>::from_str(message)?;
+            let signature = to_value(&Signature::sign(&private_key, &message.to_fields()?, &mut rng)?)?;
+
+            // Ensure the signature is valid for the signer and the message.
+            check_verify(&signature, &address, &message, true)?;
+            // Ensure the signature is invalid for another address.
+            check_verify(&signature, &other_address, &message, false)?;
+            // Ensure the signature is invalid for another message.
+            check_verify(&signature, &address, &Value::from_str("2u16")?, false)?;
+        }
+
+        // Ensure a signature with missing members is rejected.
+        let message = Value::<CurrentNetwork>::from_str("1u8")?;
+        let signature = Value::from_str("{ challenge: 1scalar, response: 1scalar }")?;
+        assert!(SignVerify::<CurrentNetwork>::verify(&signature, &address, &message).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_count() -> Result<()> {
+        type CurrentAleo = circuit::AleoV0;
+
+        let instruction = SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 r2 into r3")?;
+        let signature_types = [LiteralType::Scalar, LiteralType::Scalar, LiteralType::Group, LiteralType::Group];
+
+        // Ensure the count is measured for a signature, an address, and a message.
+        let input_types = signature_types
+            .into_iter()
+            .chain([LiteralType::Address, LiteralType::U8])
+            .map(|literal_type| (literal_type, circuit::Mode::Private))
+            .collect::<Vec<_>>();
+        let (count, output_type, mode) = instruction.count::<CurrentAleo>(&input_types)?;
+        assert!(!count.3.matches(0), "The verification must introduce constraints");
+        assert_eq!(LiteralType::Boolean, output_type);
+        assert_eq!(circuit::Mode::Private, mode);
+
+        // Ensure the count is rejected for mismatched input types.
+        assert!(instruction.count::<CurrentAleo>(&input_types[1..]).is_err());
+        let mut input_types = input_types;
+        input_types[0].0 = LiteralType::Field;
+        assert!(instruction.count::<CurrentAleo>(&input_types).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_types() -> Result<()> {
+        let process = crate::Process::<CurrentNetwork>::load_with_cache(&mut std::collections::HashMap::new())?;
+
+        for (members, address_type, is_ok) in [
+            ("challenge as scalar; response as scalar; pk_sig as group; pr_sig as group;", "address", true),
+            ("challenge as scalar; response as scalar; pk_sig as group; pr_sig as group;", "field", false),
+            ("response as scalar; challenge as scalar; pk_sig as group; pr_sig as group;", "address", false),
+            ("challenge as scalar; response as scalar; pk_sig as group; pr_sig as field;", "address", false),
+            ("challenge as scalar; response as scalar; pk_sig as group;", "address", false),
+        ] {
+            let program = crate::Program::from_str(&format!(
+                "program testing.aleo;
+                struct signature:
+                    {members}
+                function run:
+                    input r0 as signature.private;
+                    input r1 as {address_type}.private;
+                    input r2 as u64.private;
+                    sign.verify r0 r1 r2 into r3;
+                    output r3 as boolean.private;"
+            ))?;
+            assert_eq!(crate::Stack::new(&process, &program).is_ok(), is_ok, "{members} {address_type}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_bytes() -> Result<()> {
+        let (string, instruction) = SignVerify::<CurrentNetwork>::parse("sign.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Check the string representation.
+        let string = "sign.verify r0 r1 r2 into r3";
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(instruction.opcode(), SignVerify::<CurrentNetwork>::opcode());
+        assert_eq!(string, instruction.to_string());
+
+        // Check the byte representation.
+        let bytes = instruction.to_bytes_le()?;
+        assert_eq!(instruction, crate::Instruction::from_bytes_le(&bytes)?);
+        Ok(())
+    }
+}
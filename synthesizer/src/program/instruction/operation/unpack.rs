@@ -0,0 +1,440 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, Stack};
+use console::{
+    network::prelude::*,
+    program::{LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Unpacks the struct `first` into its members, storing each member in successive destination registers.
+/// i.e. `unpack r2 into r3 r4;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Unpack<N: Network> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Unpack<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Unpack
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly one input.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operand.
+        &self.operands
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+}
+
+impl<N: Network> Unpack<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the struct members.
+        let members = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Struct(members, ..)) => members,
+            _ => bail!("Instruction '{}' expects a struct operand", Self::opcode()),
+        };
+        // Ensure there is one destination for each struct member.
+        if members.len() != self.destinations.len() {
+            bail!(
+                "Instruction '{}' expects {} destinations, found {} struct members",
+                Self::opcode(),
+                self.destinations.len(),
+                members.len()
+            )
+        }
+
+        // Store each member in its destination register.
+        for (destination, (_, member)) in self.destinations.iter().zip_eq(members) {
+            registers.store(stack, destination, Value::Plaintext(member))?;
+        }
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the struct members.
+        let members = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Struct(members, ..)) => members,
+            _ => bail!("Instruction '{}' expects a struct operand", Self::opcode()),
+        };
+        // Ensure there is one destination for each struct member.
+        if members.len() != self.destinations.len() {
+            bail!(
+                "Instruction '{}' expects {} destinations, found {} struct members",
+                Self::opcode(),
+                self.destinations.len(),
+                members.len()
+            )
+        }
+
+        // Store each member in its destination register.
+        for (destination, (_, member)) in self.destinations.iter().zip_eq(members) {
+            registers.store_circuit(stack, destination, circuit::Value::Plaintext(member))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the circuit cost of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn count<A: circuit::Aleo<Network = N>>(
+        &self,
+        _input_types: &[(LiteralType, circuit::Mode)],
+    ) -> Result<(circuit::Count, LiteralType, circuit::Mode)> {
+        bail!("Instruction '{}' does not support circuit cost estimation", Self::opcode())
+    }
+
+    /// Returns the output of the instruction, which is not supported for this instruction.
+    #[inline]
+    pub fn synthesize_output<A: circuit::Aleo<Network = N>>(
+        &self,
+        _inputs: &[circuit::Literal<A>],
+    ) -> Result<circuit::Literal<A>> {
+        bail!("Instruction '{}' does not support output mode inference", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Retrieve the struct and ensure it is defined in the program.
+        let struct_ = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => stack.program().get_struct(struct_name)?,
+            input_type => bail!("Instruction '{}' expects a struct, found '{input_type}'", Self::opcode()),
+        };
+        // Ensure there is one destination for each struct member.
+        if struct_.members().len() != self.destinations.len() {
+            bail!(
+                "Instruction '{}' expects {} destinations for '{}', found {} destinations",
+                Self::opcode(),
+                struct_.members().len(),
+                struct_.name(),
+                self.destinations.len()
+            )
+        }
+
+        // Output the member types, in order.
+        Ok(struct_.members().values().map(|member_type| RegisterType::Plaintext(*member_type)).collect())
+    }
+}
+
+impl<N: Network> Parser for Unpack<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = context("operand", Operand::parse)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = context("'into'", tag("into"))(string)?;
+        // Parse the destination registers from the string.
+        let (string, destinations) =
+            map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                // Ensure the number of destinations is within the bounds.
+                match destinations.len() <= N::MAX_DATA_ENTRIES {
+                    true => Ok(destinations),
+                    false => Err(error("Failed to parse 'unpack' opcode: too many destinations")),
+                }
+            })(string)?;
+
+        Ok((string, Self { operands: vec![operand], destinations }))
+    }
+}
+
+impl<N: Network> FromStr for Unpack<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Unpack<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Unpack<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            eprintln!("The number of operands must be 1, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.is_empty() || self.destinations.len() > N::MAX_DATA_ENTRIES {
+            eprintln!("The number of destinations must be nonzero and <= {}", N::MAX_DATA_ENTRIES);
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into", Self::opcode(), self.operands[0])?;
+        self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))
+    }
+}
+
+impl<N: Network> FromBytes for Unpack<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations.is_zero() || num_destinations > N::MAX_DATA_ENTRIES {
+            return Err(error(format!("The number of destinations must be nonzero and <= {}", N::MAX_DATA_ENTRIES)));
+        }
+
+        // Initialize the vector for the destinations.
+        let mut destinations = Vec::with_capacity(num_destinations);
+        // Read the destination registers.
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Ok(Self { operands: vec![operand], destinations })
+    }
+}
+
+impl<N: Network> ToBytes for Unpack<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.is_empty() || self.destinations.len() > N::MAX_DATA_ENTRIES {
+            return Err(error(format!("The number of destinations must be nonzero and <= {}", N::MAX_DATA_ENTRIES)));
+        }
+
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the number of destination registers.
+        (self.destinations.len() as u8).write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use console::{
+        network::Testnet3,
+        program::Identifier,
+        types::{Address, Field},
+    };
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Evaluates the closure `run` in the given program on the given inputs.
+    fn evaluate(program: &str, inputs: &[&str]) -> Result<Vec<Value<CurrentNetwork>>> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = Program::from_str(program)?;
+        let stack = Stack::new(&Process::load_with_cache(&mut HashMap::new())?, &program)?;
+        let closure = program.get_closure(&Identifier::from_str("run")?)?;
+
+        // Evaluate the closure.
+        let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>()?;
+        stack.evaluate_closure::<CurrentAleo>(
+            &closure,
+            &inputs,
+            CallStack::evaluate(Authorization::new(&[]))?,
+            Address::new(Uniform::rand(&mut rng)),
+            Field::rand(&mut rng),
+        )
+    }
+
+    #[test]
+    fn test_unpack() -> Result<()> {
+        let program = r"program testing.aleo;
+struct message:
+    first as field;
+    second as u8;
+closure run:
+    input r0 as field;
+    input r1 as u8;
+    cast r0 r1 into r2 as message;
+    unpack r2 into r3 r4;
+    output r3 as field;
+    output r4 as u8;";
+
+        // Ensure packing and unpacking a struct round-trips its members.
+        let outputs = evaluate(program, &["1field", "2u8"])?;
+        assert_eq!(outputs, vec![Value::from_str("1field")?, Value::from_str("2u8")?]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_nested() -> Result<()> {
+        let program = r"program testing.aleo;
+struct message:
+    first as field;
+    second as u8;
+struct envelope:
+    inner as message;
+    flag as boolean;
+closure run:
+    input r0 as field;
+    input r1 as u8;
+    input r2 as boolean;
+    cast r0 r1 into r3 as message;
+    cast r3 r2 into r4 as envelope;
+    unpack r4 into r5 r6;
+    unpack r5 into r7 r8;
+    output r5 as message;
+    output r6 as boolean;
+    output r7 as field;
+    output r8 as u8;";
+
+        // Ensure unpacking a nested struct yields the inner struct, which unpacks into its members.
+        let outputs = evaluate(program, &["1field", "2u8", "true"])?;
+        let expected = ["{ first: 1field, second: 2u8 }", "true", "1field", "2u8"]
+            .into_iter()
+            .map(Value::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(outputs, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_mismatch() -> Result<()> {
+        let process = Process::<CurrentNetwork>::load_with_cache(&mut HashMap::new())?;
+
+        for (instructions, is_ok) in [
+            ("cast r0 r1 into r2 as message; unpack r2 into r3 r4;", true),
+            // Ensure the number of destinations must match the number of members.
+            ("cast r0 r1 into r2 as message; unpack r2 into r3;", false),
+            ("cast r0 r1 into r2 as message; unpack r2 into r3 r4 r5;", false),
+            // Ensure the operand must be a struct.
+            ("unpack r0 into r3;", false),
+            // Ensure the number of operands must match the number of members.
+            ("cast r0 into r2 as message;", false),
+            ("cast r0 r1 r1 into r2 as message;", false),
+            // Ensure the operand types must match the member types.
+            ("cast r1 r0 into r2 as message;", false),
+        ] {
+            let program = Program::from_str(&format!(
+                "program testing.aleo;
+                struct message:
+                    first as field;
+                    second as u8;
+                closure run:
+                    input r0 as field;
+                    input r1 as u8;
+                    {instructions}
+                    output r0 as field;"
+            ))?;
+            assert_eq!(Stack::new(&process, &program).is_ok(), is_ok, "{instructions}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_and_bytes() -> Result<()> {
+        let (string, unpack) = Unpack::<CurrentNetwork>::parse("unpack r0 into r1 r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(unpack.operands, vec![Operand::Register(Register::Locator(0))], "The operand is incorrect");
+        assert_eq!(unpack.destinations, vec![Register::Locator(1), Register::Locator(2)], "The destinations are wrong");
+
+        for string in ["unpack r0 into r1", "unpack r0 into r1 r2", "unpack r0.inner into r1 r2 r3"] {
+            // Check the string representation.
+            let instruction = crate::Instruction::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(instruction.opcode(), Unpack::<CurrentNetwork>::opcode());
+            assert_eq!(string, instruction.to_string());
+
+            // Check the byte representation.
+            let bytes = instruction.to_bytes_le()?;
+            assert_eq!(instruction, crate::Instruction::from_bytes_le(&bytes)?);
+        }
+
+        // Ensure an unpack without destinations is rejected.
+        assert!(Unpack::<CurrentNetwork>::from_str("unpack r0 into").is_err());
+        Ok(())
+    }
+}
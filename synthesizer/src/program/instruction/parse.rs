@@ -60,12 +60,51 @@ impl<N: Network> Parser for Instruction<N> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon from the string.
-        let (string, _) = tag(";")(string)?;
+        let (string, _) = context("';'", tag(";"))(string)?;
 
         Ok((string, instruction))
     }
 }
 
+impl<N: Network> Instruction<N> {
+    /// Returns the remaining input at the syntax error in the given instruction, along with what was expected there.
+    /// Returns `None` if the given string does not start with an instruction, or if the instruction is valid.
+    pub(crate) fn diagnose(string: &str) -> Option<(&str, &'static str)> {
+        /// Creates a parser for the instruction with the given opcode, without trying the other instructions.
+        macro_rules! instruction_diagnose {
+            ($opcode:expr, |$string:ident| $_operation:block, { $( $variant:ident, )+ }) => {{
+                $(if *$variant::<N>::opcode() == $opcode {
+                    $variant::<N>::parse($string).map(|(string, _)| (string, ()))
+                } else)+ {
+                    fail($string)
+                }
+            }};
+        }
+
+        // Retrieve the leading token.
+        let token = string.split(|c: char| c.is_whitespace() || c == ';').next().unwrap_or_default();
+        // Ensure the leading token is not the keyword of another statement.
+        if ["input", "output", "finalize", "function", "closure", "struct", "record", "mapping"].contains(&token) {
+            return None;
+        }
+        // Ensure the leading token is an opcode.
+        if !Self::OPCODES.iter().any(|opcode| **opcode == token) {
+            return Some((string, "opcode"));
+        }
+
+        // Parse the instruction with the given opcode, followed by the semicolon.
+        let result = crate::instruction!(instruction_diagnose!(token, string))
+            .and_then(|(string, _)| Sanitizer::parse_whitespaces(string))
+            .and_then(|(string, _)| context("';'", tag(";"))(string));
+        match result {
+            Ok(_) => None,
+            Err(error) => {
+                parser_error_location(&error).map(|(string, context)| (string, context.unwrap_or("valid syntax")))
+            }
+        }
+    }
+}
+
 impl<N: Network> FromStr for Instruction<N> {
     type Err = Error;
 
@@ -99,4 +138,27 @@ mod tests {
         assert_eq!(instruction, candidate.to_string());
         Ok(())
     }
+
+    #[test]
+    fn test_parse_bitwise() -> Result<()> {
+        for instruction in ["and r0 r1 into r2;", "or r0 r1 into r2;", "xor r0 r1 into r2;"] {
+            let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+            assert_eq!("", remainder);
+            assert_eq!(instruction, candidate.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_unary() -> Result<()> {
+        for instruction in ["neg r0 into r1;", "not r0 into r1;"] {
+            let (remainder, candidate) = Instruction::<CurrentNetwork>::parse(instruction)?;
+            assert_eq!("", remainder);
+            assert_eq!(instruction, candidate.to_string());
+        }
+
+        // Ensure a unary instruction with two operands fails to parse.
+        assert!(Instruction::<CurrentNetwork>::from_str("not r0 r1 into r2;").is_err());
+        Ok(())
+    }
 }
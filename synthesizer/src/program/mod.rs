@@ -1001,6 +1001,44 @@ function compute:
         assert_eq!(expected, candidate[0]);
     }
 
+    #[test]
+    fn test_program_call_checks() -> Result<()> {
+        // Returns the error from checking the program with the given `compute` instructions, if any.
+        let check = |instructions: &str| -> Result<()> {
+            let program = Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program example_call.aleo;
+
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as field.private;
+    input r1 as u8.public;
+    {instructions}
+    output r0 as field.private;"
+            ))?;
+            let process = crate::Process::load_with_cache(&mut std::collections::HashMap::new())?;
+            crate::Stack::new(&process, &program).map(|_| ())
+        };
+
+        // Ensure a well-formed call is accepted.
+        check("call execute r0 r0 into r2;")?;
+        // Ensure a call with the wrong number of operands or destinations is rejected.
+        assert!(check("call execute r0 into r2;").is_err());
+        assert!(check("call execute r0 r0 r0 into r2;").is_err());
+        assert!(check("call execute r0 r0 into r2 r3;").is_err());
+        // Ensure a call with an operand of the wrong type is rejected.
+        let error = check("call execute r0 r1 into r2;").unwrap_err();
+        assert_eq!("Expected an input of type 'field', found 'u8'", error.to_string());
+        // Ensure a function cannot call itself, or another function in the same program.
+        assert!(check("call compute r0 r1 into r2;").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_program_evaluate_call() {
         // Initialize a new program.
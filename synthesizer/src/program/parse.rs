@@ -116,6 +116,50 @@ impl<N: Network> FromStr for Program<N> {
     }
 }
 
+impl<N: Network> Program<N> {
+    /// Returns a program from a string literal, or an error that locates the first syntax error by line and column,
+    /// i.e. `line 14, column 7: expected register, found "1ufield"`.
+    ///
+    /// Errors that are not syntax errors, such as a duplicate function name, are returned as in `from_str`.
+    pub fn from_str_with_errors(string: &str) -> Result<Self> {
+        // Retrieve the remaining string, from where the parser stopped.
+        let remaining = match Self::parse(string) {
+            Ok((remaining, program)) if remaining.is_empty() => return Ok(program),
+            Ok((remaining, _)) => remaining,
+            Err(error) => match parser_error_location(&error) {
+                Some((remaining, _)) if !remaining.is_empty() => remaining,
+                _ => return Self::from_str(string),
+            },
+        };
+
+        // Parse the whitespace and comments before the statement that failed to parse.
+        let statement = Sanitizer::parse(remaining).map_or(remaining, |(statement, _)| statement);
+        // Locate the syntax error within the statement.
+        let (remaining, expected) = match Instruction::<N>::diagnose(statement) {
+            Some((remaining, expected)) => (remaining, Some(expected)),
+            None => (statement, None),
+        };
+
+        // Compute the line and column of the syntax error.
+        let (preceding, _) = string.split_at(string.len() - remaining.len());
+        let line = preceding.matches('\n').count() + 1;
+        let column = preceding.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+
+        // Retrieve the token at the syntax error.
+        let token = remaining.split(|c: char| c.is_whitespace() || c == ';').next().unwrap_or_default();
+        let found = match (token, remaining.chars().next()) {
+            (_, None) => "end of input".to_string(),
+            ("", Some(character)) => format!("\"{character}\""),
+            (token, _) => format!("\"{token}\""),
+        };
+
+        match expected {
+            Some(expected) => bail!("line {line}, column {column}: expected {expected}, found {found}"),
+            None => bail!("line {line}, column {column}: unexpected {found}"),
+        }
+    }
+}
+
 impl<N: Network> Debug for Program<N> {
     /// Prints the program as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -263,4 +307,36 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_from_str_with_errors() -> Result<()> {
+        // Returns the program, with the given instruction on line 6.
+        let program = |instruction: &str| {
+            format!(
+                "program to_parse.aleo;\n\nfunction compute:\n    \
+                 input r0 as u8.private;\n    input r1 as u8.private;\n    \
+                 {instruction}\n    output r2 as u8.private;\n"
+            )
+        };
+
+        // Ensure a valid program is returned as in `from_str`.
+        let expected = Program::<CurrentNetwork>::from_str(&program("add r0 r1 into r2;"))?;
+        assert_eq!(expected, Program::from_str_with_errors(&program("add r0 r1 into r2;"))?);
+
+        // Ensure the syntax errors are located.
+        for (instruction, expected) in [
+            ("addd r0 r1 into r2;", "line 6, column 5: expected opcode, found \"addd\""),
+            ("add r0 r1 r2;", "line 6, column 15: expected 'into', found \"r2\""),
+            ("add r0 r1 into r2", "line 7, column 5: expected ';', found \"output\""),
+            ("add r0 r1 into 1ufield;", "line 6, column 20: expected register, found \"1ufield\""),
+            ("hash.bhp256 r0 r1;", "line 6, column 20: expected 'into', found \"r1\""),
+        ] {
+            // Ensure `from_str` still rejects the program.
+            assert!(Program::<CurrentNetwork>::from_str(&program(instruction)).is_err());
+            // Ensure the diagnostic matches.
+            let error = Program::<CurrentNetwork>::from_str_with_errors(&program(instruction)).unwrap_err();
+            assert_eq!(expected, error.to_string());
+        }
+        Ok(())
+    }
 }